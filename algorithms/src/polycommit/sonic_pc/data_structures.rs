@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -395,6 +395,39 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// The accumulated elements of a batch check, which hold if their product of pairings is one.
+/// Pairing checks of independent proofs (under the same verifier key) can be combined into one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, grouped by degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Adds the given pairing check, scaled by `randomizer`, into this pairing check.
+    pub fn accumulate(&mut self, other: Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in other.combined_comms {
+            *self.combined_comms.entry(degree_bound).or_default() += comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
@@ -23,6 +23,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
@@ -348,6 +349,23 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::prepare_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(vk, check)
+    }
+
+    /// Accumulates the elements of a batch check, without performing the final pairing check.
+    /// The returned pairing check may be combined with others, and checked via `SonicKZG10::check_pairings`.
+    pub fn prepare_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -368,9 +386,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut check = PairingCheck::<E>::default();
 
         ensure!(query_to_labels_map.len() == proof.0.len());
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
@@ -389,9 +405,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut check.combined_comms,
+                &mut check.combined_witness,
+                &mut check.combined_adjusted_witness,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -404,9 +420,31 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(check)
+    }
+
+    /// Returns `true` if the given pairing check holds.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, check: PairingCheck<E>) -> Result<bool> {
+        Self::check_elems(vk, check)
+    }
+
+    /// Checks the given pairing checks at once, by combining them with random scalars into a single pairing check.
+    /// Returns `true` if and only if (with overwhelming probability) every pairing check holds.
+    pub fn check_pairings<R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let combine_time = start_timer!(|| "Combining pairing checks");
+        let mut checks = checks.into_iter();
+        // Note: The first pairing check does not need to be randomized.
+        let Some(mut combined_check) = checks.next() else { bail!("Expected at least one pairing check") };
+        for check in checks {
+            combined_check.accumulate(check, E::Fr::rand(rng));
+        }
+        end_timer!(combine_time);
+        Self::check_elems(vk, combined_check)
     }
 
     pub fn open_combinations<'a>(
@@ -482,6 +520,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::prepare_check_combinations(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_elems(vk, check)
+    }
+
+    /// Accumulates the elements to check that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `labeled_commitments`, without performing the final pairing check.
+    pub fn prepare_check_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,7 +603,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::prepare_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -633,13 +697,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok(())
     }
 
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool> {
+    fn check_elems(vk: &UniversalVerifier<E>, check: PairingCheck<E>) -> Result<bool> {
         let check_time = start_timer!(|| "Checking elems");
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = check;
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
 
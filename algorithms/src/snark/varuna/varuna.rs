@@ -20,6 +20,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -628,6 +629,42 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        // Prepare the pairing check of the proof.
+        let Some(pairing_check) = Self::prepare_verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
+        }
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Runs the verifier algorithm on the given batch proof, up to (but excluding) the final pairing check.
+    /// Returns `None` if the proof has an incorrect hiding mode.
+    ///
+    /// The returned pairing check can be combined with the pairing checks of other proofs,
+    /// and checked at once via `VarunaSNARK::verify_pairing_checks`.
+    pub fn prepare_verify_batch<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -723,7 +760,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -896,8 +933,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Preparing linear combinations with PC");
+        let pairing_check = SonicKZG10::<E, FS>::prepare_check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -908,15 +945,20 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
+    }
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+    /// Checks the given pairing checks (of independent proofs) at once, by combining them into a single pairing check.
+    /// Returns `true` if and only if (with overwhelming probability) every pairing check holds.
+    pub fn verify_pairing_checks<R: RngCore>(
+        universal_verifier: &UniversalVerifier<E>,
+        pairing_checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let pc_time = start_timer!(|| "Checking pairing checks with PC");
+        let result = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_checks, rng);
+        end_timer!(pc_time);
+        result
     }
 }
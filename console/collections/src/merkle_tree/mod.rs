@@ -119,6 +119,54 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         })
     }
 
+    #[inline]
+    /// Initializes a Merkle tree from the given hashes of the full Merkle tree, and the given number of leaves.
+    ///
+    /// Note: The hashes are not recomputed, and must be the hashes of a Merkle tree built with the given hashers
+    /// (i.e. from `MerkleTree::tree`). The caller is responsible for checking the root against a trusted root.
+    pub fn from_hashes(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        tree: Vec<PH::Hash>,
+        number_of_leaves: usize,
+    ) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = max_leaves + (max_leaves - 1);
+        // Ensure the number of hashes matches the tree size.
+        ensure!(tree.len() == tree_size, "Expected {tree_size} hashes in the Merkle tree, found {}", tree.len());
+        // Compute the number of padded levels.
+        let padding_depth = DEPTH - tree_depth::<DEPTH>(tree_size)?;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash)?;
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash,
+            number_of_leaves,
+        })
+    }
+
     #[inline]
     /// Returns a new Merkle tree with the given new leaves appended to it.
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
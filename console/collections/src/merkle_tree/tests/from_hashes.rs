@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_merkle_tree_bhp_from_hashes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in 0..core::cmp::min(1 << DEPTH, ITERATIONS) {
+            // Construct the Merkle tree for the leaves.
+            let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+            let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+            // Initialize the Merkle tree from its hashes.
+            let candidate = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::from_hashes(
+                &leaf_hasher,
+                &path_hasher,
+                merkle_tree.tree().to_vec(),
+                num_leaves,
+            )?;
+            assert_eq!(candidate.root(), merkle_tree.root());
+            assert_eq!(candidate.tree(), merkle_tree.tree());
+            assert_eq!(candidate.number_of_leaves(), num_leaves);
+            for (leaf_index, leaf) in leaves.iter().enumerate() {
+                assert_eq!(candidate.prove(leaf_index, leaf)?, merkle_tree.prove(leaf_index, leaf)?);
+            }
+
+            // Ensure the appended Merkle trees match.
+            let leaf = Field::<CurrentEnvironment>::rand(rng).to_bits_le();
+            if num_leaves < 1 << DEPTH {
+                assert_eq!(candidate.prepare_append(&[leaf.clone()])?.root(), merkle_tree.prepare_append(&[leaf])?.root());
+            }
+
+            // Ensure an incorrect number of hashes fails.
+            let mut hashes = merkle_tree.tree().to_vec();
+            hashes.push(*merkle_tree.empty_hash());
+            assert!(
+                MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::from_hashes(&leaf_hasher, &path_hasher, hashes, num_leaves)
+                    .is_err()
+            );
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    assert!(run_test::<1>(&mut rng).is_ok());
+    assert!(run_test::<2>(&mut rng).is_ok());
+    assert!(run_test::<3>(&mut rng).is_ok());
+    assert!(run_test::<4>(&mut rng).is_ok());
+    assert!(run_test::<32>(&mut rng).is_ok());
+
+    Ok(())
+}
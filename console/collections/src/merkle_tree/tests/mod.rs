@@ -15,6 +15,7 @@
 use super::*;
 
 mod append;
+mod from_hashes;
 mod remove;
 mod update;
 mod update_many;
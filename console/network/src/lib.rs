@@ -83,6 +83,8 @@ pub trait Network:
     const NAME: &'static str;
     /// The network edition.
     const EDITION: u16;
+    /// Whether a process on the network may enable dev mode, in which executions carry mock proofs.
+    const ALLOWS_DEV_MODE: bool;
 
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str;
@@ -355,6 +357,13 @@ pub trait Network:
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// from the given hashes of the full Merkle tree (i.e. from `MerkleTree::tree`), without recomputing them.
+    fn merkle_tree_bhp_from_hashes<const DEPTH: u8>(
+        tree: Vec<Field<Self>>,
+        number_of_leaves: usize,
+    ) -> Result<BHPMerkleTree<Self, DEPTH>>;
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>>;
 
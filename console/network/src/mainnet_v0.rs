@@ -131,6 +131,9 @@ impl Network for MainnetV0 {
     /// The transition ID type.
     type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
 
+    /// Whether a process on the network may enable dev mode, in which executions carry mock proofs.
+    /// Note: Dev mode is only allowed in builds with the `dev-mode` feature, which production nodes must not enable.
+    const ALLOWS_DEV_MODE: bool = cfg!(feature = "dev-mode");
     /// The network edition.
     const EDITION: u16 = 0;
     /// The network ID.
@@ -457,6 +460,15 @@ impl Network for MainnetV0 {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
     }
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// from the given hashes of the full Merkle tree (i.e. from `MerkleTree::tree`), without recomputing them.
+    fn merkle_tree_bhp_from_hashes<const DEPTH: u8>(
+        tree: Vec<Field<Self>>,
+        number_of_leaves: usize,
+    ) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::from_hashes(&*BHP_1024, &*BHP_512, tree, number_of_leaves)
+    }
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
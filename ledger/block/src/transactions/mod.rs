@@ -18,6 +18,9 @@ pub use confirmed::*;
 pub mod rejected;
 pub use rejected::*;
 
+pub mod receipt;
+pub use receipt::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
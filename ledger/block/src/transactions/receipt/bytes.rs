@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for TransactionReceipt<N> {
+    /// Reads the transaction receipt from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid transaction receipt version"));
+        }
+        // Read the transaction ID.
+        let transaction_id = N::TransactionID::read_le(&mut reader)?;
+        // Read the block height.
+        let block_height = u32::read_le(&mut reader)?;
+        // Read the status.
+        let status = match u8::read_le(&mut reader)? {
+            0 => ReceiptStatus::Accepted,
+            1 => ReceiptStatus::Rejected,
+            2 => ReceiptStatus::Aborted,
+            variant => return Err(error(format!("Failed to decode receipt status variant {variant}"))),
+        };
+        // Read the index.
+        let index = match bool::read_le(&mut reader)? {
+            true => Some(u32::read_le(&mut reader)?),
+            false => None,
+        };
+        // Read the fee.
+        let fee = u64::read_le(&mut reader)?;
+        // Read the number of finalize operations.
+        let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+        // Read the finalize operations.
+        let finalize = (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the reason.
+        let reason = match bool::read_le(&mut reader)? {
+            true => {
+                // Read the number of bytes in the reason.
+                let num_bytes = u16::read_le(&mut reader)? as usize;
+                // Ensure the number of bytes is within bounds.
+                if num_bytes > Self::MAX_REASON_BYTES {
+                    return Err(error("Transaction receipt reason exceeds the maximum size"));
+                }
+                // Read the bytes of the reason.
+                let mut bytes = vec![0u8; num_bytes];
+                reader.read_exact(&mut bytes)?;
+                Some(String::from_utf8(bytes).map_err(|e| error(e.to_string()))?)
+            }
+            false => None,
+        };
+        // Return the transaction receipt.
+        Self::new(transaction_id, block_height, status, index, fee, finalize, reason).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for TransactionReceipt<N> {
+    /// Writes the transaction receipt to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the transaction ID.
+        self.transaction_id.write_le(&mut writer)?;
+        // Write the block height.
+        self.block_height.write_le(&mut writer)?;
+        // Write the status.
+        match self.status {
+            ReceiptStatus::Accepted => 0u8.write_le(&mut writer)?,
+            ReceiptStatus::Rejected => 1u8.write_le(&mut writer)?,
+            ReceiptStatus::Aborted => 2u8.write_le(&mut writer)?,
+        }
+        // Write the index.
+        match self.index {
+            Some(index) => {
+                true.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+        // Write the fee.
+        self.fee.write_le(&mut writer)?;
+        // Write the number of finalize operations.
+        NumFinalizeSize::try_from(self.finalize.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the finalize operations.
+        self.finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))?;
+        // Write the reason.
+        match &self.reason {
+            Some(reason) => {
+                true.write_le(&mut writer)?;
+                u16::try_from(reason.len()).map_err(error)?.write_le(&mut writer)?;
+                writer.write_all(reason.as_bytes())
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_receipts(rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, TransactionReceipt::<CurrentNetwork>::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
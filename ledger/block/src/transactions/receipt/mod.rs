@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{ConfirmedTransaction, NumFinalizeSize};
+use console::network::prelude::*;
+use synthesizer_program::FinalizeOperation;
+
+/// The status of a transaction in a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReceiptStatus {
+    /// The transaction was accepted, and its finalize operations were applied.
+    Accepted,
+    /// The transaction was rejected, and only its fee was finalized.
+    Rejected,
+    /// The transaction was aborted, and was not included in the block.
+    Aborted,
+}
+
+impl ReceiptStatus {
+    /// Returns the human-readable variant of the receipt status.
+    pub const fn variant(&self) -> &str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+            Self::Aborted => "aborted",
+        }
+    }
+}
+
+/// The receipt of a transaction, describing the outcome of the transaction in the block that included it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionReceipt<N: Network> {
+    /// The ID of the transaction, as it was submitted (i.e. the unconfirmed transaction ID).
+    transaction_id: N::TransactionID,
+    /// The height of the block that included the transaction.
+    block_height: u32,
+    /// The status of the transaction.
+    status: ReceiptStatus,
+    /// The index of the transaction in the block, if the transaction was not aborted.
+    index: Option<u32>,
+    /// The fee paid by the transaction, in microcredits.
+    fee: u64,
+    /// The finalize operations applied by the transaction.
+    finalize: Vec<FinalizeOperation<N>>,
+    /// The reason the transaction was rejected or aborted, if known.
+    reason: Option<String>,
+}
+
+impl<N: Network> TransactionReceipt<N> {
+    /// The maximum number of bytes in the reason of a receipt.
+    pub const MAX_REASON_BYTES: usize = 1024;
+
+    /// Initializes a new transaction receipt.
+    pub fn new(
+        transaction_id: N::TransactionID,
+        block_height: u32,
+        status: ReceiptStatus,
+        index: Option<u32>,
+        fee: u64,
+        finalize: Vec<FinalizeOperation<N>>,
+        reason: Option<String>,
+    ) -> Result<Self> {
+        // Ensure the index is only present for transactions that were included in the block.
+        ensure!(
+            index.is_some() == (status != ReceiptStatus::Aborted),
+            "Invalid index for a {} receipt",
+            status.variant()
+        );
+        // Ensure a reason is only present for rejected or aborted transactions.
+        ensure!(status != ReceiptStatus::Accepted || reason.is_none(), "An accepted receipt cannot have a reason");
+        // Ensure aborted transactions did not pay a fee or apply finalize operations.
+        ensure!(status != ReceiptStatus::Aborted || (fee == 0 && finalize.is_empty()), "Invalid aborted receipt");
+        // Ensure the number of finalize operations is within bounds.
+        ensure!(NumFinalizeSize::try_from(finalize.len()).is_ok(), "Too many finalize operations in the receipt");
+        // Truncate the reason to the maximum number of bytes, on a character boundary.
+        let reason = reason.map(|mut reason| {
+            if reason.len() > Self::MAX_REASON_BYTES {
+                let mut length = Self::MAX_REASON_BYTES;
+                while !reason.is_char_boundary(length) {
+                    length -= 1;
+                }
+                reason.truncate(length);
+            }
+            reason
+        });
+        Ok(Self { transaction_id, block_height, status, index, fee, finalize, reason })
+    }
+
+    /// Initializes the receipt of the given confirmed transaction, in the block at the given height.
+    /// If the transaction was rejected, the reason for the rejection may be provided.
+    pub fn from_confirmed(
+        block_height: u32,
+        confirmed: &ConfirmedTransaction<N>,
+        reason: Option<String>,
+    ) -> Result<Self> {
+        // Determine the status of the transaction.
+        let status = match confirmed.is_accepted() {
+            true => ReceiptStatus::Accepted,
+            false => ReceiptStatus::Rejected,
+        };
+        Self::new(
+            confirmed.to_unconfirmed_transaction_id()?,
+            block_height,
+            status,
+            Some(confirmed.index()),
+            *confirmed.fee_amount()?,
+            confirmed.finalize_operations().clone(),
+            reason,
+        )
+    }
+
+    /// Initializes the receipt of the given aborted transaction ID, in the block at the given height.
+    /// The reason for the abort may be provided.
+    pub fn from_aborted(block_height: u32, transaction_id: N::TransactionID, reason: Option<String>) -> Result<Self> {
+        Self::new(transaction_id, block_height, ReceiptStatus::Aborted, None, 0, vec![], reason)
+    }
+}
+
+impl<N: Network> TransactionReceipt<N> {
+    /// Returns the ID of the transaction, as it was submitted.
+    pub const fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+
+    /// Returns the height of the block that included the transaction.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the status of the transaction.
+    pub const fn status(&self) -> ReceiptStatus {
+        self.status
+    }
+
+    /// Returns `true` if the transaction was accepted.
+    pub fn is_accepted(&self) -> bool {
+        self.status == ReceiptStatus::Accepted
+    }
+
+    /// Returns `true` if the transaction was rejected.
+    pub fn is_rejected(&self) -> bool {
+        self.status == ReceiptStatus::Rejected
+    }
+
+    /// Returns `true` if the transaction was aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.status == ReceiptStatus::Aborted
+    }
+
+    /// Returns the index of the transaction in the block, if the transaction was not aborted.
+    pub const fn index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// Returns the fee paid by the transaction, in microcredits.
+    pub const fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// Returns the finalize operations applied by the transaction.
+    pub const fn finalize_operations(&self) -> &Vec<FinalizeOperation<N>> {
+        &self.finalize
+    }
+
+    /// Returns the reason the transaction was rejected or aborted, if known.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+    use console::{network::MainnetV0, types::Field};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Sample a list of transaction receipts.
+    pub(crate) fn sample_receipts(rng: &mut TestRng) -> Vec<TransactionReceipt<CurrentNetwork>> {
+        vec![
+            TransactionReceipt::new(
+                Field::<CurrentNetwork>::rand(rng).into(),
+                rng.gen(),
+                ReceiptStatus::Accepted,
+                Some(rng.gen()),
+                rng.gen(),
+                vec![
+                    FinalizeOperation::InsertKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
+                    FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
+                    FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
+                ],
+                None,
+            )
+            .unwrap(),
+            TransactionReceipt::new(
+                Field::<CurrentNetwork>::rand(rng).into(),
+                rng.gen(),
+                ReceiptStatus::Rejected,
+                Some(rng.gen()),
+                rng.gen(),
+                vec![FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng))],
+                Some("Insufficient balance".to_string()),
+            )
+            .unwrap(),
+            TransactionReceipt::new(
+                Field::<CurrentNetwork>::rand(rng).into(),
+                rng.gen(),
+                ReceiptStatus::Rejected,
+                Some(0),
+                0,
+                vec![],
+                None,
+            )
+            .unwrap(),
+            TransactionReceipt::from_aborted(
+                rng.gen(),
+                Field::<CurrentNetwork>::rand(rng).into(),
+                Some("Duplicate input".to_string()),
+            )
+            .unwrap(),
+            TransactionReceipt::from_aborted(rng.gen(), Field::<CurrentNetwork>::rand(rng).into(), None).unwrap(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type Receipt = TransactionReceipt<CurrentNetwork>;
+
+    #[test]
+    fn test_new() {
+        let rng = &mut TestRng::default();
+
+        let transaction_id = console::types::Field::<CurrentNetwork>::rand(rng).into();
+
+        // Ensure an accepted receipt requires an index, and has no reason.
+        assert!(Receipt::new(transaction_id, 1, ReceiptStatus::Accepted, None, 1, vec![], None).is_err());
+        let reason = Some("reason".to_string());
+        assert!(Receipt::new(transaction_id, 1, ReceiptStatus::Accepted, Some(0), 1, vec![], reason).is_err());
+        // Ensure an aborted receipt has no index, and no fee.
+        assert!(Receipt::new(transaction_id, 1, ReceiptStatus::Aborted, Some(0), 0, vec![], None).is_err());
+        assert!(Receipt::new(transaction_id, 1, ReceiptStatus::Aborted, None, 1, vec![], None).is_err());
+
+        // Ensure a long reason is truncated on a character boundary.
+        let reason = "é".repeat(Receipt::MAX_REASON_BYTES);
+        let receipt = Receipt::from_aborted(1, transaction_id, Some(reason)).unwrap();
+        assert_eq!(receipt.reason().unwrap().len(), Receipt::MAX_REASON_BYTES);
+        assert!(receipt.is_aborted());
+        assert_eq!(receipt.index(), None);
+    }
+}
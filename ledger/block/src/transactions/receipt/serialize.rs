@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for TransactionReceipt<N> {
+    /// Serializes the transaction receipt into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let num_fields = 5 + self.index.is_some() as usize + self.reason.is_some() as usize;
+                let mut object = serializer.serialize_struct("TransactionReceipt", num_fields)?;
+                object.serialize_field("transaction_id", &self.transaction_id)?;
+                object.serialize_field("block_height", &self.block_height)?;
+                object.serialize_field("status", self.status.variant())?;
+                if let Some(index) = &self.index {
+                    object.serialize_field("index", index)?;
+                }
+                object.serialize_field("fee", &self.fee)?;
+                object.serialize_field("finalize", &self.finalize)?;
+                if let Some(reason) = &self.reason {
+                    object.serialize_field("reason", reason)?;
+                }
+                object.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransactionReceipt<N> {
+    /// Deserializes the transaction receipt from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the transaction receipt from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Parse the transaction ID.
+                let transaction_id = DeserializeExt::take_from_value::<D>(&mut object, "transaction_id")?;
+                // Parse the block height.
+                let block_height = DeserializeExt::take_from_value::<D>(&mut object, "block_height")?;
+                // Parse the status.
+                let status = match object.get("status").and_then(|status| status.as_str()) {
+                    Some("accepted") => ReceiptStatus::Accepted,
+                    Some("rejected") => ReceiptStatus::Rejected,
+                    Some("aborted") => ReceiptStatus::Aborted,
+                    _ => return Err(de::Error::custom("Invalid transaction receipt status")),
+                };
+                // Parse the index, if it exists.
+                let index =
+                    serde_json::from_value(object.get_mut("index").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
+                // Parse the fee.
+                let fee = DeserializeExt::take_from_value::<D>(&mut object, "fee")?;
+                // Parse the finalize operations.
+                let finalize = DeserializeExt::take_from_value::<D>(&mut object, "finalize")?;
+                // Parse the reason, if it exists.
+                let reason =
+                    serde_json::from_value(object.get_mut("reason").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
+
+                // Return the transaction receipt.
+                Self::new(transaction_id, block_height, status, index, fee, finalize, reason).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction receipt"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_receipts(rng) {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, &candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, TransactionReceipt::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_receipts(rng) {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, TransactionReceipt::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
@@ -36,6 +36,91 @@ impl<N: Network> Block<N> {
         current_epoch_challenge: &EpochChallenge<N>,
         current_timestamp: i64,
         ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<(Vec<PuzzleCommitment<N>>, Vec<N::TransactionID>)> {
+        self.verify_internal(
+            previous_block,
+            current_state_root,
+            previous_committee_lookback,
+            current_committee_lookback,
+            current_puzzle,
+            current_epoch_challenge,
+            current_timestamp,
+            ratified_finalize_operations,
+            false,
+        )
+    }
+
+    /// Ensures the block is correct, excluding the checks in `Block::verify_stateless`.
+    ///
+    /// Note: This method must only be called on a block that passed `Block::verify_stateless`,
+    /// with the proof target of the given previous block, and the given puzzle and epoch challenge.
+    pub fn verify_preverified(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        previous_committee_lookback: &Committee<N>,
+        current_committee_lookback: &Committee<N>,
+        current_puzzle: &CoinbasePuzzle<N>,
+        current_epoch_challenge: &EpochChallenge<N>,
+        current_timestamp: i64,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<(Vec<PuzzleCommitment<N>>, Vec<N::TransactionID>)> {
+        self.verify_internal(
+            previous_block,
+            current_state_root,
+            previous_committee_lookback,
+            current_committee_lookback,
+            current_puzzle,
+            current_epoch_challenge,
+            current_timestamp,
+            ratified_finalize_operations,
+            true,
+        )
+    }
+
+    /// Ensures the checks of the block that do not depend on the ledger state are correct.
+    /// Namely, this checks the beacon signature and the puzzle proofs of the block,
+    /// which allows these checks to run in parallel, ahead of `Block::verify_preverified`.
+    pub fn verify_stateless(
+        &self,
+        previous_proof_target: u64,
+        current_puzzle: &CoinbasePuzzle<N>,
+        current_epoch_challenge: &EpochChallenge<N>,
+    ) -> Result<()> {
+        let height = self.height();
+
+        // Ensure the beacon signature is valid.
+        if let Authority::Beacon(signature) = &self.authority {
+            ensure!(
+                signature.verify(&signature.to_address(), &[*self.block_hash]),
+                "Signature is invalid in block {height}"
+            );
+        }
+
+        // Ensure the puzzle proofs are valid.
+        if let Some(coinbase) = self.solutions.deref() {
+            if let Err(e) = current_puzzle.check_solutions(coinbase, current_epoch_challenge, previous_proof_target) {
+                bail!("Block {height} contains an invalid puzzle proof - {e}");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Ensures the block is correct.
+    /// If `is_preverified` is `true`, the checks in `Block::verify_stateless` are skipped.
+    fn verify_internal(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        previous_committee_lookback: &Committee<N>,
+        current_committee_lookback: &Committee<N>,
+        current_puzzle: &CoinbasePuzzle<N>,
+        current_epoch_challenge: &EpochChallenge<N>,
+        current_timestamp: i64,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+        is_preverified: bool,
     ) -> Result<(Vec<PuzzleCommitment<N>>, Vec<N::TransactionID>)> {
         // Ensure the block hash is correct.
         self.verify_hash(previous_block.height(), previous_block.hash())?;
@@ -52,6 +137,7 @@ impl<N: Network> Block<N> {
             previous_block.height(),
             previous_committee_lookback,
             current_committee_lookback,
+            is_preverified,
         )?;
 
         // Ensure the block solutions are correct.
@@ -64,7 +150,7 @@ impl<N: Network> Block<N> {
             expected_last_coinbase_timestamp,
             expected_block_reward,
             expected_puzzle_reward,
-        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge)?;
+        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge, is_preverified)?;
 
         // Ensure the block ratifications are correct.
         self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
@@ -151,6 +237,7 @@ impl<N: Network> Block<N> {
         previous_height: u32,
         previous_committee_lookback: &Committee<N>,
         current_committee_lookback: &Committee<N>,
+        is_preverified: bool,
     ) -> Result<(u64, u32, i64, Vec<PuzzleCommitment<N>>, Vec<N::TransactionID>)> {
         // Note: Do not remove this. This ensures that all blocks after genesis are quorum blocks.
         #[cfg(not(any(test, feature = "test")))]
@@ -199,7 +286,7 @@ impl<N: Network> Block<N> {
                 );
                 // Ensure the signature is valid.
                 ensure!(
-                    signature.verify(&signer, &[*self.block_hash]),
+                    is_preverified || signature.verify(&signer, &[*self.block_hash]),
                     "Signature is invalid in block {expected_height}"
                 );
 
@@ -306,6 +393,7 @@ impl<N: Network> Block<N> {
         previous_block: &Block<N>,
         current_puzzle: &CoinbasePuzzle<N>,
         current_epoch_challenge: &EpochChallenge<N>,
+        is_preverified: bool,
     ) -> Result<(u128, u128, u64, u64, u64, i64, u64, u64)> {
         let height = self.height();
         let timestamp = self.timestamp();
@@ -347,11 +435,13 @@ impl<N: Network> Block<N> {
 
         let (expected_cumulative_proof_target, is_coinbase_target_reached) = match self.solutions.deref() {
             Some(coinbase) => {
-                // Ensure the puzzle proof is valid.
-                if let Err(e) =
-                    current_puzzle.check_solutions(coinbase, current_epoch_challenge, previous_block.proof_target())
-                {
-                    bail!("Block {height} contains an invalid puzzle proof - {e}");
+                // Ensure the puzzle proof is valid, if it has not been verified before.
+                if !is_preverified {
+                    if let Err(e) =
+                        current_puzzle.check_solutions(coinbase, current_epoch_challenge, previous_block.proof_target())
+                    {
+                        bail!("Block {height} contains an invalid puzzle proof - {e}");
+                    }
                 }
 
                 // Ensure that the block cumulative proof target is less than the previous block's coinbase target.
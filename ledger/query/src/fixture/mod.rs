@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod recorder;
+pub use recorder::*;
+
+mod serialize;
+
+use crate::QueryTrait;
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use indexmap::IndexMap;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// A query that is answered from fixtures, i.e. the state root, state paths and programs recorded from a ledger.
+///
+/// The fixtures are recorded with a `QueryRecorder` during a live run, and saved as JSON,
+/// so the tests that query the ledger can replay them deterministically and offline.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FixtureQuery<N: Network> {
+    /// The current state root.
+    state_root: Option<N::StateRoot>,
+    /// The state paths, indexed by their commitment.
+    state_paths: IndexMap<Field<N>, StatePath<N>>,
+    /// The programs, indexed by their program ID.
+    programs: IndexMap<ProgramID<N>, Program<N>>,
+}
+
+impl<N: Network> Default for FixtureQuery<N> {
+    /// Initializes empty fixtures.
+    fn default() -> Self {
+        Self { state_root: None, state_paths: Default::default(), programs: Default::default() }
+    }
+}
+
+impl<N: Network> FixtureQuery<N> {
+    /// Initializes empty fixtures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the fixtures from the JSON file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).map_err(|error| anyhow!("Failed to open the fixtures at {}: {error}", path.display()))?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+
+    /// Saves the fixtures as a JSON file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        Ok(writer.flush()?)
+    }
+
+    /// Returns the recorded state root.
+    pub const fn state_root(&self) -> Option<&N::StateRoot> {
+        self.state_root.as_ref()
+    }
+
+    /// Returns the recorded state paths, indexed by their commitment.
+    pub const fn state_paths(&self) -> &IndexMap<Field<N>, StatePath<N>> {
+        &self.state_paths
+    }
+
+    /// Returns the recorded programs, indexed by their program ID.
+    pub const fn programs(&self) -> &IndexMap<ProgramID<N>, Program<N>> {
+        &self.programs
+    }
+
+    /// Records the given state root as the current state root.
+    pub fn insert_state_root(&mut self, state_root: N::StateRoot) {
+        self.state_root = Some(state_root);
+    }
+
+    /// Records the state path for the given `commitment`.
+    pub fn insert_state_path(&mut self, commitment: Field<N>, state_path: StatePath<N>) {
+        self.state_paths.insert(commitment, state_path);
+    }
+
+    /// Records the given program.
+    pub fn insert_program(&mut self, program: Program<N>) {
+        self.programs.insert(*program.id(), program);
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.programs.get(program_id).cloned().ok_or_else(|| anyhow!("Program {program_id} not found in the fixtures"))
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get_program(program_id)
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for FixtureQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        self.state_root.ok_or_else(|| anyhow!("State root not found in the fixtures"))
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.state_paths
+            .get(commitment)
+            .cloned()
+            .ok_or_else(|| anyhow!("State path for commitment {commitment} not found in the fixtures"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Query;
+    use console::network::MainnetV0;
+    use ledger_block::Block;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_record_and_replay() {
+        // Initialize a block store with the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Record the queries of a live run.
+        let recorder = QueryRecorder::new(Query::<CurrentNetwork, BlockMemory<_>>::from(&block_store));
+        let state_root = recorder.current_state_root().unwrap();
+        let commitments = block.transitions().flat_map(|transition| transition.commitments()).collect::<Vec<_>>();
+        for commitment in &commitments {
+            recorder.get_state_path_for_commitment(commitment).unwrap();
+        }
+        // Ensure a failed query is not recorded.
+        assert!(recorder.get_program(&ProgramID::from_str("missing.aleo").unwrap()).is_err());
+
+        // Save and load the fixtures.
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("fixtures.json");
+        recorder.save(&path).unwrap();
+        let mut fixtures = FixtureQuery::<CurrentNetwork>::load(&path).unwrap();
+        assert!(fixtures == recorder.fixtures());
+        assert_eq!(fixtures.state_paths().len(), commitments.len());
+        assert!(fixtures.programs().is_empty());
+
+        // Ensure the fixtures replay the recorded queries.
+        assert_eq!(fixtures.current_state_root().unwrap(), state_root);
+        for commitment in commitments {
+            assert_eq!(
+                fixtures.get_state_path_for_commitment(commitment).unwrap(),
+                block_store.get_state_path_for_commitment(commitment).unwrap()
+            );
+        }
+
+        // Ensure the queries that were not recorded fail.
+        assert!(fixtures.get_state_path_for_commitment(&Field::from_u64(1)).is_err());
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+        assert!(fixtures.get_program(program.id()).is_err());
+
+        // Ensure the programs can be added to the fixtures.
+        fixtures.insert_program(program.clone());
+        assert!(fixtures.get_program(program.id()).unwrap() == program);
+    }
+
+    #[test]
+    fn test_empty_fixtures() {
+        let fixtures = FixtureQuery::<CurrentNetwork>::new();
+        assert!(fixtures.current_state_root().is_err());
+        assert!(fixtures.get_state_path_for_commitment(&Field::from_u64(1)).is_err());
+        assert!(FixtureQuery::<CurrentNetwork>::load("missing-fixtures.json").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Query;
+use ledger_store::BlockStorage;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A query that forwards each query to a live `Query`, and records its successful responses as fixtures.
+///
+/// The clones of a recorder share its fixtures, so the recorder can be given by value to a `Trace`,
+/// and saved once the run is finished.
+#[derive(Clone)]
+pub struct QueryRecorder<N: Network, B: BlockStorage<N>> {
+    /// The live query.
+    query: Query<N, B>,
+    /// The recorded fixtures.
+    fixtures: Arc<RwLock<FixtureQuery<N>>>,
+}
+
+impl<N: Network, B: BlockStorage<N>> QueryRecorder<N, B> {
+    /// Initializes a new recorder of the given live query.
+    pub fn new(query: impl Into<Query<N, B>>) -> Self {
+        Self { query: query.into(), fixtures: Default::default() }
+    }
+
+    /// Returns the fixtures recorded so far.
+    pub fn fixtures(&self) -> FixtureQuery<N> {
+        self.fixtures.read().clone()
+    }
+
+    /// Saves the fixtures recorded so far as a JSON file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.fixtures.read().save(path)
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let program = self.query.get_program(program_id)?;
+        self.fixtures.write().insert_program(program.clone());
+        Ok(program)
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let program = self.query.get_program_async(program_id).await?;
+        self.fixtures.write().insert_program(program.clone());
+        Ok(program)
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for QueryRecorder<N, B> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        let state_root = self.query.current_state_root()?;
+        self.fixtures.write().insert_state_root(state_root);
+        Ok(state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        let state_root = self.query.current_state_root_async().await?;
+        self.fixtures.write().insert_state_root(state_root);
+        Ok(state_root)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_path = self.query.get_state_path_for_commitment(commitment)?;
+        self.fixtures.write().insert_state_path(*commitment, state_path.clone());
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_path = self.query.get_state_path_for_commitment_async(commitment).await?;
+        self.fixtures.write().insert_state_path(*commitment, state_path.clone());
+        Ok(state_path)
+    }
+}
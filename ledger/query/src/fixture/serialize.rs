@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for FixtureQuery<N> {
+    /// Serializes the fixtures to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fixtures = serializer.serialize_struct("FixtureQuery", 3)?;
+        fixtures.serialize_field("state_root", &self.state_root)?;
+        fixtures.serialize_field("state_paths", &self.state_paths)?;
+        fixtures.serialize_field("programs", &self.programs)?;
+        fixtures.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FixtureQuery<N> {
+    /// Deserializes the fixtures from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fixtures = serde_json::Value::deserialize(deserializer)?;
+        let programs: IndexMap<ProgramID<N>, Program<N>> =
+            DeserializeExt::take_from_value::<D>(&mut fixtures, "programs")?;
+
+        // Ensure each program is indexed by its own program ID.
+        if let Some((program_id, program)) = programs.iter().find(|(program_id, program)| program.id() != *program_id) {
+            return Err(de::Error::custom(format!("Program {} is recorded as {program_id}", program.id())));
+        }
+
+        Ok(Self {
+            state_root: DeserializeExt::take_from_value::<D>(&mut fixtures, "state_root")?,
+            state_paths: DeserializeExt::take_from_value::<D>(&mut fixtures, "state_paths")?,
+            programs,
+        })
+    }
+}
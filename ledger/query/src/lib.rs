@@ -18,10 +18,25 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "fixture")]
+mod fixture;
+#[cfg(feature = "fixture")]
+pub use fixture::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+#[cfg(feature = "query")]
+mod rest;
+#[cfg(feature = "query")]
+pub use rest::*;
+
+#[cfg(feature = "server")]
+mod server;
+#[cfg(feature = "server")]
+pub use server::*;
+
 mod traits;
 pub use traits::*;
@@ -12,50 +12,90 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{rest::percent_encode, QueryTrait, RestClient};
 use console::{
     network::prelude::*,
-    program::{ProgramID, StatePath},
+    program::{Identifier, Plaintext, ProgramID, StatePath, Value},
     types::Field,
 };
-use ledger_store::{BlockStorage, BlockStore};
+use ledger_block::Transaction;
+use ledger_store::{BlockStorage, BlockStore, FinalizeStorage, FinalizeStore};
 use synthesizer_program::Program;
 
+use std::sync::Arc;
+
 #[derive(Clone)]
 pub enum Query<N: Network, B: BlockStorage<N>> {
-    /// The block store from the VM.
-    VM(BlockStore<N, B>),
-    /// The base URL of the node.
-    REST(String),
+    /// The block store from the VM, along with the mappings of its finalize store, if they may be queried.
+    VM(BlockStore<N, B>, Option<Arc<dyn MappingStore<N>>>),
+    /// The REST client of the node, which keeps its configuration and its response cache across queries.
+    REST(RestClient),
+}
+
+/// The mappings of a finalize store, which may be queried regardless of the storage of the store.
+pub trait MappingStore<N: Network>: Send + Sync {
+    /// Returns the confirmed value for the given `key` in the given mapping, or `None` if the key is not in the mapping.
+    fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>>;
+}
+
+impl<N: Network, P: FinalizeStorage<N>> MappingStore<N> for FinalizeStore<N, P> {
+    /// Returns the confirmed value for the given `key` in the given mapping, or `None` if the key is not in the mapping.
+    fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.get_value_confirmed(*program_id, *mapping_name, key)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
     fn from(block_store: BlockStore<N, B>) -> Self {
-        Self::VM(block_store)
+        Self::VM(block_store, None)
     }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     fn from(block_store: &BlockStore<N, B>) -> Self {
-        Self::VM(block_store.clone())
+        Self::VM(block_store.clone(), None)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>, P: FinalizeStorage<N>> From<(BlockStore<N, B>, FinalizeStore<N, P>)>
+    for Query<N, B>
+{
+    fn from((block_store, finalize_store): (BlockStore<N, B>, FinalizeStore<N, P>)) -> Self {
+        Self::VM(block_store, Some(Arc::new(finalize_store)))
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> From<RestClient> for Query<N, B> {
+    fn from(client: RestClient) -> Self {
+        Self::REST(client)
     }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<String> for Query<N, B> {
     fn from(url: String) -> Self {
-        Self::REST(url)
+        Self::REST(RestClient::new(url))
     }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<&String> for Query<N, B> {
     fn from(url: &String) -> Self {
-        Self::REST(url.to_string())
+        Self::REST(RestClient::new(url.as_str()))
     }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<&str> for Query<N, B> {
     fn from(url: &str) -> Self {
-        Self::REST(url.to_string())
+        Self::REST(RestClient::new(url))
     }
 }
 
@@ -64,13 +104,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     /// Returns the current state root.
     fn current_state_root(&self) -> Result<N::StateRoot> {
         match self {
-            Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/latest/stateRoot"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::VM(block_store, _) => Ok(block_store.current_state_root()),
+            Self::REST(client) => client.get::<N, _>("latest/stateRoot"),
         }
     }
 
@@ -78,26 +113,16 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     #[cfg(feature = "async")]
     async fn current_state_root_async(&self) -> Result<N::StateRoot> {
         match self {
-            Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/latest/stateRoot")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::VM(block_store, _) => Ok(block_store.current_state_root()),
+            Self::REST(client) => client.get_async::<N, _>("latest/stateRoot").await,
         }
     }
 
     /// Returns a state path for the given `commitment`.
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
-            Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/statePath/{commitment}"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::VM(block_store, _) => block_store.get_state_path_for_commitment(commitment),
+            Self::REST(client) => client.get::<N, _>(&format!("statePath/{commitment}")),
         }
     }
 
@@ -105,13 +130,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     #[cfg(feature = "async")]
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
-            Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/statePath/{commitment}")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::VM(block_store, _) => block_store.get_state_path_for_commitment(commitment),
+            Self::REST(client) => client.get_async::<N, _>(&format!("statePath/{commitment}")).await,
         }
     }
 }
@@ -120,15 +140,10 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     /// Returns the program for the given program ID.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
-            Self::VM(block_store) => {
+            Self::VM(block_store, _) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/program/{program_id}"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(client) => client.get::<N, _>(&format!("program/{program_id}")),
         }
     }
 
@@ -136,28 +151,111 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     #[cfg(feature = "async")]
     pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
-            Self::VM(block_store) => {
+            Self::VM(block_store, _) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/program/{program_id}")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(client) => client.get_async::<N, _>(&format!("program/{program_id}")).await,
+        }
+    }
+
+    /// Returns the value for the given `key` in the given mapping, or `None` if the key is not in the mapping.
+    /// Note: The block store holds no mappings, so the mapping values are only available from `Query::VM`
+    /// if it holds the mappings of a finalize store, e.g. if it was built from a block store and a finalize store.
+    pub fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        match self {
+            Self::VM(_, Some(finalize_store)) => finalize_store.get_mapping_value(program_id, mapping_name, key),
+            Self::VM(_, None) => bail!("Mapping values are not available from the block store alone"),
+            Self::REST(client) => client.get::<N, _>(&Self::mapping_value_path(program_id, mapping_name, key)),
+        }
+    }
+
+    /// Returns the value for the given `key` in the given mapping, or `None` if the key is not in the mapping.
+    /// Note: The block store holds no mappings, so the mapping values are only available from `Query::VM`
+    /// if it holds the mappings of a finalize store, e.g. if it was built from a block store and a finalize store.
+    #[cfg(feature = "async")]
+    pub async fn get_mapping_value_async(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        match self {
+            Self::VM(_, Some(finalize_store)) => finalize_store.get_mapping_value(program_id, mapping_name, key),
+            Self::VM(_, None) => bail!("Mapping values are not available from the block store alone"),
+            Self::REST(client) => {
+                client.get_async::<N, _>(&Self::mapping_value_path(program_id, mapping_name, key)).await
+            }
+        }
+    }
+
+    /// Returns the current block height.
+    pub fn current_block_height(&self) -> Result<u32> {
+        match self {
+            Self::VM(block_store, _) => {
+                block_store.current_block_height().ok_or_else(|| anyhow!("The block store is empty"))
+            }
+            Self::REST(client) => client.get::<N, _>("latest/height"),
+        }
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    pub async fn current_block_height_async(&self) -> Result<u32> {
+        match self {
+            Self::VM(block_store, _) => {
+                block_store.current_block_height().ok_or_else(|| anyhow!("The block store is empty"))
+            }
+            Self::REST(client) => client.get_async::<N, _>("latest/height").await,
+        }
+    }
+
+    /// Returns the confirmed transaction for the given transaction ID.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        match self {
+            Self::VM(block_store, _) => block_store
+                .get_transaction(transaction_id)?
+                .ok_or_else(|| anyhow!("Transaction {transaction_id} not found in storage")),
+            Self::REST(client) => client.get::<N, _>(&format!("transaction/{transaction_id}")),
+        }
+    }
+
+    /// Returns the confirmed transaction for the given transaction ID.
+    #[cfg(feature = "async")]
+    pub async fn get_transaction_async(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        match self {
+            Self::VM(block_store, _) => block_store
+                .get_transaction(transaction_id)?
+                .ok_or_else(|| anyhow!("Transaction {transaction_id} not found in storage")),
+            Self::REST(client) => client.get_async::<N, _>(&format!("transaction/{transaction_id}")).await,
         }
     }
 
-    /// Performs a GET request to the given URL.
-    fn get_request(url: &str) -> Result<ureq::Response> {
-        let response = ureq::get(url).call()?;
-        if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
+    /// Returns the hash of the block that contains the given transaction ID,
+    /// or `None` if the transaction is not confirmed.
+    pub fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        match self {
+            Self::VM(block_store, _) => block_store.find_block_hash(transaction_id),
+            Self::REST(client) => client.get::<N, _>(&format!("find/blockHash/{transaction_id}")),
+        }
     }
 
-    /// Performs a GET request to the given URL.
+    /// Returns the hash of the block that contains the given transaction ID,
+    /// or `None` if the transaction is not confirmed.
     #[cfg(feature = "async")]
-    async fn get_request_async(url: &str) -> Result<reqwest::Response> {
-        let response = reqwest::get(url).await?;
-        if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
+    pub async fn find_block_hash_async(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        match self {
+            Self::VM(block_store, _) => block_store.find_block_hash(transaction_id),
+            Self::REST(client) => client.get_async::<N, _>(&format!("find/blockHash/{transaction_id}")).await,
+        }
+    }
+
+    /// Returns the path of the endpoint of the given mapping value.
+    fn mapping_value_path(program_id: &ProgramID<N>, mapping_name: &Identifier<N>, key: &Plaintext<N>) -> String {
+        format!("program/{program_id}/mapping/{mapping_name}/{}", percent_encode(&key.to_string()))
     }
 }
@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// A client of the REST API of a node, which caches its responses and retries its transient failures.
+///
+/// The responses are only cached if a cache TTL is set, and at most the cache capacity of them are kept,
+/// evicting the oldest response first. The clones of a client share its cache.
+#[derive(Clone)]
+pub struct RestClient {
+    /// The base URL of the node.
+    url: String,
+    /// The duration for which a response is cached, or `None` if the responses are not cached.
+    cache_ttl: Option<Duration>,
+    /// The maximum number of cached responses.
+    cache_capacity: usize,
+    /// The maximum number of retries of a request that failed transiently.
+    max_retries: u32,
+    /// The delay before the first retry, which doubles with each retry.
+    initial_backoff: Duration,
+    /// The cached responses, indexed by their URL, along with the time they were fetched.
+    cache: Arc<RwLock<HashMap<String, (Instant, String)>>>,
+}
+
+impl RestClient {
+    /// The default maximum number of cached responses.
+    pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+    /// The default delay before the first retry.
+    pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+    /// The default maximum number of retries of a request that failed transiently.
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// Initializes a new client of the node at the given base URL, without a response cache.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            cache_ttl: None,
+            cache_capacity: Self::DEFAULT_CACHE_CAPACITY,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            initial_backoff: Self::DEFAULT_INITIAL_BACKOFF,
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns the client with its responses cached for the given duration.
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = Some(cache_ttl);
+        self
+    }
+
+    /// Returns the client with the given maximum number of cached responses.
+    pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity = cache_capacity;
+        self
+    }
+
+    /// Returns the client with the given maximum number of retries, and the delay before the first retry.
+    /// A transient failure is a connection failure, a timeout, or a `429`, `502`, `503` or `504` status.
+    pub fn with_retries(mut self, max_retries: u32, initial_backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Returns the base URL of the node.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Removes all the cached responses.
+    pub fn clear_cache(&self) {
+        self.cache.write().clear();
+    }
+
+    /// Performs a GET request to the given path of the network endpoints, and returns its JSON response.
+    pub(crate) fn get<N: Network, T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.endpoint::<N>(path)?;
+        let body = match self.cached(&url) {
+            Some(body) => body,
+            None => {
+                let body = self.fetch(&url)?;
+                self.cache(url, &body);
+                body
+            }
+        };
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Performs a GET request to the given path of the network endpoints, and returns its JSON response.
+    #[cfg(feature = "async")]
+    pub(crate) async fn get_async<N: Network, T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.endpoint::<N>(path)?;
+        let body = match self.cached(&url) {
+            Some(body) => body,
+            None => {
+                let body = self.fetch_async(&url).await?;
+                self.cache(url, &body);
+                body
+            }
+        };
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Returns the URL of the given path of the network endpoints.
+    fn endpoint<N: Network>(&self, path: &str) -> Result<String> {
+        match N::ID {
+            console::network::MainnetV0::ID => Ok(format!("{}/mainnet/{path}", self.url)),
+            _ => bail!("Unsupported network ID in inclusion query"),
+        }
+    }
+
+    /// Returns the cached response of the given URL, if it has not expired.
+    fn cached(&self, url: &str) -> Option<String> {
+        let cache_ttl = self.cache_ttl?;
+        match self.cache.read().get(url) {
+            Some((fetched_at, body)) if fetched_at.elapsed() < cache_ttl => Some(body.clone()),
+            _ => None,
+        }
+    }
+
+    /// Caches the given response of the given URL, and removes the expired responses.
+    /// If the cache is full, the oldest response is evicted.
+    fn cache(&self, url: String, body: &str) {
+        if let Some(cache_ttl) = self.cache_ttl {
+            let mut cache = self.cache.write();
+            cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < cache_ttl);
+            // Evict the oldest responses, until there is room for the given response.
+            while !cache.contains_key(&url) && !cache.is_empty() && cache.len() >= self.cache_capacity {
+                let oldest = cache.iter().min_by_key(|(_, (fetched_at, _))| *fetched_at).map(|(url, _)| url.clone());
+                if let Some(oldest) = oldest {
+                    cache.remove(&oldest);
+                }
+            }
+            if self.cache_capacity > 0 {
+                cache.insert(url, (Instant::now(), body.to_string()));
+            }
+        }
+    }
+
+    /// Performs a GET request to the given URL, retrying its transient failures.
+    fn fetch(&self, url: &str) -> Result<String> {
+        let mut backoff = self.initial_backoff;
+        let mut num_retries = 0;
+        loop {
+            let error = match ureq::get(url).call() {
+                Ok(response) if response.status() == 200 => return Ok(response.into_string()?),
+                Ok(_) => bail!("Failed to fetch from {url}"),
+                Err(error) => error,
+            };
+            // Retry the transient failures, up to the maximum number of retries.
+            let is_transient = match &error {
+                ureq::Error::Status(status, _) => is_transient_status(*status),
+                ureq::Error::Transport(_) => true,
+            };
+            if !is_transient || num_retries >= self.max_retries {
+                bail!("Failed to fetch from {url}: {error}")
+            }
+            std::thread::sleep(backoff);
+            backoff *= 2;
+            num_retries += 1;
+        }
+    }
+
+    /// Performs a GET request to the given URL, retrying its transient failures.
+    #[cfg(feature = "async")]
+    async fn fetch_async(&self, url: &str) -> Result<String> {
+        let mut backoff = self.initial_backoff;
+        let mut num_retries = 0;
+        loop {
+            // Retry the transient failures, up to the maximum number of retries.
+            let is_transient = match reqwest::get(url).await {
+                Ok(response) if response.status() == 200 => return Ok(response.text().await?),
+                Ok(response) => is_transient_status(response.status().as_u16()),
+                Err(error) => error.is_connect() || error.is_timeout(),
+            };
+            if !is_transient || num_retries >= self.max_retries {
+                bail!("Failed to fetch from {url}")
+            }
+            sleep(backoff).await;
+            backoff *= 2;
+            num_retries += 1;
+        }
+    }
+}
+
+/// Waits for the given duration.
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Waits for the given duration, with the `setTimeout` of the JavaScript runtime.
+/// Note: If the runtime has no `setTimeout`, the wait ends immediately.
+#[cfg(all(feature = "async", target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    use js_sys::{
+        wasm_bindgen::{JsCast, JsValue},
+        Function,
+        Promise,
+        Reflect,
+    };
+
+    let promise = Promise::new(&mut |resolve, _| {
+        let global = js_sys::global();
+        match Reflect::get(&global, &JsValue::from_str("setTimeout")).map(JsCast::dyn_into::<Function>) {
+            Ok(Ok(set_timeout)) => {
+                let _ = set_timeout.call2(&global, &resolve, &JsValue::from_f64(duration.as_secs_f64() * 1000.0));
+            }
+            _ => {
+                let _ = resolve.call0(&JsValue::UNDEFINED);
+            }
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Returns `true` if the given HTTP status is a transient failure, i.e. the request may succeed if retried.
+const fn is_transient_status(status: u16) -> bool {
+    matches!(status, 429 | 502 | 503 | 504)
+}
+
+/// Percent-encodes the given path segment, so it may contain any character.
+pub(crate) fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(char::from(byte)),
+            byte => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("credits.aleo"), "credits.aleo");
+        assert_eq!(percent_encode("aleo1_-~"), "aleo1_-~");
+        assert_eq!(percent_encode("{ a: 1u8 }"), "%7B%20a%3A%201u8%20%7D");
+    }
+
+    #[test]
+    fn test_cache_disabled_by_default() {
+        let client = RestClient::new("http://127.0.0.1:0");
+        client.cache("http://127.0.0.1:0/mainnet/latest/height".to_string(), "0");
+        assert!(client.cached("http://127.0.0.1:0/mainnet/latest/height").is_none());
+
+        // Ensure the cached responses expire.
+        let client = client.with_cache_ttl(Duration::ZERO);
+        client.cache("http://127.0.0.1:0/mainnet/latest/height".to_string(), "0");
+        assert!(client.cached("http://127.0.0.1:0/mainnet/latest/height").is_none());
+        let client = client.with_cache_ttl(Duration::from_secs(60));
+        client.cache("http://127.0.0.1:0/mainnet/latest/height".to_string(), "0");
+        assert_eq!(client.cached("http://127.0.0.1:0/mainnet/latest/height").unwrap(), "0");
+    }
+
+    #[test]
+    fn test_cache_capacity() {
+        let client =
+            RestClient::new("http://127.0.0.1:0").with_cache_ttl(Duration::from_secs(60)).with_cache_capacity(2);
+        client.cache("a".to_string(), "0");
+        std::thread::sleep(Duration::from_millis(1));
+        client.cache("b".to_string(), "1");
+        std::thread::sleep(Duration::from_millis(1));
+
+        // Ensure a cached response is replaced without evicting another response.
+        client.cache("b".to_string(), "2");
+        assert_eq!(client.cached("a").unwrap(), "0");
+        assert_eq!(client.cached("b").unwrap(), "2");
+
+        // Ensure the oldest response is evicted once the cache is full.
+        client.cache("c".to_string(), "3");
+        assert!(client.cached("a").is_none());
+        assert_eq!(client.cached("b").unwrap(), "2");
+        assert_eq!(client.cached("c").unwrap(), "3");
+
+        // Ensure nothing is cached without capacity.
+        let client = client.with_cache_capacity(0);
+        client.clear_cache();
+        client.cache("a".to_string(), "0");
+        assert!(client.cached("a").is_none());
+    }
+}
@@ -0,0 +1,494 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+    types::Field,
+};
+use ledger_store::{BlockStorage, BlockStore, FinalizeStorage, FinalizeStore};
+use synthesizer_program::Program;
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// The maximum duration of a read or a write on a connection, after which its client is dropped.
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A local HTTP server, which serves the endpoints of the `Query` from a block store and a finalize store.
+///
+/// The endpoints are served under the network path, i.e. `{url}/mainnet/...`, so the URL of the server
+/// can be given to `Query::from`. The server is meant for local chains and tests: it handles one request
+/// at a time, and closes each connection after its response. A client that stalls a read or a write
+/// for longer than 5 seconds is dropped, so it cannot block the other clients. It is shut down when dropped.
+///
+/// The following `GET` endpoints are served:
+/// - `/latest/stateRoot`, `/latest/height`, `/latest/hash`, `/latest/block`
+/// - `/stateRoot/{height}`, `/statePath/{commitment}`
+/// - `/block/{height or hash}`
+/// - `/program/{program ID}`, `/program/{program ID}/mappings`
+/// - `/program/{program ID}/mapping/{mapping name}/{key}`
+/// - `/transaction/{transaction ID}`, `/find/blockHash/{transaction ID}`
+pub struct QueryServer {
+    /// The address the server is listening on.
+    address: SocketAddr,
+    /// The flag that stops the server.
+    is_shutdown: Arc<AtomicBool>,
+    /// The handle of the thread of the server.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl QueryServer {
+    /// Starts a server on the given address, which serves the endpoints from the given stores.
+    /// The address may use port `0`, in which case the port is chosen by the operating system.
+    pub fn start<N: Network, B: BlockStorage<N>, P: FinalizeStorage<N>>(
+        address: impl ToSocketAddrs,
+        block_store: BlockStore<N, B>,
+        finalize_store: FinalizeStore<N, P>,
+    ) -> Result<Self> {
+        let router = Router { network: network_path::<N>()?, block_store, finalize_store };
+
+        // Bind the listener, and retrieve the address it is listening on.
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+
+        let is_shutdown = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let is_shutdown = is_shutdown.clone();
+            std::thread::Builder::new().name("query-server".to_string()).spawn(move || {
+                for stream in listener.incoming() {
+                    // Stop the server once it is shut down.
+                    if is_shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    // Note: A failed connection only concerns its client, so it is skipped.
+                    if let Ok(stream) = stream {
+                        let _ = router.handle(stream);
+                    }
+                }
+            })?
+        };
+
+        Ok(Self { address, is_shutdown, handle: Some(handle) })
+    }
+
+    /// Returns the address the server is listening on.
+    pub const fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the base URL of the server, which can be given to `Query::from`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+}
+
+impl Drop for QueryServer {
+    /// Shuts down the server, and waits for its thread to finish.
+    fn drop(&mut self) {
+        self.is_shutdown.store(true, Ordering::SeqCst);
+        // Wake up the listener, which is blocked on the next connection.
+        let _ = TcpStream::connect(self.address);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Returns the path segment of the network, under which the endpoints are served.
+fn network_path<N: Network>() -> Result<&'static str> {
+    match N::ID {
+        console::network::MainnetV0::ID => Ok("mainnet"),
+        _ => bail!("Unsupported network ID in query server"),
+    }
+}
+
+/// The status of a response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Status {
+    Ok,
+    BadRequest,
+    NotFound,
+    MethodNotAllowed,
+    InternalServerError,
+}
+
+impl Status {
+    /// Returns the status line of the status.
+    const fn status_line(&self) -> &'static str {
+        match self {
+            Self::Ok => "200 OK",
+            Self::BadRequest => "400 Bad Request",
+            Self::NotFound => "404 Not Found",
+            Self::MethodNotAllowed => "405 Method Not Allowed",
+            Self::InternalServerError => "500 Internal Server Error",
+        }
+    }
+}
+
+/// A response, i.e. its status and its body, which is JSON for a successful response, and a message otherwise.
+type Response = (Status, String);
+
+/// Parses the given path segment, or returns a `400 Bad Request` response from the enclosing function.
+macro_rules! parse {
+    ($segment:expr, $type:ty, $name:expr) => {
+        match $segment.parse::<$type>() {
+            Ok(value) => value,
+            Err(_) => return Ok((Status::BadRequest, format!("Invalid {} '{}'", $name, $segment))),
+        }
+    };
+}
+
+/// The router of the requests to the stores.
+struct Router<N: Network, B: BlockStorage<N>, P: FinalizeStorage<N>> {
+    /// The path segment of the network.
+    network: &'static str,
+    /// The block store.
+    block_store: BlockStore<N, B>,
+    /// The finalize store.
+    finalize_store: FinalizeStore<N, P>,
+}
+
+impl<N: Network, B: BlockStorage<N>, P: FinalizeStorage<N>> Router<N, B, P> {
+    /// Reads the request from the given stream, and writes its response.
+    fn handle(&self, mut stream: TcpStream) -> Result<()> {
+        // Bound the duration of the reads and writes, as the connections are handled one at a time.
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        let mut reader = BufReader::new(&stream);
+
+        // Read the request line, i.e. `{method} {path} {version}`.
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Skip the headers, as the request has no body.
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 2 {
+            header.clear();
+        }
+
+        let (status, body) = match request_line.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["GET", target, _] => self.respond(target),
+            [_, _, _] => (Status::MethodNotAllowed, "Only GET requests are supported".to_string()),
+            _ => (Status::BadRequest, "Malformed request line".to_string()),
+        };
+
+        let content_type = match status {
+            Status::Ok => "application/json",
+            _ => "text/plain; charset=utf-8",
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            status.status_line(),
+            body.len()
+        )?;
+        Ok(stream.flush()?)
+    }
+
+    /// Returns the response to the given request target.
+    fn respond(&self, target: &str) -> Response {
+        // Split the path into its decoded segments, ignoring the query string.
+        let path = target.split('?').next().unwrap_or_default();
+        let segments = match path.split('/').filter(|segment| !segment.is_empty()).map(percent_decode).collect() {
+            Ok(segments) => segments,
+            Err(error) => return (Status::BadRequest, error.to_string()),
+        };
+
+        match self.route(segments) {
+            Ok(response) => response,
+            Err(error) => (Status::InternalServerError, error.to_string()),
+        }
+    }
+
+    /// Returns the response to the request with the given path segments.
+    fn route(&self, segments: Vec<String>) -> Result<Response> {
+        let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+        // Ensure the request is for the network of the server.
+        let segments = match segments.split_first() {
+            Some((network, segments)) if *network == self.network => segments,
+            _ => return Ok(not_found("Unknown endpoint")),
+        };
+
+        match segments {
+            ["latest", "stateRoot"] => json(&self.block_store.current_state_root()),
+            ["latest", "height"] => match self.block_store.current_block_height() {
+                Some(height) => json(&height),
+                None => Ok(not_found("The block store is empty")),
+            },
+            ["latest", "hash"] => match self.latest_block_hash()? {
+                Some(hash) => json(&hash),
+                None => Ok(not_found("The block store is empty")),
+            },
+            ["latest", "block"] => match self.latest_block_hash()? {
+                Some(hash) => self.block(&hash),
+                None => Ok(not_found("The block store is empty")),
+            },
+            ["stateRoot", height] => {
+                let height = parse!(height, u32, "block height");
+                match self.block_store.get_state_root(height)? {
+                    Some(state_root) => json(&state_root),
+                    None => Ok(not_found(format!("State root for block {height} not found"))),
+                }
+            }
+            ["statePath", commitment] => {
+                let commitment = parse!(commitment, Field<N>, "commitment");
+                json(&self.block_store.get_state_path_for_commitment(&commitment)?)
+            }
+            ["block", height_or_hash] => {
+                let hash = match height_or_hash.parse::<u32>() {
+                    Ok(height) => self.block_store.get_block_hash(height)?,
+                    Err(_) => Some(parse!(height_or_hash, N::BlockHash, "block height or hash")),
+                };
+                match hash {
+                    Some(hash) => self.block(&hash),
+                    None => Ok(not_found(format!("Block {height_or_hash} not found"))),
+                }
+            }
+            ["program", program_id] => {
+                let program_id = parse!(program_id, ProgramID<N>, "program ID");
+                match self.block_store.get_program(&program_id)? {
+                    Some(program) => json(&program),
+                    // Note: The credits program is not deployed, so it is served from its source.
+                    None if program_id.to_string() == "credits.aleo" => json(&Program::<N>::credits()?),
+                    None => Ok(not_found(format!("Program {program_id} not found"))),
+                }
+            }
+            ["program", program_id, "mappings"] => {
+                let program_id = parse!(program_id, ProgramID<N>, "program ID");
+                match self.finalize_store.get_mapping_names_confirmed(&program_id)? {
+                    Some(mapping_names) => json(&mapping_names),
+                    None => Ok(not_found(format!("Program {program_id} not found"))),
+                }
+            }
+            ["program", program_id, "mapping", mapping_name, key] => {
+                let program_id = parse!(program_id, ProgramID<N>, "program ID");
+                let mapping_name = parse!(mapping_name, Identifier<N>, "mapping name");
+                let key = parse!(key, Plaintext<N>, "mapping key");
+                // Note: A missing value is returned as `null`, as in the remote endpoint.
+                json(&self.finalize_store.get_value_confirmed(program_id, mapping_name, &key)?)
+            }
+            ["transaction", transaction_id] => {
+                let transaction_id = parse!(transaction_id, N::TransactionID, "transaction ID");
+                match self.block_store.get_transaction(&transaction_id)? {
+                    Some(transaction) => json(&transaction),
+                    None => Ok(not_found(format!("Transaction {transaction_id} not found"))),
+                }
+            }
+            ["find", "blockHash", transaction_id] => {
+                let transaction_id = parse!(transaction_id, N::TransactionID, "transaction ID");
+                json(&self.block_store.find_block_hash(&transaction_id)?)
+            }
+            _ => Ok(not_found("Unknown endpoint")),
+        }
+    }
+
+    /// Returns the hash of the latest block, or `None` if the block store is empty.
+    fn latest_block_hash(&self) -> Result<Option<N::BlockHash>> {
+        match self.block_store.current_block_height() {
+            Some(height) => self.block_store.get_block_hash(height),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the response with the block of the given hash.
+    fn block(&self, hash: &N::BlockHash) -> Result<Response> {
+        match self.block_store.get_block(hash)? {
+            Some(block) => json(&block),
+            None => Ok(not_found(format!("Block {hash} not found"))),
+        }
+    }
+}
+
+/// Returns a successful response with the given value as JSON.
+fn json<T: Serialize>(value: &T) -> Result<Response> {
+    Ok((Status::Ok, serde_json::to_string(value)?))
+}
+
+/// Returns a `404 Not Found` response with the given message.
+fn not_found(message: impl Into<String>) -> Response {
+    (Status::NotFound, message.into())
+}
+
+/// Decodes the percent-encoded characters of the given path segment.
+fn percent_decode(segment: &str) -> Result<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut iter = segment.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next(), iter.next()];
+                let hex = match hex {
+                    [Some(high), Some(low)] => [high, low],
+                    _ => bail!("Truncated percent-encoding in '{segment}'"),
+                };
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex)?, 16)?);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Query, QueryTrait, RestClient};
+    use console::{network::MainnetV0, program::Value};
+    use ledger_block::Block;
+    use ledger_store::helpers::memory::{BlockMemory, FinalizeMemory};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("credits.aleo").unwrap(), "credits.aleo");
+        assert_eq!(percent_decode("%7B%20a%3A%201u8%20%7D").unwrap(), "{ a: 1u8 }");
+        assert!(percent_decode("%7").is_err());
+        assert!(percent_decode("%zz").is_err());
+    }
+
+    #[test]
+    fn test_query_server() {
+        // Initialize the stores with the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Start the server.
+        let server = QueryServer::start("127.0.0.1:0", block_store.clone(), finalize_store.clone()).unwrap();
+        let query = Query::<CurrentNetwork, BlockMemory<_>>::from(server.url());
+
+        // Ensure the state root and the state paths are served.
+        assert_eq!(query.current_state_root().unwrap(), block_store.current_state_root());
+        let commitment = block.transitions().flat_map(|transition| transition.commitments()).next().unwrap();
+        assert_eq!(
+            query.get_state_path_for_commitment(commitment).unwrap(),
+            block_store.get_state_path_for_commitment(commitment).unwrap()
+        );
+
+        // Ensure the programs are served.
+        assert_eq!(
+            query.get_program(&ProgramID::from_str("credits.aleo").unwrap()).unwrap(),
+            Program::credits().unwrap()
+        );
+        assert!(query.get_program(&ProgramID::from_str("missing.aleo").unwrap()).is_err());
+
+        // Ensure the block height and the transactions are served.
+        assert_eq!(query.current_block_height().unwrap(), 0);
+        let transaction_id = block.transaction_ids().next().unwrap();
+        assert_eq!(
+            query.get_transaction(transaction_id).unwrap(),
+            block_store.get_transaction(transaction_id).unwrap().unwrap()
+        );
+        assert_eq!(query.find_block_hash(transaction_id).unwrap(), Some(block.hash()));
+        assert_eq!(query.find_block_hash(&Default::default()).unwrap(), None);
+
+        // Ensure the mapping values are served, including those of the keys that must be percent-encoded.
+        let program_id = ProgramID::from_str("test.aleo").unwrap();
+        let mapping_name = Identifier::from_str("values").unwrap();
+        let key = Plaintext::from_str("{ a: 1u8 }").unwrap();
+        let value = Value::from_str("5u64").unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.replace_mapping(program_id, mapping_name, vec![(key.clone(), value.clone())]).unwrap();
+        assert_eq!(query.get_mapping_value(&program_id, &mapping_name, &key).unwrap(), Some(value.clone()));
+        let missing_key = Plaintext::from_str("{ a: 2u8 }").unwrap();
+        assert_eq!(query.get_mapping_value(&program_id, &mapping_name, &missing_key).unwrap(), None);
+
+        // Ensure the mapping values are also served from the stores.
+        let vm_query = Query::<CurrentNetwork, BlockMemory<_>>::from((block_store.clone(), finalize_store.clone()));
+        assert_eq!(vm_query.get_mapping_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        assert_eq!(vm_query.get_mapping_value(&program_id, &mapping_name, &missing_key).unwrap(), None);
+        assert!(Query::from(&block_store).get_mapping_value(&program_id, &mapping_name, &key).is_err());
+
+        // Ensure the blocks are served.
+        let url = format!("{}/mainnet", server.url());
+        let get = |path: &str| ureq::get(&format!("{url}/{path}")).call();
+        assert_eq!(get("latest/height").unwrap().into_json::<u32>().unwrap(), 0);
+        assert_eq!(
+            get("latest/hash").unwrap().into_json::<<CurrentNetwork as Network>::BlockHash>().unwrap(),
+            block.hash()
+        );
+        assert_eq!(
+            get("block/0").unwrap().into_json::<serde_json::Value>().unwrap(),
+            serde_json::to_value(&block).unwrap()
+        );
+        let hash = block.hash();
+        assert_eq!(
+            get(&format!("block/{hash}")).unwrap().into_json::<serde_json::Value>().unwrap(),
+            serde_json::to_value(&block).unwrap()
+        );
+
+        // Ensure the missing and malformed requests are rejected.
+        assert!(matches!(get("block/1"), Err(ureq::Error::Status(404, _))));
+        assert!(matches!(get("stateRoot/latest"), Err(ureq::Error::Status(400, _))));
+        assert!(matches!(get("unknown"), Err(ureq::Error::Status(404, _))));
+    }
+
+    #[test]
+    fn test_query_cache_and_retries() {
+        // Initialize the stores with the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Start the server, and query it with a cache.
+        let server = QueryServer::start("127.0.0.1:0", block_store, finalize_store).unwrap();
+        let backoff = std::time::Duration::from_millis(10);
+        let client =
+            RestClient::new(server.url()).with_cache_ttl(std::time::Duration::from_secs(60)).with_retries(2, backoff);
+        let query = Query::<CurrentNetwork, BlockMemory<_>>::from(client.clone());
+        assert_eq!(query.current_block_height().unwrap(), 0);
+
+        // Ensure the cached responses are served once the server is shut down.
+        drop(server);
+        assert_eq!(query.current_block_height().unwrap(), 0);
+        assert!(query.get_transaction(&Default::default()).is_err());
+
+        // Ensure the requests are retried with a backoff once the cache is cleared.
+        client.clear_cache();
+        let timer = std::time::Instant::now();
+        assert!(query.current_block_height().is_err());
+        assert!(timer.elapsed() >= backoff * 3);
+    }
+
+    #[test]
+    fn test_query_server_stalled_client() {
+        // Initialize the stores with the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Start the server, and connect a client that never sends its request.
+        let server = QueryServer::start("127.0.0.1:0", block_store, finalize_store).unwrap();
+        let stalled = TcpStream::connect(server.address()).unwrap();
+
+        // Ensure the next client is served once the stalled client is dropped.
+        let timer = std::time::Instant::now();
+        let query = Query::<CurrentNetwork, BlockMemory<_>>::from(server.url());
+        assert_eq!(query.current_block_height().unwrap(), 0);
+        assert!(timer.elapsed() >= IO_TIMEOUT / 2);
+        drop(stalled);
+    }
+}
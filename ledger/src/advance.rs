@@ -14,6 +14,13 @@
 
 use super::*;
 
+use std::sync::mpsc;
+
+/// The number of blocks that are pre-verified together, during a bulk import.
+pub(crate) const BULK_IMPORT_BATCH_SIZE: usize = 8;
+/// The number of pre-verified batches that may be prepared ahead of the blocks being added to the ledger.
+const BULK_IMPORT_PIPELINE_DEPTH: usize = 2;
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns a candidate for the next block in the ledger, using a committed subdag and its transmissions.
     pub fn prepare_advance_to_next_quorum_block<R: Rng + CryptoRng>(
@@ -110,6 +117,152 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Checks and adds the given blocks to the ledger, in order, and returns the number of blocks added.
+    ///
+    /// The stateless checks of the blocks (the transaction proofs, the beacon signatures, and the puzzle proofs)
+    /// run in parallel on a separate thread, ahead of the stateful checks, which run in order as each block is added.
+    /// Blocks that are already in the ledger are skipped, after ensuring they match the ledger.
+    pub fn advance_to_next_blocks(&self, blocks: Vec<Block<N>>) -> Result<u32> {
+        let mut blocks = blocks.into_iter();
+        // Split the blocks into batches.
+        let batches = std::iter::from_fn(move || {
+            let batch = blocks.by_ref().take(BULK_IMPORT_BATCH_SIZE).collect::<Vec<_>>();
+            (!batch.is_empty()).then_some(Ok(batch))
+        });
+        self.advance_to_next_batches(batches)
+    }
+
+    /// Checks and adds the given batches of blocks to the ledger, in order, and returns the number of blocks added.
+    ///
+    /// The batches are pulled from the iterator, and pre-verified, on a separate thread,
+    /// ahead of the blocks being added to the ledger.
+    pub(crate) fn advance_to_next_batches(
+        &self,
+        batches: impl Iterator<Item = Result<Vec<Block<N>>>> + Send,
+    ) -> Result<u32> {
+        let timer = timer!("Ledger::advance_to_next_batches");
+
+        let num_added = std::thread::scope(|scope| {
+            // Initialize the channel of pre-verified blocks.
+            let (sender, receiver) = mpsc::sync_channel(BULK_IMPORT_PIPELINE_DEPTH);
+            // Pre-verify the blocks on a separate thread.
+            scope.spawn(move || {
+                if let Err(error) = self.preverify_batches(batches, &sender) {
+                    // Note: If the receiver has stopped, the error is not needed.
+                    let _ = sender.send(Err(error));
+                }
+            });
+            // Add the blocks to the ledger, in order.
+            // Note: The receiver is dropped on return, which stops the thread pre-verifying the blocks.
+            self.add_preverified_batches(receiver)
+        })?;
+
+        finish!(timer, "Added {num_added} blocks");
+        Ok(num_added)
+    }
+
+    /// Runs the stateless checks on each batch of blocks, and sends the blocks that passed them.
+    /// The blocks are checked against the preceding block in the batches, or the latest block in the ledger.
+    fn preverify_batches(
+        &self,
+        batches: impl Iterator<Item = Result<Vec<Block<N>>>>,
+        sender: &mpsc::SyncSender<Result<Vec<Block<N>>>>,
+    ) -> Result<()> {
+        // Retrieve the latest block height, as blocks at or below this height are skipped.
+        let latest_height = self.latest_height();
+        // Initialize the previous block, from the latest block in the ledger.
+        let latest_block = self.latest_block();
+        let (mut previous_height, mut previous_hash, mut previous_proof_target) =
+            (latest_block.height(), latest_block.hash(), latest_block.proof_target());
+        // Initialize the epoch challenge, from the latest epoch challenge in the ledger.
+        let mut epoch_challenge = self.latest_epoch_challenge()?;
+
+        for batch in batches {
+            let mut blocks = batch?;
+
+            // Determine the previous proof target and epoch challenge of each block, in order.
+            let mut contexts = Vec::with_capacity(blocks.len());
+            let mut result = Ok(());
+            for block in &blocks {
+                // Skip the block if it is already in the ledger.
+                if block.height() <= latest_height {
+                    contexts.push(None);
+                    continue;
+                }
+                // Ensure the block is the next block.
+                if block.height() != previous_height.saturating_add(1) || block.previous_hash() != previous_hash {
+                    result = Err(anyhow!("Block {} does not follow block {previous_height}", block.height()));
+                    break;
+                }
+                contexts.push(Some((previous_proof_target, epoch_challenge.clone())));
+                // Update the previous block.
+                (previous_height, previous_hash, previous_proof_target) =
+                    (block.height(), block.hash(), block.proof_target());
+                // If the block is the start of a new epoch, update the epoch challenge.
+                if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 {
+                    let epoch_number = block.height() / N::NUM_BLOCKS_PER_EPOCH;
+                    epoch_challenge =
+                        EpochChallenge::new(epoch_number, block.previous_hash(), N::COINBASE_PUZZLE_DEGREE)?;
+                }
+            }
+
+            // Run the stateless checks of the blocks, in parallel.
+            let results = cfg_iter!(blocks)
+                .zip(contexts)
+                .map(|(block, context)| match context {
+                    Some((previous_proof_target, epoch_challenge)) => {
+                        block.verify_stateless(previous_proof_target, self.coinbase_puzzle(), &epoch_challenge)
+                    }
+                    None => Ok(()),
+                })
+                .collect::<Vec<_>>();
+            // Keep the blocks that passed the stateless checks, up to the first invalid block.
+            let num_valid = results.iter().take_while(|result| result.is_ok()).count();
+            if let Some(Err(error)) = results.into_iter().nth(num_valid) {
+                result = Err(error);
+            }
+            blocks.truncate(num_valid);
+
+            // Pre-verify the proofs of the transactions.
+            let transactions = blocks
+                .iter()
+                .flat_map(|block| block.transactions().iter().map(|transaction| transaction.transaction()))
+                .collect::<Vec<_>>();
+            self.vm.preverify_transactions(&transactions, &mut OsRng);
+
+            // Send the blocks, and stop if the receiver has stopped.
+            if sender.send(Ok(blocks)).is_err() {
+                return Ok(());
+            }
+            // Stop if a block is invalid.
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Adds the received batches of pre-verified blocks to the ledger, in order, and returns the number of blocks added.
+    fn add_preverified_batches(&self, receiver: mpsc::Receiver<Result<Vec<Block<N>>>>) -> Result<u32> {
+        let mut num_added = 0u32;
+        for blocks in receiver {
+            for block in blocks? {
+                // Skip the block if it is already in the ledger.
+                if block.height() <= self.latest_height() {
+                    ensure!(
+                        self.get_hash(block.height())? == block.hash(),
+                        "Block {} does not match the block in the ledger",
+                        block.height()
+                    );
+                    continue;
+                }
+                // Check the block, and add it to the ledger.
+                self.check_next_block_internal(&block, true, &mut OsRng)?;
+                self.advance_to_next_block(&block)?;
+                num_added += 1;
+            }
+        }
+        Ok(num_added)
+    }
 }
 
 /// Splits candidate solutions into a collection of accepted ones and aborted ones.
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::advance::BULK_IMPORT_BATCH_SIZE;
+
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+
+/// The magic bytes at the start of a block archive.
+const BLOCK_ARCHIVE_MAGIC: [u8; 8] = *b"ALEOBLKS";
+/// The version of the block archive format.
+const BLOCK_ARCHIVE_VERSION: u8 = 1;
+/// The maximum number of bytes of a block in a block archive.
+const MAX_ARCHIVED_BLOCK_SIZE: u32 = 1 << 30;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Writes the blocks in the given range of heights to the writer, as a block archive,
+    /// and returns the number of blocks written.
+    ///
+    /// A block archive is a header (the magic bytes, the version, and the network ID), followed by a frame
+    /// for each block, and an empty frame. Each frame is the length of the block bytes, the block bytes,
+    /// and the SHA-256 checksum of the block bytes.
+    pub fn export_blocks<W: Write>(&self, heights: Range<u32>, mut writer: W) -> Result<u32> {
+        let timer = timer!("Ledger::export_blocks");
+
+        // Ensure the blocks exist in the ledger.
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "Cannot export blocks {heights:?} - the latest block height is {}",
+            self.latest_height()
+        );
+
+        // Write the header.
+        writer.write_all(&BLOCK_ARCHIVE_MAGIC)?;
+        BLOCK_ARCHIVE_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+        // Write each block.
+        for height in heights.clone() {
+            write_archive_frame(&mut writer, &self.get_block(height)?.to_bytes_le()?)?;
+        }
+        // Write the empty frame.
+        0u32.write_le(&mut writer)?;
+        writer.flush()?;
+
+        finish!(timer, "Exported blocks {heights:?}");
+        Ok(heights.end.saturating_sub(heights.start))
+    }
+
+    /// Reads a block archive from the reader, adds its blocks to the ledger, and returns the number of blocks added.
+    ///
+    /// The blocks are added with `Ledger::advance_to_next_blocks`, which skips the blocks that are already
+    /// in the ledger. To speed up the import, the archive is read and deserialized in batches on a separate thread,
+    /// along with the stateless checks of the blocks, ahead of the blocks being added to the ledger.
+    pub fn import_blocks<R: Read + Send>(&self, mut reader: R) -> Result<u32> {
+        // Read the header.
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == BLOCK_ARCHIVE_MAGIC, "Invalid block archive - incorrect magic bytes");
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == BLOCK_ARCHIVE_VERSION, "Invalid block archive - unsupported version {version}");
+        let network = u16::read_le(&mut reader)?;
+        ensure!(network == N::ID, "Invalid block archive - expected network {}, found {network}", N::ID);
+
+        // Read the blocks in batches, until the empty frame or the first error.
+        let mut is_finished = false;
+        let batches = std::iter::from_fn(move || {
+            if is_finished {
+                return None;
+            }
+            let batch = read_archive_batch(&mut reader, &mut is_finished);
+            is_finished |= batch.is_err();
+            match batch {
+                Ok(blocks) if blocks.is_empty() => None,
+                batch => Some(batch),
+            }
+        });
+        // Check and add the blocks to the ledger.
+        self.advance_to_next_batches(batches)
+    }
+}
+
+/// Reads and deserializes the next batch of blocks from the block archive.
+/// If the empty frame at the end of the archive is reached, `is_finished` is set to `true`.
+fn read_archive_batch<N: Network, R: Read>(mut reader: R, is_finished: &mut bool) -> Result<Vec<Block<N>>> {
+    // Read the block bytes.
+    let mut frames = Vec::with_capacity(BULK_IMPORT_BATCH_SIZE);
+    while frames.len() < BULK_IMPORT_BATCH_SIZE {
+        match read_archive_frame(&mut reader)? {
+            Some(bytes) => frames.push(bytes),
+            None => {
+                *is_finished = true;
+                break;
+            }
+        }
+    }
+    // Deserialize the blocks, in parallel.
+    cfg_into_iter!(frames)
+        .map(|bytes| Block::read_le(&bytes[..]).map_err(|e| anyhow!("Invalid block in the block archive - {e}")))
+        .collect()
+}
+
+/// Writes the given block bytes to the writer, as a block archive frame.
+fn write_archive_frame<W: Write>(mut writer: W, bytes: &[u8]) -> Result<()> {
+    let length = u32::try_from(bytes.len())?;
+    ensure!(length > 0 && length <= MAX_ARCHIVED_BLOCK_SIZE, "Invalid block size ({length} bytes)");
+    length.write_le(&mut writer)?;
+    writer.write_all(bytes)?;
+    writer.write_all(&Sha256::digest(bytes))?;
+    Ok(())
+}
+
+/// Reads the block bytes of the next block archive frame from the reader,
+/// or returns `None` if the frame is the empty frame at the end of the archive.
+fn read_archive_frame<R: Read>(mut reader: R) -> Result<Option<Vec<u8>>> {
+    let length = u32::read_le(&mut reader)?;
+    if length == 0 {
+        return Ok(None);
+    }
+    ensure!(length <= MAX_ARCHIVED_BLOCK_SIZE, "Invalid block archive - block size ({length} bytes) is too large");
+    // Note: The length is not trusted, so the block bytes are read as they arrive, instead of being allocated upfront.
+    let mut bytes = Vec::new();
+    reader.by_ref().take(u64::from(length)).read_to_end(&mut bytes)?;
+    ensure!(bytes.len() == usize::try_from(length)?, "Invalid block archive - truncated block");
+    let mut checksum = [0u8; 32];
+    reader.read_exact(&mut checksum)?;
+    ensure!(Sha256::digest(&bytes)[..] == checksum, "Invalid block archive - incorrect block checksum");
+    Ok(Some(bytes))
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::FinalizeSnapshotHeader;
+use std::io::Read;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Loads a new ledger from storage, starting from the given trusted block, with the finalize state
+    /// and committee store of the given finalize snapshot.
+    ///
+    /// The snapshot header must be obtained from a trusted source, as the blocks do not commit to the finalize state.
+    /// The blocks from the genesis block up to the trusted block are synced by their headers only: each block
+    /// must link to the previous block hash, up to the trusted block hash, and is stored without being re-executed.
+    ///
+    /// Note: The storage must be empty. If the bootstrap fails, the storage must be cleared before trying again.
+    pub fn load_from_snapshot<R: Read>(
+        genesis_block: Block<N>,
+        storage_mode: StorageMode,
+        trusted_header: &FinalizeSnapshotHeader<N>,
+        blocks: impl IntoIterator<Item = Block<N>>,
+        snapshot: R,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_snapshot");
+
+        info!("Loading the ledger from the finalize snapshot at block {}...", trusted_header.block_height());
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage_mode)?;
+        // Ensure the block store is empty.
+        if store.block_store().heights().max().is_some() {
+            bail!("Failed to load the ledger from a finalize snapshot, as the ledger is already initialized")
+        }
+        lap!(timer, "Load consensus store");
+
+        // Sync the blocks up to the trusted block.
+        let mut previous_hash = None;
+        for (expected_height, block) in (0..=trusted_header.block_height()).zip(blocks) {
+            // Ensure the block is the expected block.
+            ensure!(block.height() == expected_height, "Expected block {expected_height}, found {}", block.height());
+            match previous_hash {
+                None => ensure!(block.hash() == genesis_block.hash(), "Incorrect genesis block in the blocks to sync"),
+                Some(previous_hash) => ensure!(
+                    block.previous_hash() == previous_hash,
+                    "Block {expected_height} does not link to the previous block hash"
+                ),
+            }
+            // Ensure the block hash is the hash of the block header.
+            let block_hash = N::hash_bhp1024(&to_bits_le![block.previous_hash(), block.header().to_root()?])?;
+            ensure!(N::BlockHash::from(block_hash) == block.hash(), "Incorrect block hash for block {expected_height}");
+            // Store the block.
+            store.block_store().insert(&block)?;
+            previous_hash = Some(block.hash());
+        }
+        // Ensure the blocks reach the trusted block.
+        ensure!(
+            previous_hash == Some(trusted_header.block_hash()),
+            "The synced blocks do not end at the trusted block {} ('{}')",
+            trusted_header.block_height(),
+            trusted_header.block_hash()
+        );
+        lap!(timer, "Sync the block headers");
+
+        // Import the finalize snapshot.
+        store.finalize_store().import_snapshot(trusted_header, snapshot)?;
+        lap!(timer, "Import the finalize snapshot");
+
+        // Initialize a new VM.
+        let vm = VM::from(store)?;
+        lap!(timer, "Initialize a new VM");
+
+        // Initialize the ledger.
+        let ledger = Self {
+            vm,
+            genesis_block: genesis_block.clone(),
+            coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
+            current_epoch_challenge: Default::default(),
+            current_committee: Default::default(),
+            current_block: Arc::new(RwLock::new(genesis_block)),
+        };
+        // Load the latest state.
+        ledger.load_latest_state()?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+}
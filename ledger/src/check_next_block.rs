@@ -17,6 +17,18 @@ use super::*;
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
+        self.check_next_block_internal(block, false, rng)
+    }
+
+    /// Checks the given block is valid next block.
+    /// If `is_preverified` is `true`, the checks in `Block::verify_stateless` are skipped,
+    /// as the block must have passed them against the latest block and the latest epoch challenge.
+    pub(crate) fn check_next_block_internal<R: CryptoRng + Rng>(
+        &self,
+        block: &Block<N>,
+        is_preverified: bool,
+        rng: &mut R,
+    ) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -106,7 +118,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
 
         // Ensure the block is correct.
-        let (expected_existing_solution_ids, expected_existing_transaction_ids) = block.verify(
+        let verify = match is_preverified {
+            true => Block::verify_preverified,
+            false => Block::verify,
+        };
+        let (expected_existing_solution_ids, expected_existing_transaction_ids) = verify(
+            block,
             &self.latest_block(),
             self.latest_state_root(),
             &previous_committee_lookback,
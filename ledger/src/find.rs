@@ -46,6 +46,28 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transaction_store().find_transaction_id_from_transition_id(transition_id)
     }
 
+    /// Returns the number of transitions for the given program ID and function name.
+    pub fn num_transitions_by_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<usize> {
+        self.vm.transition_store().num_function_transitions(program_id, function_name)
+    }
+
+    /// Returns at most `limit` of the `(position, transition ID)` pairs for the given program ID and function name,
+    /// starting from the given position, in the order they were included in the ledger.
+    /// To fetch the next page, start from the position after the last returned transition.
+    pub fn find_transitions_by_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        start: TransitionPosition,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.vm.transition_store().get_function_transitions(program_id, function_name, start, limit)
+    }
+
     /// Returns the transition ID that contains the given `input ID` or `output ID`.
     pub fn find_transition_id(&self, id: &Field<N>) -> Result<N::TransitionID> {
         self.vm.transition_store().find_transition_id(id)
@@ -141,4 +163,115 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             })
         })
     }
+
+    /// Returns the records that belong to the given view keys in the given block range, along with their spent status.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// Note: Pruned blocks no longer contain their record ciphertexts, so the range must start after the pruned blocks.
+    pub fn scan_records(&self, view_keys: &[ViewKey<N>], heights: Range<u32>) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the end height is not past the latest block.
+        ensure!(heights.end <= self.latest_height().saturating_add(1), "Cannot scan past the latest block");
+        self.scan_records_from(view_keys.iter().map(|view_key| (view_key, heights.start)).collect(), heights.end)
+    }
+
+    /// Returns the records that belong to the given view keys, starting from the scan checkpoint of each view key
+    /// up to the latest block, along with their spent status. The scan checkpoints are then advanced past the
+    /// latest block, so the next call only scans the blocks added since.
+    ///
+    /// The scan checkpoints are kept by the ledger, and are rewound when blocks are rolled back.
+    ///
+    /// Note: Only the spent status of the newly-found records is returned; wallets should re-check the spent
+    /// status of previously-found records with `Ledger::contains_tag`.
+    pub fn scan_records_since_checkpoint(&self, view_keys: &[ViewKey<N>]) -> Result<Vec<ScannedRecord<N>>> {
+        // Hold the read lock on the current block for the whole scan, so no block is added or rolled back
+        // between scanning the blocks and advancing the scan checkpoints.
+        let current_block = self.current_block.read();
+        // Determine the end of the scan, which is past the latest block.
+        let end_height = current_block.height().saturating_add(1);
+        // Retrieve the scan checkpoint of each view key.
+        let view_keys = view_keys
+            .iter()
+            .map(|view_key| Ok((view_key, self.get_scan_checkpoint(view_key)?.min(end_height))))
+            .collect::<Result<Vec<_>>>()?;
+        // Scan the records.
+        let records = self.scan_records_from(view_keys.clone(), end_height)?;
+        // Advance the scan checkpoints.
+        let checkpoints: Vec<_> = view_keys.iter().map(|(view_key, _)| (view_key.to_address(), end_height)).collect();
+        self.vm.block_store().insert_scan_checkpoints(&checkpoints)?;
+        // Drop the read lock on the current block.
+        drop(current_block);
+        // Return the records.
+        Ok(records)
+    }
+
+    /// Returns the scan checkpoint of the given view key, which is the height of the next block to scan.
+    pub fn get_scan_checkpoint(&self, view_key: &ViewKey<N>) -> Result<u32> {
+        Ok(self.vm.block_store().get_scan_checkpoint(&view_key.to_address())?.unwrap_or(0))
+    }
+
+    /// Returns the records that belong to the given view keys, starting from the given height of each view key,
+    /// up to (but excluding) the given end height, along with their spent status.
+    ///
+    /// Note: The caller must ensure the end height is not past the latest block.
+    fn scan_records_from(&self, view_keys: Vec<(&ViewKey<N>, u32)>, end_height: u32) -> Result<Vec<ScannedRecord<N>>> {
+        // Prepare the address, address x-coordinate, and `sk_tag` of each view key.
+        let accounts = view_keys
+            .into_iter()
+            .map(|(view_key, start_height)| {
+                // Derive the address corresponding to the given view key.
+                let address = view_key.to_address();
+                // Derive the `sk_tag` from the graph key.
+                let sk_tag = match GraphKey::try_from(view_key) {
+                    Ok(graph_key) => graph_key.sk_tag(),
+                    Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+                };
+                Ok((view_key, address, address.to_x_coordinate(), sk_tag, start_height))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Determine the start of the scan.
+        let Some(start_height) = accounts.iter().map(|(.., start_height)| *start_height).min() else {
+            return Ok(vec![]);
+        };
+        // Ensure the scanned blocks have not been pruned, as their records can no longer be decrypted.
+        if start_height < end_height {
+            if let Some(pruned_height) = self.vm.block_store().get_pruning_config()?.and_then(|c| c.pruned_height()) {
+                ensure!(
+                    start_height > pruned_height,
+                    "Cannot scan from block {start_height}, as the blocks up to {pruned_height} have been pruned"
+                );
+            }
+        }
+
+        // Scan the blocks in the range.
+        let records = cfg_into_iter!(start_height..end_height.max(start_height))
+            .map(|height| {
+                // Retrieve the transactions in the block.
+                let block_hash = self.get_hash(height)?;
+                let transactions = match self.vm.block_store().get_block_transactions(&block_hash)? {
+                    Some(transactions) => transactions,
+                    None => bail!("Missing transactions for block {height} ('{block_hash}')"),
+                };
+
+                // Find the records that belong to the view keys.
+                let mut records = Vec::new();
+                for (commitment, record) in transactions.records() {
+                    for (view_key, address, address_x_coordinate, sk_tag, _) in
+                        accounts.iter().filter(|(.., start_height)| *start_height <= height)
+                    {
+                        if record.is_owner_with_address_x_coordinate(view_key, address_x_coordinate) {
+                            // Decrypt the record.
+                            let record = record.decrypt(view_key)?;
+                            // Determine if the record is spent.
+                            let is_spent = self.contains_tag(&Record::<N, Plaintext<N>>::tag(*sk_tag, *commitment)?)?;
+                            records.push(ScannedRecord::new(*address, height, *commitment, record, is_spent));
+                        }
+                    }
+                }
+                Ok(records)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(records.into_iter().flatten().collect())
+    }
 }
@@ -191,6 +191,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the latest transaction receipt for the given unconfirmed `transaction ID`.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<TransactionReceipt<N>> {
+        // Retrieve the transaction receipt.
+        match self.vm.block_store().get_receipt(transaction_id)? {
+            Some(receipt) => Ok(receipt),
+            None => bail!("Missing transaction receipt for ID {transaction_id}"),
+        }
+    }
+
     /// Returns the program for the given program ID.
     pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
         match self.vm.block_store().get_program(&program_id)? {
@@ -15,5 +15,8 @@
 mod bft;
 pub use bft::*;
 
+mod scan;
+pub use scan::*;
+
 mod supply;
 pub use supply::*;
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    network::Network,
+    program::{Plaintext, Record},
+    types::Field,
+};
+
+/// A record found by scanning the blocks for the records of a view key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The address of the record owner.
+    owner: Address<N>,
+    /// The height of the block that contains the record.
+    block_height: u32,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// Whether the record is spent.
+    is_spent: bool,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Initializes a new scanned record.
+    pub const fn new(
+        owner: Address<N>,
+        block_height: u32,
+        commitment: Field<N>,
+        record: Record<N, Plaintext<N>>,
+        is_spent: bool,
+    ) -> Self {
+        Self { owner, block_height, commitment, record, is_spent }
+    }
+
+    /// Returns the address of the record owner.
+    pub const fn owner(&self) -> Address<N> {
+        self.owner
+    }
+
+    /// Returns the height of the block that contains the record.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> Field<N> {
+        self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns `true` if the record is spent.
+    pub const fn is_spent(&self) -> bool {
+        self.is_spent
+    }
+}
@@ -49,6 +49,41 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().transition_ids()
     }
 
+    /// Returns an iterator over the `(position, transition ID)` pairs, for all transitions of the given function.
+    /// The transitions are returned in the order they were included in the ledger, and are read in pages.
+    pub fn transitions_by_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<impl '_ + Iterator<Item = (TransitionPosition, N::TransitionID)>> {
+        // The number of transitions to read at a time.
+        const PAGE_SIZE: usize = 1024;
+
+        let (program_id, function_name) = (*program_id, *function_name);
+        // Read the first page.
+        let page = self.find_transitions_by_function(&program_id, &function_name, Default::default(), PAGE_SIZE)?;
+        let mut next = page.last().filter(|_| page.len() == PAGE_SIZE).map(|(position, _)| position.next());
+        let mut page = page.into_iter();
+
+        Ok(core::iter::from_fn(move || loop {
+            if let Some(entry) = page.next() {
+                return Some(entry);
+            }
+            // Read the next page, if the previous page was full.
+            let start = next.take()?;
+            match self.find_transitions_by_function(&program_id, &function_name, start, PAGE_SIZE) {
+                Ok(entries) => {
+                    next = entries.last().filter(|_| entries.len() == PAGE_SIZE).map(|(position, _)| position.next());
+                    page = entries.into_iter();
+                }
+                Err(error) => {
+                    warn!("Failed to read the transitions of '{program_id}/{function_name}': {error}");
+                    return None;
+                }
+            }
+        }))
+    }
+
     /* Input */
 
     /// Returns an iterator over the input IDs, for all transition inputs.
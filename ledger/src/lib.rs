@@ -35,6 +35,8 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod archive;
+mod bootstrap;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
@@ -42,6 +44,11 @@ mod find;
 mod get;
 mod iterators;
 
+mod read_only;
+pub use read_only::*;
+
+mod rollback;
+
 #[cfg(test)]
 mod tests;
 
@@ -56,7 +63,7 @@ use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSo
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{ConsensusStorage, ConsensusStore, TransitionPosition};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -129,9 +136,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the latest height.
         let latest_height = ledger.current_block.read().height();
         debug_assert_eq!(latest_height, *ledger.vm.block_store().heights().max().unwrap(), "Mismatch in latest height");
+        // Determine the earliest block height that has not been pruned.
+        let pruned_height = ledger.vm.block_store().get_pruning_config()?.and_then(|config| config.pruned_height());
+        let start_height = pruned_height.map_or(0, |height| height.saturating_add(1).min(latest_height));
         // Sample random block heights.
-        let block_heights: Vec<u32> =
-            (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
+        let block_heights: Vec<u32> = (start_height..=latest_height)
+            .choose_multiple(&mut OsRng, ((latest_height - start_height) as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
             ledger.get_block(height)?;
             Ok::<_, Error>(())
@@ -162,7 +172,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
 
         // Initialize the ledger.
-        let mut ledger = Self {
+        let ledger = Self {
             vm,
             genesis_block: genesis_block.clone(),
             coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
@@ -178,23 +188,79 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
         lap!(timer, "Initialize genesis");
 
+        // Load the latest state.
+        ledger.load_latest_state()?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from storage with read-only access, alongside a primary instance of the ledger
+    /// (e.g. a running node) that writes to the same storage.
+    ///
+    /// The read-only ledger does not write to storage, and must be caught up with the writes of the primary
+    /// instance with `ReadOnlyLedger::catch_up`. Note that only one storage can be opened per process.
+    pub fn load_read_only(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<ReadOnlyLedger<N, C>> {
+        let timer = timer!("Ledger::load_read_only");
+
+        info!("Loading the ledger from storage with read-only access...");
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open_read_only(storage_mode)?;
+        lap!(timer, "Load consensus store");
+
+        // Ensure the block store is initialized, as the genesis block cannot be added with read-only access.
+        if store.block_store().heights().max().is_none() {
+            bail!("Failed to load ledger with read-only access, as the ledger is not initialized")
+        }
+
+        // Initialize a new VM.
+        let vm = VM::from(store)?;
+        lap!(timer, "Initialize a new VM");
+
+        // Initialize the ledger.
+        let ledger = Self {
+            vm,
+            genesis_block: genesis_block.clone(),
+            coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
+            current_epoch_challenge: Default::default(),
+            current_committee: Default::default(),
+            current_block: Arc::new(RwLock::new(genesis_block.clone())),
+        };
+
+        // Ensure the ledger contains the correct genesis block.
+        if !ledger.contains_block_hash(&genesis_block.hash())? {
+            bail!("Incorrect genesis block in the ledger")
+        }
+        // Load the latest state.
+        ledger.load_latest_state()?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ReadOnlyLedger::new(ledger))
+    }
+
+    /// Loads the latest block, committee, and epoch challenge from storage.
+    fn load_latest_state(&self) -> Result<()> {
         // Retrieve the latest height.
         let latest_height =
-            *ledger.vm.block_store().heights().max().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
+            *self.vm.block_store().heights().max().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
         // Fetch the latest block.
-        let block = ledger
+        let block = self
             .get_block(latest_height)
             .map_err(|_| anyhow!("Failed to load block {latest_height} from the ledger"))?;
 
         // Set the current block.
-        ledger.current_block = Arc::new(RwLock::new(block));
+        *self.current_block.write() = block;
         // Set the current committee (and ensures the latest committee exists).
-        ledger.current_committee = Arc::new(RwLock::new(Some(ledger.latest_committee()?)));
+        *self.current_committee.write() = Some(self.vm.finalize_store().committee_store().current_committee()?);
         // Set the current epoch challenge.
-        ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
+        *self.current_epoch_challenge.write() = Some(self.get_epoch_challenge(latest_height)?);
+        Ok(())
+    }
 
-        finish!(timer, "Initialize ledger");
-        Ok(ledger)
+    /// Enables pruning, retaining the given number of recent blocks in full, and prunes the older blocks.
+    /// The proofs and transition payloads of pruned blocks are removed, and retrieving them returns an error.
+    pub fn enable_pruning(&self, retention: u32) -> Result<()> {
+        self.vm.block_store().enable_pruning(retention)
     }
 
     /// Returns the VM.
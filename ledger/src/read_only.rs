@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::io::Write;
+
+/// A ledger that is opened with read-only access, alongside a primary instance of the ledger that writes to it.
+///
+/// The read-only ledger only exposes the read methods of the ledger, and can catch up with the writes
+/// of the primary instance with `ReadOnlyLedger::catch_up`.
+#[derive(Clone)]
+pub struct ReadOnlyLedger<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+}
+
+/// Implements the given methods of the read-only ledger, by calling the corresponding methods of the ledger.
+macro_rules! delegate {
+    ($($(#[doc = $doc:expr])* fn $name:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;)*) => {
+        $(
+            $(#[doc = $doc])*
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                self.ledger.$name($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ReadOnlyLedger<N, C> {
+    /// Initializes the read-only ledger from the given ledger.
+    pub(crate) const fn new(ledger: Ledger<N, C>) -> Self {
+        Self { ledger }
+    }
+
+    /// Catches up with the writes of the primary instance of the ledger.
+    pub fn catch_up(&self) -> Result<()> {
+        // Catch up with the primary instance of the storage, and load the newly-deployed programs.
+        self.ledger.vm.try_catch_up_with_primary()?;
+        // Update the latest state.
+        self.ledger.load_latest_state()
+    }
+
+    /// Returns the record ciphertexts that belong to the given view key.
+    pub fn find_record_ciphertexts<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Cow<'_, Record<N, Ciphertext<N>>>)>> {
+        self.ledger.find_record_ciphertexts(view_key, filter)
+    }
+
+    /// Returns the records that belong to the given view key.
+    pub fn find_records<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        self.ledger.find_records(view_key, filter)
+    }
+
+    /// Writes the blocks in the given range of heights to the writer, as a block archive,
+    /// and returns the number of blocks written.
+    pub fn export_blocks<W: Write>(&self, heights: Range<u32>, writer: W) -> Result<u32> {
+        self.ledger.export_blocks(heights, writer)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ReadOnlyLedger<N, C> {
+    delegate! {
+        /// Returns the latest committee.
+        fn latest_committee(&self) -> Result<Committee<N>>;
+
+        /// Returns the latest state root.
+        fn latest_state_root(&self) -> N::StateRoot;
+
+        /// Returns the latest epoch number.
+        fn latest_epoch_number(&self) -> u32;
+
+        /// Returns the latest epoch challenge.
+        fn latest_epoch_challenge(&self) -> Result<EpochChallenge<N>>;
+
+        /// Returns the latest block.
+        fn latest_block(&self) -> Block<N>;
+
+        /// Returns the latest round number.
+        fn latest_round(&self) -> u64;
+
+        /// Returns the latest block height.
+        fn latest_height(&self) -> u32;
+
+        /// Returns the latest block hash.
+        fn latest_hash(&self) -> N::BlockHash;
+
+        /// Returns the latest block header.
+        fn latest_header(&self) -> Header<N>;
+
+        /// Returns the latest block cumulative weight.
+        fn latest_cumulative_weight(&self) -> u128;
+
+        /// Returns the latest block cumulative proof target.
+        fn latest_cumulative_proof_target(&self) -> u128;
+
+        /// Returns the latest block solutions root.
+        fn latest_solutions_root(&self) -> Field<N>;
+
+        /// Returns the latest block coinbase target.
+        fn latest_coinbase_target(&self) -> u64;
+
+        /// Returns the latest block proof target.
+        fn latest_proof_target(&self) -> u64;
+
+        /// Returns the last coinbase target.
+        fn last_coinbase_target(&self) -> u64;
+
+        /// Returns the last coinbase timestamp.
+        fn last_coinbase_timestamp(&self) -> i64;
+
+        /// Returns the latest block timestamp.
+        fn latest_timestamp(&self) -> i64;
+
+        /// Returns the latest block transactions.
+        fn latest_transactions(&self) -> Transactions<N>;
+
+        /// Returns the unspent `credits.aleo` records.
+        fn find_unspent_credits_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>>;
+
+        /// Returns the committee for the given `block height`.
+        fn get_committee(&self, block_height: u32) -> Result<Option<Committee<N>>>;
+
+        /// Returns the committee for the given `round`.
+        fn get_committee_for_round(&self, round: u64) -> Result<Option<Committee<N>>>;
+
+        /// Returns the state root that contains the given `block height`.
+        fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>>;
+
+        /// Returns a state path for the given commitment.
+        fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>>;
+
+        /// Returns the epoch challenge for the given block height.
+        fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>>;
+
+        /// Returns the block for the given block height.
+        fn get_block(&self, height: u32) -> Result<Block<N>>;
+
+        /// Returns the blocks in the given block range.
+        /// The range is inclusive of the start and exclusive of the end.
+        fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>>;
+
+        /// Returns the block for the given block hash.
+        fn get_block_by_hash(&self, block_hash: &N::BlockHash) -> Result<Block<N>>;
+
+        /// Returns the block height for the given block hash.
+        fn get_height(&self, block_hash: &N::BlockHash) -> Result<u32>;
+
+        /// Returns the block hash for the given block height.
+        fn get_hash(&self, height: u32) -> Result<N::BlockHash>;
+
+        /// Returns the previous block hash for the given block height.
+        fn get_previous_hash(&self, height: u32) -> Result<N::BlockHash>;
+
+        /// Returns the block header for the given block height.
+        fn get_header(&self, height: u32) -> Result<Header<N>>;
+
+        /// Returns the block transactions for the given block height.
+        fn get_transactions(&self, height: u32) -> Result<Transactions<N>>;
+
+        /// Returns the aborted transaction IDs for the given block height.
+        fn get_aborted_transaction_ids(&self, height: u32) -> Result<Vec<N::TransactionID>>;
+
+        /// Returns the transaction for the given transaction ID.
+        fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>>;
+
+        /// Returns the confirmed transaction for the given transaction ID.
+        fn get_confirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<ConfirmedTransaction<N>>;
+
+        /// Returns the unconfirmed transaction for the given `transaction ID`.
+        fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>>;
+
+        /// Returns the transaction receipt for the given unconfirmed `transaction ID`.
+        fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<TransactionReceipt<N>>;
+
+        /// Returns the program for the given program ID.
+        fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>>;
+
+        /// Returns the block solutions for the given block height.
+        fn get_solutions(&self, height: u32) -> Result<Solutions<N>>;
+
+        /// Returns the solution for the given solution ID.
+        fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>>;
+
+        /// Returns the block authority for the given block height.
+        fn get_authority(&self, height: u32) -> Result<Authority<N>>;
+
+        /// Returns the batch certificate for the given `certificate ID`.
+        fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>>;
+
+        /// Returns `true` if the given state root exists.
+        fn contains_state_root(&self, state_root: &N::StateRoot) -> Result<bool>;
+
+        /// Returns `true` if the given block height exists.
+        fn contains_block_height(&self, height: u32) -> Result<bool>;
+
+        /// Returns `true` if the given block hash exists.
+        fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool>;
+
+        /// Returns `true` if the given batch certificate ID exists.
+        fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given program ID exists.
+        fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool>;
+
+        /// Returns `true` if the transmission exists in the ledger.
+        fn contains_transmission(&self, transmission_id: &TransmissionID<N>) -> Result<bool>;
+
+        /// Returns `true` if the given puzzle commitment exists.
+        fn contains_puzzle_commitment(&self, solution_id: &PuzzleCommitment<N>) -> Result<bool>;
+
+        /// Returns `true` if the given transaction ID exists.
+        fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool>;
+
+        /// Returns `true` if the given transition ID exists.
+        fn contains_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool>;
+
+        /// Returns `true` if the given input ID exists.
+        fn contains_input_id(&self, input_id: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given serial number exists.
+        fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given tag exists.
+        fn contains_tag(&self, tag: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given output ID exists.
+        fn contains_output_id(&self, output_id: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given commitment exists.
+        fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool>;
+
+        /// Returns `true` if the given checksum exists.
+        fn contains_checksum(&self, checksum: &Field<N>) -> bool;
+
+        /// Returns `true` if the given nonce exists.
+        fn contains_nonce(&self, nonce: &Group<N>) -> Result<bool>;
+
+        /// Returns `true` if the given transition public key exists.
+        fn contains_tpk(&self, tpk: &Group<N>) -> Result<bool>;
+
+        /// Returns `true` if the given transition commitment exists.
+        fn contains_tcm(&self, tcm: &Field<N>) -> Result<bool>;
+
+        /// Returns the block height that contains the given `state root`.
+        fn find_block_height_from_state_root(&self, state_root: N::StateRoot) -> Result<Option<u32>>;
+
+        /// Returns the block hash that contains the given `transaction ID`.
+        fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>>;
+
+        /// Returns the block height that contains the given `puzzle commitment`.
+        fn find_block_height_from_puzzle_commitment(
+            &self,
+            puzzle_commitment: &PuzzleCommitment<N>,
+        ) -> Result<Option<u32>>;
+
+        /// Returns the transaction ID that contains the given `program ID`.
+        fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>>;
+
+        /// Returns the transaction ID that contains the given `transition ID`.
+        fn find_transaction_id_from_transition_id(
+            &self,
+            transition_id: &N::TransitionID,
+        ) -> Result<Option<N::TransactionID>>;
+
+        /// Returns the number of transitions for the given program ID and function name.
+        fn num_transitions_by_function(
+            &self,
+            program_id: &ProgramID<N>,
+            function_name: &Identifier<N>,
+        ) -> Result<usize>;
+
+        /// Returns at most `limit` of the `(position, transition ID)` pairs for the given program ID and function name,
+        /// starting from the given position, in the order they were included in the ledger.
+        fn find_transitions_by_function(
+            &self,
+            program_id: &ProgramID<N>,
+            function_name: &Identifier<N>,
+            start: TransitionPosition,
+            limit: usize,
+        ) -> Result<Vec<(TransitionPosition, N::TransitionID)>>;
+
+        /// Returns the transition ID that contains the given `input ID` or `output ID`.
+        fn find_transition_id(&self, id: &Field<N>) -> Result<N::TransitionID>;
+
+        /// Returns the records that belong to the given view keys in the given block range, along with their spent status.
+        /// The range is inclusive of the start and exclusive of the end.
+        fn scan_records(&self, view_keys: &[ViewKey<N>], heights: Range<u32>) -> Result<Vec<ScannedRecord<N>>>;
+
+        /// Returns the scan checkpoint of the given view key, which is the height of the next block to scan.
+        fn get_scan_checkpoint(&self, view_key: &ViewKey<N>) -> Result<u32>;
+
+        /// Returns an iterator over the state roots, for all blocks in `self`.
+        fn state_roots(&self) -> impl '_ + Iterator<Item = Cow<'_, N::StateRoot>>;
+
+        /// Returns an iterator over the solution IDs, for all blocks in `self`.
+        fn solution_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, PuzzleCommitment<N>>>;
+
+        /// Returns an iterator over the program IDs, for all transactions in `self`.
+        fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>>;
+
+        /// Returns an iterator over the programs, for all transactions in `self`.
+        fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>>;
+
+        /// Returns an iterator over the transaction IDs, for all transactions in `self`.
+        fn transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>>;
+
+        /// Returns an iterator over the transition IDs, for all transitions.
+        fn transition_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransitionID>>;
+
+        /// Returns an iterator over the `(block height, transition ID)` pairs, for all transitions of the given function.
+        /// The transitions are returned in the order they were included in the ledger.
+        fn transitions_by_function(
+            &self,
+            program_id: &ProgramID<N>,
+            function_name: &Identifier<N>,
+        ) -> Result<impl '_ + Iterator<Item = (TransitionPosition, N::TransitionID)>>;
+
+        /// Returns an iterator over the input IDs, for all transition inputs.
+        fn input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>>;
+
+        /// Returns an iterator over the serial numbers, for all transition inputs that are records.
+        fn serial_numbers(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>>;
+
+        /// Returns an iterator over the tags, for all transition inputs that are records.
+        fn tags(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>>;
+
+        /// Returns an iterator over the output IDs, for all transition outputs that are records.
+        fn output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>>;
+
+        /// Returns an iterator over the commitments, for all transition outputs that are records.
+        fn commitments(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>>;
+
+        /// Returns an iterator over the nonces, for all transition outputs that are records.
+        fn nonces(&self) -> impl '_ + Iterator<Item = Cow<'_, Group<N>>>;
+
+        /// Returns an iterator over the `(commitment, record)` pairs, for all transition outputs that are records.
+        fn records(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Record<N, Ciphertext<N>>>)>;
+
+        /// Returns an iterator over the transition public keys, for all transactions.
+        fn transition_public_keys(&self) -> impl '_ + Iterator<Item = Cow<'_, Group<N>>>;
+    }
+}
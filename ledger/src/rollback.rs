@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Rolls back the ledger to the given block height, by removing the blocks after it.
+    /// The removed blocks are reverted in a single atomic batch, along with their transactions, committees,
+    /// finalize state, and the programs they deployed.
+    ///
+    /// Note: If the rollback fails, the ledger is left unchanged.
+    pub fn rollback_to(&self, block_height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Ensure the block height is in the ledger.
+        let latest_height = current_block.height();
+        ensure!(
+            block_height <= latest_height,
+            "Cannot roll back to block {block_height} - the latest block is {latest_height}"
+        );
+
+        // If the ledger is at the given block height, there is nothing to roll back.
+        if block_height == latest_height {
+            return Ok(());
+        }
+
+        // Remove the blocks after the given block height.
+        self.vm.remove_last_blocks(latest_height - block_height)?;
+
+        // Update the current block from storage.
+        *current_block = self.get_block(block_height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = self.vm.finalize_store().committee_store().current_committee().ok();
+        // Update the current epoch challenge.
+        *self.current_epoch_challenge.write() = self.get_epoch_challenge(block_height).ok();
+
+        Ok(())
+    }
+}
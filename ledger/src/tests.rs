@@ -20,14 +20,15 @@ use crate::{
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use indexmap::IndexMap;
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
-use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore, TransitionPosition};
 use synthesizer::{program::Program, vm::VM, Stack};
 
 #[test]
@@ -69,6 +70,16 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_load_read_only_memory() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Ensure the memory storage cannot be loaded with read-only access.
+    let ledger = Ledger::<CurrentNetwork, ConsensusMemory<_>>::load_read_only(genesis, StorageMode::Production);
+    assert!(ledger.is_err());
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -448,6 +459,23 @@ finalize failed_assert:
 
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
+
+    // Check the receipt of the rejected execution.
+    let receipt = ledger.get_receipt(&failed_assert_transaction_id).unwrap();
+    assert!(receipt.is_rejected());
+    assert_eq!(receipt.block_height(), next_block.height());
+    assert_eq!(receipt.index(), Some(0));
+    assert_eq!(receipt.fee(), *confirmed_transaction.fee_amount().unwrap());
+    assert_eq!(receipt.finalize_operations(), confirmed_transaction.finalize_operations());
+    assert!(receipt.reason().is_some());
+
+    // Prune the block, and ensure the rejected execution is kept by its ID only.
+    let rejected_id = confirmed_transaction.to_rejected_id().unwrap().unwrap();
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    ledger.enable_pruning(1).unwrap();
+    let block_store = ledger.vm().block_store();
+    assert!(block_store.contains_rejected_deployment_or_execution_id(&rejected_id).unwrap());
 }
 
 #[test]
@@ -656,6 +684,7 @@ fn test_aborted_transaction_indexing() {
         .vm
         .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
         .unwrap();
+    let accepted_transaction_id = transfer_transaction_2.id();
 
     // Create a block.
     let block = ledger
@@ -676,6 +705,22 @@ fn test_aborted_transaction_indexing() {
 
     // Add the deployment block to the ledger.
     ledger.advance_to_next_block(&block).unwrap();
+
+    // Check the receipt of the aborted transaction.
+    let receipt = ledger.get_receipt(&aborted_transaction_id).unwrap();
+    assert!(receipt.is_aborted());
+    assert_eq!(receipt.block_height(), block.height());
+    assert_eq!(receipt.index(), None);
+    assert_eq!(receipt.fee(), 0);
+    assert!(receipt.reason().is_some());
+
+    // Check the receipt of the accepted transaction.
+    let receipt = ledger.get_receipt(&accepted_transaction_id).unwrap();
+    assert!(receipt.is_accepted());
+    assert_eq!(receipt.index(), Some(0));
+    assert!(receipt.fee() > 0);
+    assert!(!receipt.finalize_operations().is_empty());
+    assert_eq!(receipt.reason(), None);
 }
 
 #[test]
@@ -1672,3 +1717,432 @@ fn test_deployment_exceeding_max_transaction_spend() {
     // Check that the deployment failed.
     assert!(result.is_err());
 }
+
+#[test]
+fn test_rollback_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the state of the genesis block.
+    let genesis_hash = ledger.latest_hash();
+    let genesis_checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping entries:
+    key as u8.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    set r0 into entries[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let deploy_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+
+    // Transfer credits to a recipient.
+    let recipient_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let transfer_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Ensure the rollback cannot go past the latest block.
+    assert!(ledger.rollback_to(3).is_err());
+
+    // Roll back the transfer block, and ensure the recipient balance is reverted.
+    let account_mapping = Identifier::from_str("account").unwrap();
+    let recipient_key = Plaintext::from(Literal::Address(recipient_address));
+    let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+    ledger.rollback_to(1).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.latest_hash(), deploy_block.hash());
+    assert!(!ledger.contains_block_hash(&transfer_block.hash()).unwrap());
+    assert_eq!(
+        ledger.vm().finalize_store().get_value_confirmed(credits_id, account_mapping, &recipient_key).unwrap(),
+        None
+    );
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Roll back the deployment block, and ensure the program is removed.
+    ledger.rollback_to(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.latest_hash(), genesis_hash);
+    assert!(!ledger.vm().contains_program(&program_id));
+    assert!(!ledger.vm().finalize_store().contains_program_confirmed(&program_id).unwrap());
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), genesis_checksum);
+    assert_eq!(
+        ledger.latest_committee().unwrap(),
+        ledger.vm().finalize_store().committee_store().get_committee(0).unwrap().unwrap()
+    );
+
+    // Ensure the blocks can be added again.
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Roll back both blocks at once, and ensure the genesis state is restored.
+    ledger.rollback_to(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert!(!ledger.vm().contains_program(&program_id));
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), genesis_checksum);
+
+    // Prune the deployment block, and ensure a rollback past it fails and leaves the ledger unchanged.
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+    let checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+    ledger.enable_pruning(1).unwrap();
+    assert!(ledger.rollback_to(0).is_err());
+    assert_eq!(ledger.latest_height(), 2);
+    assert_eq!(ledger.latest_hash(), transfer_block.hash());
+    assert!(ledger.vm().contains_program(&program_id));
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), checksum);
+    assert!(ledger.vm().finalize_store().contains_rollback(2).unwrap());
+}
+
+#[test]
+fn test_scan_records() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Sample another account.
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+    let view_keys = [view_key, other_view_key];
+
+    // Ensure the genesis records are found, and are unspent.
+    let records = ledger.scan_records(&view_keys, 0..1).unwrap();
+    assert!(!records.is_empty());
+    assert_eq!(records.len(), ledger.find_records(&view_key, RecordsFilter::All).unwrap().count());
+    assert!(records.iter().all(|record| record.owner() == address && record.block_height() == 0));
+    assert!(records.iter().all(|record| !record.is_spent()));
+
+    // Ensure the scan resumes from the checkpoints.
+    assert_eq!(ledger.get_scan_checkpoint(&view_key).unwrap(), 0);
+    assert_eq!(ledger.scan_records_since_checkpoint(&view_keys).unwrap(), records);
+    assert_eq!(ledger.get_scan_checkpoint(&view_key).unwrap(), 1);
+    assert_eq!(ledger.get_scan_checkpoint(&other_view_key).unwrap(), 1);
+    assert!(ledger.scan_records_since_checkpoint(&view_keys).unwrap().is_empty());
+
+    // Transfer credits to the other account.
+    let other_address = Address::try_from(&other_private_key).unwrap();
+    let transaction = ledger.create_transfer(&private_key, other_address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure only the records in the new block are found.
+    let new_records = ledger.scan_records_since_checkpoint(&view_keys).unwrap();
+    assert!(new_records.iter().all(|record| record.block_height() == 1 && !record.is_spent()));
+    assert!(new_records.iter().any(|record| record.owner() == address));
+    assert!(new_records.iter().any(|record| record.owner() == other_address));
+    assert_eq!(ledger.get_scan_checkpoint(&view_key).unwrap(), 2);
+
+    // Ensure the spent records are reported as spent.
+    let records = ledger.scan_records(&[view_key], 0..1).unwrap();
+    assert_eq!(records.iter().filter(|record| record.is_spent()).count(), 2);
+
+    // Ensure the scan checkpoints are rewound when the block is rolled back.
+    ledger.rollback_to(0).unwrap();
+    assert_eq!(ledger.get_scan_checkpoint(&view_key).unwrap(), 1);
+    assert_eq!(ledger.get_scan_checkpoint(&other_view_key).unwrap(), 1);
+    assert!(ledger.scan_records_since_checkpoint(&view_keys).unwrap().is_empty());
+
+    // Ensure the replaced block is scanned again.
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.scan_records_since_checkpoint(&view_keys).unwrap(), new_records);
+    assert_eq!(ledger.get_scan_checkpoint(&other_view_key).unwrap(), 2);
+
+    // Ensure the pruned blocks cannot be scanned.
+    ledger.enable_pruning(1).unwrap();
+    assert!(ledger.scan_records(&view_keys, 0..2).is_err());
+    let new_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(ledger.scan_records_since_checkpoint(&[new_view_key]).is_err());
+    assert_eq!(ledger.get_scan_checkpoint(&new_view_key).unwrap(), 0);
+}
+
+#[test]
+fn test_transitions_by_function() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+    let function_name = Identifier::<CurrentNetwork>::from_str("transfer_public").unwrap();
+    assert_eq!(ledger.num_transitions_by_function(&program_id, &function_name).unwrap(), 0);
+
+    // Add blocks with public transfers.
+    let mut expected = vec![];
+    for _ in 0..2 {
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        let transition_id = *block.transactions().transitions().next().unwrap().id();
+        expected.push((TransitionPosition::new(block.height(), 0), transition_id));
+    }
+
+    // Ensure the transitions are found, in order, and paginated from a position.
+    let start = TransitionPosition::default();
+    assert_eq!(ledger.num_transitions_by_function(&program_id, &function_name).unwrap(), 2);
+    assert_eq!(ledger.transitions_by_function(&program_id, &function_name).unwrap().collect::<Vec<_>>(), expected);
+    assert_eq!(ledger.find_transitions_by_function(&program_id, &function_name, start, 1).unwrap(), expected[..1]);
+    let next = expected[0].0.next();
+    assert_eq!(ledger.find_transitions_by_function(&program_id, &function_name, next, 10).unwrap(), expected[1..]);
+
+    // Ensure the index is updated when the latest block is rolled back.
+    ledger.rollback_to(1).unwrap();
+    assert_eq!(ledger.find_transitions_by_function(&program_id, &function_name, start, 10).unwrap(), expected[..1]);
+}
+
+#[test]
+fn test_export_import_blocks() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add blocks to the ledger.
+    for _ in 0..2 {
+        let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 3);
+
+    // Export the blocks.
+    let mut archive = vec![];
+    assert_eq!(ledger.export_blocks(0..4, &mut archive).unwrap(), 4);
+    assert!(ledger.export_blocks(0..5, &mut vec![]).is_err());
+
+    // Import the blocks into a new ledger.
+    let genesis = ledger.get_block(0).unwrap();
+    let other_ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+    assert_eq!(other_ledger.import_blocks(&archive[..]).unwrap(), 3);
+    assert_eq!(other_ledger.latest_hash(), ledger.latest_hash());
+    assert_eq!(other_ledger.latest_state_root(), ledger.latest_state_root());
+
+    // Ensure importing the blocks again is a no-op.
+    assert_eq!(other_ledger.import_blocks(&archive[..]).unwrap(), 0);
+
+    // Ensure a corrupted archive is rejected.
+    let other_ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+    let mut corrupted = archive.clone();
+    let length = corrupted.len();
+    corrupted[length - 40] ^= 1;
+    assert!(other_ledger.import_blocks(&corrupted[..]).is_err());
+
+    // Ensure a truncated archive is rejected.
+    let other_ledger = CurrentLedger::load(genesis, StorageMode::Production).unwrap();
+    assert!(other_ledger.import_blocks(&archive[..archive.len() - 4]).is_err());
+
+    // Ensure a frame that claims more block bytes than the archive holds is rejected.
+    let mut oversized = archive[..11].to_vec();
+    oversized.extend_from_slice(&(1u32 << 30).to_le_bytes());
+    oversized.extend_from_slice(&archive[15..1024]);
+    assert!(other_ledger.import_blocks(&oversized[..]).is_err());
+}
+
+#[test]
+fn test_load_from_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add blocks to the ledger.
+    for _ in 0..2 {
+        let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Export the finalize snapshot at the latest block.
+    let mut snapshot = vec![];
+    let header =
+        ledger.vm().finalize_store().export_snapshot(ledger.latest_height(), ledger.latest_hash(), &mut snapshot).unwrap();
+    let blocks = ledger.get_blocks(0..3).unwrap();
+    let genesis = blocks[0].clone();
+
+    // Ensure the snapshot is rejected if the blocks do not reach the trusted block.
+    let result = CurrentLedger::load_from_snapshot(
+        genesis.clone(),
+        StorageMode::Production,
+        &header,
+        blocks[..2].iter().cloned(),
+        &snapshot[..],
+    );
+    assert!(result.is_err());
+    // Ensure the snapshot is rejected if the blocks do not link up.
+    let result = CurrentLedger::load_from_snapshot(
+        genesis.clone(),
+        StorageMode::Production,
+        &header,
+        [blocks[0].clone(), blocks[2].clone()],
+        &snapshot[..],
+    );
+    assert!(result.is_err());
+
+    // Load a new ledger from the snapshot.
+    let other_ledger =
+        CurrentLedger::load_from_snapshot(genesis, StorageMode::Production, &header, blocks, &snapshot[..]).unwrap();
+    assert_eq!(other_ledger.latest_height(), ledger.latest_height());
+    assert_eq!(other_ledger.latest_hash(), ledger.latest_hash());
+    assert_eq!(other_ledger.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(other_ledger.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        other_ledger.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+
+    // Ensure the new ledger continues from the trusted block.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    other_ledger.check_next_block(&block, rng).unwrap();
+    other_ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(other_ledger.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(
+        other_ledger.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+}
+
+#[test]
+fn test_advance_to_next_blocks() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add blocks to the ledger.
+    let mut blocks = vec![];
+    for i in 0..12 {
+        let transactions = match i % 3 {
+            0 => vec![ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap()],
+            _ => vec![],
+        };
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        blocks.push(block);
+    }
+
+    // Add the blocks to a new ledger, in bulk.
+    let genesis = ledger.get_block(0).unwrap();
+    let other_ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+    assert_eq!(other_ledger.advance_to_next_blocks(blocks[..5].to_vec()).unwrap(), 5);
+    assert_eq!(other_ledger.latest_height(), 5);
+    // Ensure the blocks already in the ledger are skipped.
+    assert_eq!(other_ledger.advance_to_next_blocks(blocks.clone()).unwrap(), 7);
+    assert_eq!(other_ledger.latest_hash(), ledger.latest_hash());
+    assert_eq!(other_ledger.latest_state_root(), ledger.latest_state_root());
+
+    // Ensure a gap in the blocks is rejected, after adding the blocks before the gap.
+    let other_ledger = CurrentLedger::load(genesis, StorageMode::Production).unwrap();
+    let mut candidate_blocks = blocks[..3].to_vec();
+    candidate_blocks.extend_from_slice(&blocks[4..]);
+    assert!(other_ledger.advance_to_next_blocks(candidate_blocks).is_err());
+    assert_eq!(other_ledger.latest_height(), 3);
+}
+
+#[test]
+fn test_pruning() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add blocks to the ledger.
+    let mut transactions = vec![];
+    for _ in 0..2 {
+        let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+        let block = ledger
+            .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+            .unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        transactions.push(transaction);
+    }
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 3);
+
+    // Retrieve the state path of a record in the block to prune.
+    let (pruned, retained) = (&transactions[0], &transactions[1]);
+    let commitment = pruned.commitments().next().unwrap();
+    let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+
+    // Ensure the latest block must be retained.
+    assert!(ledger.enable_pruning(0).is_err());
+    // Enable pruning, retaining the last 2 blocks.
+    ledger.enable_pruning(2).unwrap();
+    let config = ledger.vm().block_store().get_pruning_config().unwrap().unwrap();
+    assert_eq!(config.retention(), 2);
+    assert_eq!(config.pruned_height(), Some(1));
+
+    // Ensure the pruned block and its transaction return a pruned error.
+    assert!(ledger.get_block(1).unwrap_err().to_string().contains("pruned"));
+    assert!(ledger.get_transaction(pruned.id()).unwrap_err().to_string().contains("pruned"));
+    // Ensure the records of the pruned block remain provable.
+    assert_eq!(ledger.get_state_path_for_commitment(commitment).unwrap(), state_path);
+    // Ensure the consensus data of the pruned block is kept.
+    assert!(ledger.contains_commitment(commitment).unwrap());
+    for serial_number in pruned.serial_numbers() {
+        assert!(ledger.contains_serial_number(serial_number).unwrap());
+    }
+    assert!(ledger.get_state_root(1).unwrap().is_some());
+
+    // Ensure the retained block and its transaction are intact.
+    assert!(ledger.get_block(2).is_ok());
+    assert_eq!(ledger.get_transaction(retained.id()).unwrap(), *retained);
+    assert!(ledger.get_state_path_for_commitment(retained.commitments().next().unwrap()).is_ok());
+
+    // Ensure the next block prunes the block outside the retention window.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.vm().block_store().get_pruning_config().unwrap().unwrap().pruned_height(), Some(2));
+    assert!(ledger.get_transaction(retained.id()).unwrap_err().to_string().contains("pruned"));
+    assert!(ledger.get_block(3).is_ok());
+
+    // Ensure the state paths of the pruned records are anchored to the latest state root.
+    for commitment in pruned.commitments().chain(retained.commitments()) {
+        let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+        assert_eq!(state_path.global_state_root(), ledger.latest_state_root());
+        assert!(state_path.verify(true, Field::zero()).is_ok());
+    }
+
+    // Ensure pruned blocks cannot be removed.
+    assert!(ledger.vm().block_store().remove_last_n(3).is_err());
+    assert_eq!(ledger.latest_height(), 4);
+}
@@ -15,19 +15,25 @@
 pub mod confirmed_tx_type;
 pub use confirmed_tx_type::*;
 
+mod pruning;
+pub use pruning::*;
+
 use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+    IntegrityReport,
     TransactionStorage,
     TransactionStore,
+    TransitionPosition,
     TransitionStorage,
     TransitionStore,
 };
 use console::{
+    account::Address,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockPath, BlockTree, HeaderLeaf, ProgramID, StatePath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -39,7 +45,9 @@ use ledger_block::{
     Rejected,
     Solutions,
     Transaction,
+    TransactionReceipt,
     Transactions,
+    Transition,
 };
 use ledger_coinbase::{ProverSolution, PuzzleCommitment};
 use ledger_narwhal_batch_certificate::BatchCertificate;
@@ -103,6 +111,9 @@ fn to_confirmed_transaction<N: Network>(
     }
 }
 
+/// The key of the block pruning configuration.
+const PRUNING_KEY: u8 = 0;
+
 /// A trait for block storage.
 pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `block height` to `state root`.
@@ -139,6 +150,18 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of unconfirmed `transaction ID` to `block height` to `transaction receipt`.
+    type ReceiptsMap: for<'a> NestedMap<'a, N::TransactionID, u32, TransactionReceipt<N>>;
+    /// The mapping of `address` to the height of the next block to scan for its records.
+    type ScanCheckpointMap: for<'a> Map<'a, Address<N>, u32>;
+    /// The mapping of `block tree node index` to `block tree node hash`.
+    type TreeMap: for<'a> Map<'a, u64, Field<N>>;
+    /// The mapping of the pruning key to the block pruning configuration.
+    type PruningMap: for<'a> Map<'a, u8, BlockPruning>;
+    /// The mapping of a record `commitment` in a pruned block to its `state path`.
+    type PrunedStatePathMap: for<'a> Map<'a, Field<N>, StatePath<N>>;
+    /// The mapping of a rejected deployment or execution ID in a pruned block to its `block height`.
+    type PrunedRejectedIDMap: for<'a> Map<'a, Field<N>, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +204,18 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap;
+    /// Returns the scan checkpoint map.
+    fn scan_checkpoint_map(&self) -> &Self::ScanCheckpointMap;
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap;
+    /// Returns the block pruning map.
+    fn pruning_map(&self) -> &Self::PruningMap;
+    /// Returns the pruned state path map.
+    fn pruned_state_path_map(&self) -> &Self::PrunedStatePathMap;
+    /// Returns the pruned rejected ID map.
+    fn pruned_rejected_id_map(&self) -> &Self::PrunedRejectedIDMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -193,6 +228,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         debug_assert!(self.transaction_store().storage_mode() == self.transition_store().storage_mode());
         self.transition_store().storage_mode()
     }
+    /// Returns `true` if the storage is read-only.
+    fn is_read_only(&self) -> bool {
+        false
+    }
 
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
@@ -213,6 +252,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.receipts_map().start_atomic();
+        self.scan_checkpoint_map().start_atomic();
+        self.tree_map().start_atomic();
+        self.pruning_map().start_atomic();
+        self.pruned_state_path_map().start_atomic();
+        self.pruned_rejected_id_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +280,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.receipts_map().is_atomic_in_progress()
+            || self.scan_checkpoint_map().is_atomic_in_progress()
+            || self.tree_map().is_atomic_in_progress()
+            || self.pruning_map().is_atomic_in_progress()
+            || self.pruned_state_path_map().is_atomic_in_progress()
+            || self.pruned_rejected_id_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +308,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.receipts_map().atomic_checkpoint();
+        self.scan_checkpoint_map().atomic_checkpoint();
+        self.tree_map().atomic_checkpoint();
+        self.pruning_map().atomic_checkpoint();
+        self.pruned_state_path_map().atomic_checkpoint();
+        self.pruned_rejected_id_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +336,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.receipts_map().clear_latest_checkpoint();
+        self.scan_checkpoint_map().clear_latest_checkpoint();
+        self.tree_map().clear_latest_checkpoint();
+        self.pruning_map().clear_latest_checkpoint();
+        self.pruned_state_path_map().clear_latest_checkpoint();
+        self.pruned_rejected_id_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +364,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.receipts_map().atomic_rewind();
+        self.scan_checkpoint_map().atomic_rewind();
+        self.tree_map().atomic_rewind();
+        self.pruning_map().atomic_rewind();
+        self.pruned_state_path_map().atomic_rewind();
+        self.pruned_rejected_id_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +392,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.receipts_map().abort_atomic();
+        self.scan_checkpoint_map().abort_atomic();
+        self.tree_map().abort_atomic();
+        self.pruning_map().abort_atomic();
+        self.pruned_state_path_map().abort_atomic();
+        self.pruned_rejected_id_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +420,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.receipts_map().finish_atomic()?;
+        self.scan_checkpoint_map().finish_atomic()?;
+        self.tree_map().finish_atomic()?;
+        self.pruning_map().finish_atomic()?;
+        self.pruned_state_path_map().finish_atomic()?;
+        self.pruned_rejected_id_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -456,6 +537,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Store the transaction.
                 self.transaction_store().insert(&transaction)?;
             }
+            // Index the transitions by their program ID and function name.
+            self.transition_store().insert_function_index(block.height(), block.transactions().transitions())?;
 
             Ok(())
         })
@@ -498,8 +581,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Retrieve the block transactions.
+        let transactions = self.get_block_transactions(block_hash)?;
+
         // Retrieve the rejected transaction IDs, and the deployment or execution ID.
-        let rejected_transaction_ids_and_deployment_or_execution_id = match self.get_block_transactions(block_hash)? {
+        let rejected_transaction_ids_and_deployment_or_execution_id = match &transactions {
             Some(transactions) => transactions
                 .iter()
                 .filter(|tx| tx.is_rejected())
@@ -508,6 +594,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Determine the transaction IDs of the receipts to remove.
+        let receipt_ids_to_remove: Vec<_> = transaction_ids
+            .iter()
+            .copied()
+            .chain(rejected_transaction_ids_and_deployment_or_execution_id.iter().map(|(id, _)| *id))
+            .chain(aborted_transaction_ids.iter().copied())
+            .collect();
+
+        // Determine the scan checkpoints past the removed block, which must be rewound to the removed block.
+        let scan_checkpoints_to_rewind: Vec<_> = self
+            .scan_checkpoint_map()
+            .iter_confirmed()
+            .filter(|(_, height)| **height > block_height)
+            .map(|(address, _)| cow_to_copied!(address))
+            .collect();
+
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
             Some(authority) => match authority {
@@ -578,6 +680,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
             }
 
+            // Remove the function index entries of the block transitions.
+            if let Some(transactions) = &transactions {
+                self.transition_store().remove_function_index(block_height, transactions.transitions())?;
+            }
+
             // Remove the block transactions.
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
@@ -586,6 +693,26 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the transaction receipts.
+            for transaction_id in receipt_ids_to_remove.iter() {
+                self.receipts_map().remove_key(transaction_id, &block_height)?;
+            }
+
+            // Rewind the scan checkpoints, so the removed block is scanned again once it is replaced.
+            for address in scan_checkpoints_to_rewind {
+                self.scan_checkpoint_map().insert(address, block_height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Stores the given transaction receipts into storage.
+    fn insert_receipts(&self, receipts: &[TransactionReceipt<N>]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for receipt in receipts {
+                self.receipts_map().insert(receipt.transaction_id(), receipt.block_height(), receipt.clone())?;
+            }
             Ok(())
         })
     }
@@ -603,7 +730,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns `true` if the given rejected deployment or execution ID.
     fn contains_rejected_deployment_or_execution_id(&self, rejected_id: &Field<N>) -> Result<bool> {
-        self.rejected_deployment_or_execution_map().contains_key_confirmed(rejected_id)
+        Ok(self.rejected_deployment_or_execution_map().contains_key_confirmed(rejected_id)?
+            || self.pruned_rejected_id_map().contains_key_confirmed(rejected_id)?)
     }
 
     /// Returns the block height that contains the given `state root`.
@@ -665,70 +793,64 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(block_hash) => block_hash,
             None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
         };
-
-        // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
-            Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
-        };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+        // Retrieve the block height.
+        let block_height = match self.get_block_height(&block_hash)? {
+            Some(block_height) => block_height,
+            None => bail!("The block height for commitment '{commitment}' is missing in storage"),
         };
 
         // Construct the global state root and block path.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+        let block_path = block_tree.prove(block_height as usize, &block_hash.to_bits_le())?;
 
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
             bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing in storage");
         }
 
-        // Construct the transition root, transition path and transaction leaf.
-        let transition_root = transition.to_root()?;
-        let transition_leaf = transition.to_leaf(commitment, false)?;
-        let transition_path = transition.to_path(&transition_leaf)?;
+        // If the block has been pruned, use the state path that was stored when the block was pruned.
+        if self.get_pruning_config()?.map_or(false, |config| config.is_pruned(block_height)) {
+            let state_path = match self.pruned_state_path_map().get_confirmed(commitment)? {
+                Some(state_path) => cow_to_cloned!(state_path),
+                None => bail!("The state path for commitment '{commitment}' in pruned block {block_height} is missing"),
+            };
+            // Anchor the state path to the given block tree.
+            return Ok(StatePath::from(
+                global_state_root.into(),
+                block_path,
+                state_path.block_hash(),
+                state_path.previous_block_hash(),
+                *state_path.header_root(),
+                state_path.header_path().clone(),
+                *state_path.header_leaf(),
+                state_path.transactions_path().clone(),
+                *state_path.transaction_id(),
+                state_path.transaction_path().clone(),
+                *state_path.transaction_leaf(),
+                *state_path.transition_root(),
+                *state_path.tcm(),
+                state_path.transition_path().clone(),
+                *state_path.transition_leaf(),
+            ));
+        }
 
-        // Construct the transactions path.
-        let transactions = block.transactions();
-        let transactions_path = match transactions.to_path(transaction_id) {
-            Ok(transactions_path) => transactions_path,
-            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
+        // Retrieve the transition.
+        let transition = match self.transition_store().get_transition(&transition_id)? {
+            Some(transition) => transition,
+            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
         };
-
-        // Construct the transaction path and transaction leaf.
-        let transaction = match transactions.get(&transaction_id) {
+        // Retrieve the block.
+        let block = match self.get_block(&block_hash)? {
+            Some(block) => block,
+            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+        };
+        // Retrieve the transaction.
+        let transaction = match block.transactions().get(&transaction_id) {
             Some(transaction) => transaction,
             None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
         };
-        let transaction_leaf = transaction.to_leaf(transition.id())?;
-        let transaction_path = transaction.to_path(&transaction_leaf)?;
-
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
-        Ok(StatePath::from(
-            global_state_root.into(),
-            block_path,
-            block.hash(),
-            block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
-            transactions_path,
-            transaction.id(),
-            transaction_path,
-            transaction_leaf,
-            transition_root,
-            *transition.tcm(),
-            transition_path,
-            transition_leaf,
-        ))
+
+        to_state_path(global_state_root.into(), block_path, &block, transaction, &transition, commitment)
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -863,6 +985,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(transaction_ids) => transaction_ids,
             None => return Ok(None),
         };
+        // Ensure the block has not been pruned.
+        self.ensure_not_pruned(block_hash)?;
         // Retrieve the transactions.
         transaction_ids
             .iter()
@@ -900,7 +1024,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
                 None => bail!("Missing transactions for block '{block_hash}' in block storage"),
             },
-            None => self.transaction_store().get_transaction(transaction_id),
+            None => {
+                // Ensure the transaction has not been pruned.
+                self.ensure_transaction_not_pruned(transaction_id)?;
+                self.transaction_store().get_transaction(transaction_id)
+            }
         }
     }
 
@@ -936,7 +1064,462 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
                 None => bail!("Missing transactions for block '{block_hash}' in block storage"),
             },
-            None => self.transaction_store().get_transaction(transaction_id),
+            None => {
+                // Ensure the transaction has not been pruned.
+                self.ensure_transaction_not_pruned(transaction_id)?;
+                self.transaction_store().get_transaction(transaction_id)
+            }
+        }
+    }
+
+    /// Stores the given `(address, scan checkpoint)` pairs into storage.
+    fn insert_scan_checkpoints(&self, checkpoints: &[(Address<N>, u32)]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (address, height) in checkpoints {
+                self.scan_checkpoint_map().insert(*address, *height)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the scan checkpoint for the given `address`, which is the height of the next block to scan.
+    fn get_scan_checkpoint(&self, address: &Address<N>) -> Result<Option<u32>> {
+        match self.scan_checkpoint_map().get_confirmed(address)? {
+            Some(height) => Ok(Some(cow_to_copied!(height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the nodes of the given block tree that differ from the given previous block tree.
+    /// If no previous block tree is given, every node of the block tree is stored.
+    fn update_tree(&self, previous: Option<&BlockTree<N>>, tree: &BlockTree<N>) -> Result<()> {
+        // Retrieve the previous nodes.
+        let previous_nodes = previous.map(|previous| previous.tree()).unwrap_or_default();
+
+        atomic_batch_scope!(self, {
+            // Store the nodes that changed.
+            for (index, node) in tree.tree().iter().enumerate() {
+                if previous_nodes.get(index) != Some(node) {
+                    self.tree_map().insert(u64::try_from(index)?, *node)?;
+                }
+            }
+            // Remove the nodes that are no longer in the block tree.
+            for index in tree.tree().len()..previous_nodes.len() {
+                self.tree_map().remove(&u64::try_from(index)?)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the block tree with the given number of leaves, if all of its nodes are in storage.
+    fn get_tree(&self, number_of_leaves: usize) -> Result<Option<BlockTree<N>>> {
+        // Compute the number of nodes in the block tree.
+        let num_nodes = match number_of_leaves.checked_next_power_of_two() {
+            Some(max_leaves) => max_leaves + (max_leaves - 1),
+            None => bail!("Integer overflow when computing the size of the block tree"),
+        };
+        // Retrieve the nodes of the block tree.
+        let nodes = cfg_into_iter!(0..u64::try_from(num_nodes)?)
+            .map(|index| Ok(self.tree_map().get_confirmed(&index)?.map(|node| cow_to_copied!(node))))
+            .collect::<Result<Option<Vec<_>>>>()?;
+        // Construct the block tree.
+        match nodes {
+            Some(nodes) => Ok(Some(N::merkle_tree_bhp_from_hashes(nodes, number_of_leaves)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Checks that each block is complete, and checks the block indexes and receipts against the blocks.
+    /// If `repair` is `true`, the inconsistent index entries are rewritten from their source maps,
+    /// and the dangling entries are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Ensure the block heights are contiguous.
+        let mut heights: Vec<u32> = self.id_map().keys_confirmed().map(|height| cow_to_copied!(height)).collect();
+        heights.sort_unstable();
+        for (expected, height) in (0u32..).zip(&heights) {
+            if *height != expected {
+                report.add_issue("Block(ID)", format!("Missing block {expected} (found block {height})"), false);
+                break;
+            }
+        }
+        report.add_checked("Block(ID)", heights.len());
+
+        // Ensure each block is complete.
+        for (height, block_hash) in self.id_map().iter_confirmed() {
+            // Ensure the block header exists, and is for the block height.
+            match self.header_map().get_confirmed(&block_hash)? {
+                Some(header) if header.height() == *height => (),
+                Some(header) => {
+                    let message = format!("Header of block '{}' has height {}", *block_hash, header.height());
+                    report.add_issue("Block(Header)", message, false)
+                }
+                None => report.add_issue("Block(Header)", format!("Missing header of block '{}'", *block_hash), false),
+            }
+            // Ensure the remaining block components exist.
+            macro_rules! ensure_component {
+                ($map:ident, $name:literal, $component:literal) => {
+                    if !self.$map().contains_key_confirmed(&block_hash)? {
+                        let message = format!("Missing {} of block '{}'", $component, *block_hash);
+                        report.add_issue($name, message, false);
+                    }
+                };
+            }
+            ensure_component!(authority_map, "Block(Authority)", "authority");
+            ensure_component!(ratifications_map, "Block(Ratifications)", "ratifications");
+            ensure_component!(solutions_map, "Block(Solutions)", "solutions");
+            ensure_component!(aborted_solution_ids_map, "Block(AbortedSolutionIDs)", "aborted solution IDs");
+            ensure_component!(aborted_transaction_ids_map, "Block(AbortedTransactionIDs)", "aborted transaction IDs");
+
+            // Ensure each transaction in the block is confirmed in the block, and exists.
+            let transaction_ids = match self.transactions_map().get_confirmed(&block_hash)? {
+                Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+                None => {
+                    let message = format!("Missing transactions of block '{}'", *block_hash);
+                    report.add_issue("Block(Transactions)", message, false);
+                    continue;
+                }
+            };
+            for transaction_id in transaction_ids {
+                let confirmed = self.confirmed_transactions_map().get_confirmed(&transaction_id)?;
+                if !confirmed.is_some_and(|confirmed| confirmed.0 == *block_hash) {
+                    let message = format!("Transaction '{transaction_id}' is not confirmed in block '{}'", *block_hash);
+                    report.add_issue("Block(ConfirmedTransactions)", message, false);
+                }
+                if !self.transaction_store().contains_transaction_id(&transaction_id)? {
+                    let message = format!("Missing transaction '{transaction_id}' of block '{}'", *block_hash);
+                    report.add_issue("Transaction(ID)", message, false);
+                }
+            }
+        }
+
+        // Check the reverse block hashes.
+        let expected: Vec<_> = self.id_map().iter_confirmed().map(|(height, hash)| (*hash, *height)).collect();
+        report.check_index(
+            "Block(ReverseID)",
+            self.reverse_id_map(),
+            expected,
+            |block_hash, height| Ok(self.id_map().get_confirmed(height)?.is_some_and(|hash| *hash == *block_hash)),
+            repair,
+        )?;
+
+        // Check the reverse state roots.
+        let expected: Vec<_> = self.state_root_map().iter_confirmed().map(|(height, root)| (*root, *height)).collect();
+        report.check_index(
+            "Block(ReverseStateRoot)",
+            self.reverse_state_root_map(),
+            expected,
+            |state_root, height| {
+                Ok(self.state_root_map().get_confirmed(height)?.is_some_and(|root| *root == *state_root))
+            },
+            repair,
+        )?;
+
+        // Check the puzzle commitments.
+        let mut expected = Vec::new();
+        for (height, block_hash) in self.id_map().iter_confirmed() {
+            if let Some(solutions) = self.solutions_map().get_confirmed(&block_hash)? {
+                expected.extend(solutions.solution_ids().map(|solution_id| (*solution_id, *height)));
+            }
+        }
+        report.check_index(
+            "Block(PuzzleCommitments)",
+            self.puzzle_commitments_map(),
+            expected,
+            |solution_id, height| {
+                let Some(block_hash) = self.id_map().get_confirmed(height)? else { return Ok(false) };
+                Ok(self
+                    .solutions_map()
+                    .get_confirmed(&block_hash)?
+                    .is_some_and(|solutions| solutions.solution_ids().any(|id| id == solution_id)))
+            },
+            repair,
+        )?;
+
+        // Ensure each block has a state root, and each state root belongs to a block.
+        for height in &heights {
+            if !self.state_root_map().contains_key_confirmed(height)? {
+                report.add_issue("Block(StateRoot)", format!("Missing state root of block {height}"), false);
+            }
+        }
+        report.check_index(
+            "Block(StateRoot)",
+            self.state_root_map(),
+            [],
+            |height, _| self.id_map().contains_key_confirmed(height),
+            repair,
+        )?;
+
+        // Check the certificates, aborted solution heights, and aborted transaction IDs against the blocks.
+        let mut certificates = Vec::new();
+        let mut aborted_solutions = Vec::new();
+        let mut rejected_or_aborted = Vec::new();
+        for (height, block_hash) in self.id_map().iter_confirmed() {
+            if let Some(authority) = self.authority_map().get_confirmed(&block_hash)? {
+                if let Authority::Quorum(subdag) = &*authority {
+                    for (round, certificate) in subdag.iter().flat_map(|(round, c)| c.iter().map(move |c| (round, c))) {
+                        certificates.push((certificate.id(), (*height, *round)));
+                    }
+                }
+            }
+            if let Some(solution_ids) = self.aborted_solution_ids_map().get_confirmed(&block_hash)? {
+                aborted_solutions.extend(solution_ids.iter().map(|solution_id| (*solution_id, *height)));
+            }
+            if let Some(transaction_ids) = self.aborted_transaction_ids_map().get_confirmed(&block_hash)? {
+                rejected_or_aborted.extend(transaction_ids.iter().map(|transaction_id| (*transaction_id, *block_hash)));
+            }
+        }
+        report.check_index("Block(Certificate)", self.certificate_map(), certificates, |_, _| Ok(false), repair)?;
+        report.check_index(
+            "Block(AbortedSolutionHeights)",
+            self.aborted_solution_heights_map(),
+            aborted_solutions,
+            |_, _| Ok(false),
+            repair,
+        )?;
+
+        // Ensure the pruned blocks are before the latest block.
+        let pruned_height = self.get_pruning_config()?.and_then(|config| config.pruned_height());
+        if let Some(pruned_height) = pruned_height {
+            if heights.last().map_or(true, |latest_height| pruned_height >= *latest_height) {
+                let message = format!("Blocks are pruned up to block {pruned_height}, past the latest retained block");
+                report.add_issue("Block(Pruning)", message, false);
+            }
+        }
+        report.add_checked("Block(Pruning)", self.pruning_map().len_confirmed());
+        let is_pruned = |height: u32| pruned_height.is_some_and(|pruned_height| height <= pruned_height);
+
+        // Check the function index and rejected transactions against the blocks.
+        // Note: The transactions of a pruned block cannot be reconstructed, so its transitions are not indexed,
+        // and its rejected deployments and executions are indexed by ID, from their confirmed transaction types.
+        let mut function_index = Vec::new();
+        let mut rejected = Vec::new();
+        let mut pruned_rejected = Vec::new();
+        for (height, block_hash) in self.id_map().iter_confirmed() {
+            if is_pruned(*height) {
+                let Some(transaction_ids) = self.transactions_map().get_confirmed(&block_hash)? else { continue };
+                for transaction_id in transaction_ids.iter() {
+                    let Some(confirmed) = self.confirmed_transactions_map().get_confirmed(transaction_id)? else {
+                        continue;
+                    };
+                    match &confirmed.1 {
+                        ConfirmedTxType::RejectedDeploy(_, rejected)
+                        | ConfirmedTxType::RejectedExecute(_, rejected) => {
+                            pruned_rejected.push((rejected.to_id()?, *height))
+                        }
+                        ConfirmedTxType::AcceptedDeploy(..) | ConfirmedTxType::AcceptedExecute(..) => (),
+                    }
+                }
+            } else if let Some(transactions) = self.get_block_transactions(&block_hash)? {
+                for (index, transition) in transactions.transitions().enumerate() {
+                    let locator = (*transition.program_id(), *transition.function_name());
+                    let position = TransitionPosition::new(*height, u32::try_from(index)?);
+                    function_index.push((locator, position, *transition.id()));
+                }
+                for confirmed in transactions.iter() {
+                    if let Some(rejected_transaction) = confirmed.to_rejected() {
+                        rejected.push((rejected_transaction.to_id()?, rejected_transaction.clone()));
+                        rejected_or_aborted.push((confirmed.to_unconfirmed_transaction_id()?, *block_hash));
+                    }
+                }
+            }
+        }
+        report.check_index(
+            "Block(RejectedOrAbortedTransactionID)",
+            self.rejected_or_aborted_transaction_id_map(),
+            rejected_or_aborted,
+            // Note: The rejected transaction IDs of a pruned block cannot be recomputed, so they are kept.
+            |_, block_hash| Ok(self.reverse_id_map().get_confirmed(block_hash)?.is_some_and(|h| is_pruned(*h))),
+            repair,
+        )?;
+        report.check_index(
+            "Block(RejectedDeploymentOrExecution)",
+            self.rejected_deployment_or_execution_map(),
+            rejected,
+            |_, _| Ok(false),
+            repair,
+        )?;
+        report.check_index(
+            "Block(PrunedRejectedID)",
+            self.pruned_rejected_id_map(),
+            pruned_rejected,
+            |_, _| Ok(false),
+            repair,
+        )?;
+
+        // Ensure each state path of a pruned record belongs to a pruned block, and its record exists.
+        report.check_index(
+            "Block(PrunedStatePath)",
+            self.pruned_state_path_map(),
+            [],
+            |commitment, state_path| {
+                Ok(self.reverse_id_map().get_confirmed(&state_path.block_hash())?.is_some_and(|h| is_pruned(*h))
+                    && self.transition_store().contains_commitment(commitment)?)
+            },
+            repair,
+        )?;
+
+        // Ensure each transaction receipt is for a stored block.
+        report.check_nested_index(
+            "Block(Receipts)",
+            self.receipts_map(),
+            [],
+            |_, height, receipt| Ok(receipt.block_height() == *height && self.id_map().contains_key_confirmed(height)?),
+            repair,
+        )?;
+
+        self.transition_store().check_function_index(report, function_index, pruned_height, repair)
+    }
+
+    /// Enables pruning, retaining the given number of recent blocks in full.
+    /// Blocks that were pruned under a previous configuration remain pruned.
+    fn enable_pruning(&self, retention: u32) -> Result<()> {
+        // Ensure at least the latest block is retained.
+        ensure!(retention > 0, "The pruning retention must be at least 1 block");
+        // Retrieve the latest pruned block height, if any.
+        let pruned_height = self.pruning_map().get_speculative(&PRUNING_KEY)?.and_then(|config| config.pruned_height());
+
+        atomic_batch_scope!(self, {
+            // Update the block pruning configuration.
+            self.pruning_map().insert(PRUNING_KEY, BlockPruning::new(retention, pruned_height))?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the blocks that are outside the retention window, after the block at the given height is stored.
+    /// The given block tree must contain the blocks to prune. If pruning is not enabled, this method does nothing.
+    fn prune(&self, block_height: u32, block_tree: &BlockTree<N>) -> Result<()> {
+        // Retrieve the block pruning configuration.
+        let config = match self.pruning_map().get_speculative(&PRUNING_KEY)? {
+            Some(config) => cow_to_copied!(config),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the blocks, in ascending order.
+            for height in config.heights_to_prune(block_height) {
+                self.prune_block(height, block_tree)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the proofs and transition payloads of the block at the given height,
+    /// which must be the block after the latest pruned block.
+    ///
+    /// The state paths of the records output in the block are stored first, so that they remain provable.
+    /// The rejected deployments and executions of the block are replaced by their IDs.
+    fn prune_block(&self, block_height: u32, block_tree: &BlockTree<N>) -> Result<()> {
+        // Retrieve the block pruning configuration.
+        let config = match self.pruning_map().get_speculative(&PRUNING_KEY)? {
+            Some(config) => cow_to_copied!(config),
+            None => bail!("Failed to prune block {block_height}: pruning is not enabled"),
+        };
+        // Ensure the blocks are pruned in order.
+        let expected_height = config.pruned_height().map_or(0, |height| height.saturating_add(1));
+        ensure!(
+            block_height == expected_height,
+            "Failed to prune block {block_height}: expected block {expected_height}"
+        );
+        // Retrieve the block hash.
+        let Some(block_hash) = self.get_block_hash(block_height)? else {
+            bail!("Failed to prune block {block_height}: missing block hash")
+        };
+        // Retrieve the block.
+        let Some(block) = self.get_block(&block_hash)? else {
+            bail!("Failed to prune block {block_height}: missing block '{block_hash}'")
+        };
+
+        // Construct the state paths of the records output in the block.
+        let global_state_root = *block_tree.root();
+        let block_path = block_tree.prove(block_height as usize, &block_hash.to_bits_le())?;
+        let mut state_paths = Vec::new();
+        for transaction in block.transactions().iter().map(|confirmed| confirmed.transaction()) {
+            for transition in transaction.transitions() {
+                for commitment in transition.commitments() {
+                    let state_path = to_state_path(
+                        global_state_root.into(),
+                        block_path.clone(),
+                        &block,
+                        transaction,
+                        transition,
+                        commitment,
+                    )?;
+                    state_paths.push((*commitment, state_path));
+                }
+            }
+        }
+        // Retrieve the rejected deployment or execution IDs.
+        let rejected_ids = block
+            .transactions()
+            .iter()
+            .filter_map(|confirmed| confirmed.to_rejected_id().transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_batch_scope!(self, {
+            // Store the state paths of the records.
+            for (commitment, state_path) in state_paths {
+                self.pruned_state_path_map().insert(commitment, state_path)?;
+            }
+            // Replace the rejected deployments and executions with their IDs.
+            for rejected_id in rejected_ids {
+                self.rejected_deployment_or_execution_map().remove(&rejected_id)?;
+                self.pruned_rejected_id_map().insert(rejected_id, block_height)?;
+            }
+            // Prune the transactions.
+            for transaction_id in block.transaction_ids() {
+                self.transaction_store().prune(transaction_id)?;
+            }
+            // Update the latest pruned block height.
+            self.pruning_map().insert(PRUNING_KEY, BlockPruning::new(config.retention(), Some(block_height)))?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the block pruning configuration, if pruning is enabled.
+    fn get_pruning_config(&self) -> Result<Option<BlockPruning>> {
+        Ok(self.pruning_map().get_confirmed(&PRUNING_KEY)?.map(|config| cow_to_copied!(config)))
+    }
+
+    /// Returns an error if the block for the given `block hash` has been pruned.
+    fn ensure_not_pruned(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // If pruning is not enabled, the block has not been pruned.
+        let Some(config) = self.get_pruning_config()? else { return Ok(()) };
+        // Retrieve the block height.
+        match self.get_block_height(block_hash)? {
+            Some(height) if config.is_pruned(height) => bail!("Block {height} ('{block_hash}') has been pruned"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if the block containing the given `transaction ID` has been pruned.
+    fn ensure_transaction_not_pruned(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // If pruning is not enabled, the transaction has not been pruned.
+        let Some(config) = self.get_pruning_config()? else { return Ok(()) };
+        // Retrieve the block hash and height.
+        let Some(block_hash) = self.find_block_hash(transaction_id)? else { return Ok(()) };
+        match self.get_block_height(&block_hash)? {
+            Some(height) if config.is_pruned(height) => {
+                bail!("Transaction '{transaction_id}' in block {height} ('{block_hash}') has been pruned")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the latest transaction receipt for the given unconfirmed `transaction ID`.
+    /// Note: A transaction that is aborted may be included again in a later block, and so have several receipts.
+    fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionReceipt<N>>> {
+        let receipts = self.receipts_map().get_map_confirmed(transaction_id)?;
+        Ok(receipts.into_iter().max_by_key(|(height, _)| *height).map(|(_, receipt)| receipt))
+    }
+
+    /// Returns the transaction receipt for the given unconfirmed `transaction ID` in the block at the given height.
+    fn get_receipt_at(&self, transaction_id: &N::TransactionID, height: u32) -> Result<Option<TransactionReceipt<N>>> {
+        match self.receipts_map().get_value_confirmed(transaction_id, &height)? {
+            Some(receipt) => Ok(Some(cow_to_cloned!(receipt))),
+            None => Ok(None),
         }
     }
 
@@ -997,6 +1580,55 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// Returns the state path for the given record `commitment`, which is output by the given transition in the block.
+fn to_state_path<N: Network>(
+    global_state_root: N::StateRoot,
+    block_path: BlockPath<N>,
+    block: &Block<N>,
+    transaction: &Transaction<N>,
+    transition: &Transition<N>,
+    commitment: &Field<N>,
+) -> Result<StatePath<N>> {
+    // Construct the transition root, transition path and transaction leaf.
+    let transition_root = transition.to_root()?;
+    let transition_leaf = transition.to_leaf(commitment, false)?;
+    let transition_path = transition.to_path(&transition_leaf)?;
+
+    // Construct the transactions path.
+    let transactions_path = match block.transactions().to_path(transaction.id()) {
+        Ok(transactions_path) => transactions_path,
+        Err(_) => bail!("The transaction '{}' for commitment '{commitment}' is not in the block", transaction.id()),
+    };
+
+    // Construct the transaction path and transaction leaf.
+    let transaction_leaf = transaction.to_leaf(transition.id())?;
+    let transaction_path = transaction.to_path(&transaction_leaf)?;
+
+    // Construct the block header path.
+    let block_header = block.header();
+    let header_root = block_header.to_root()?;
+    let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+    let header_path = block_header.to_path(&header_leaf)?;
+
+    Ok(StatePath::from(
+        global_state_root,
+        block_path,
+        block.hash(),
+        block.previous_hash(),
+        header_root,
+        header_path,
+        header_leaf,
+        transactions_path,
+        transaction.id(),
+        transaction_path,
+        transaction_leaf,
+        transition_root,
+        *transition.tcm(),
+        transition_path,
+        transition_leaf,
+    ))
+}
+
 /// The block store.
 #[derive(Clone)]
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
@@ -1011,29 +1643,42 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the block storage.
         let storage = B::open(storage)?;
-
-        // Compute the block tree.
-        let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
-                    })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
-        };
+        // Load the block tree.
+        let tree = Self::load_tree(&storage)?;
+        let tree = Arc::new(RwLock::new(tree));
 
         // Return the block store.
         Ok(Self { storage, tree })
     }
 
+    /// Reloads the block tree from storage, appending the blocks that were added since it was loaded.
+    /// If the latest block of the block tree was removed or replaced since, the block tree is reloaded in full.
+    /// This is used to follow a block storage that is written to by another process.
+    pub fn reload_tree(&self) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        let number_of_leaves = u32::try_from(tree.number_of_leaves())?;
+
+        // Ensure the latest block of the block tree is unchanged, by checking its state root.
+        if let Some(latest_height) = number_of_leaves.checked_sub(1) {
+            if self.storage.get_state_root(latest_height)? != Some((*tree.root()).into()) {
+                *tree = Self::load_tree(&self.storage)?;
+                return Ok(());
+            }
+        }
+
+        // Retrieve the hashes of the blocks that were added since.
+        let mut hashes = Vec::new();
+        while let Some(block_hash) = self.storage.get_block_hash(number_of_leaves + u32::try_from(hashes.len())?)? {
+            hashes.push(block_hash.to_bits_le());
+        }
+        // Append the blocks to the block tree.
+        if !hashes.is_empty() {
+            *tree = tree.prepare_append(&hashes)?;
+        }
+        Ok(())
+    }
+
     /// Stores the given block into storage.
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
@@ -1044,14 +1689,50 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Store the updated nodes of the block tree.
+            self.storage.update_tree(Some(&tree), &updated_tree)?;
+            // Prune the blocks that are outside the retention window.
+            self.storage.prune(block.height(), &tree)
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
         Ok(())
     }
 
+    /// Stores the given transaction receipts into storage.
+    pub fn insert_receipts(&self, receipts: &[TransactionReceipt<N>]) -> Result<()> {
+        self.storage.insert_receipts(receipts)
+    }
+
+    /// Stores the given `(address, scan checkpoint)` pairs into storage.
+    pub fn insert_scan_checkpoints(&self, checkpoints: &[(Address<N>, u32)]) -> Result<()> {
+        self.storage.insert_scan_checkpoints(checkpoints)
+    }
+
+    /// Enables pruning, retaining the given number of recent blocks in full, and prunes the older blocks.
+    ///
+    /// Pruning removes the proofs and transition payloads of the older blocks, while keeping the data needed
+    /// for consensus (serial numbers, tags, commitments, state roots, the block tree, programs, and verifying keys).
+    /// Once pruned, a block cannot be retrieved or removed, and the records it created are no longer found by scans,
+    /// although their state paths remain available.
+    pub fn enable_pruning(&self, retention: u32) -> Result<()> {
+        // Acquire the write lock on the block tree, to ensure no blocks are inserted concurrently.
+        let tree = self.tree.write();
+        // Update the block pruning configuration.
+        self.storage.enable_pruning(retention)?;
+        // Prune the older blocks, one block per atomic batch.
+        if let (Some(config), Some(latest_height)) = (self.storage.get_pruning_config()?, self.heights().max()) {
+            for height in config.heights_to_prune(*latest_height) {
+                self.storage.prune_block(height, &tree)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Reverts the Merkle tree to its shape before the insertion of the last 'n' blocks.
     pub fn remove_last_n_from_tree_only(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -1085,6 +1766,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
                     .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
                 // Ensure the block height matches the number of leaves in the Merkle tree.
                 ensure!(end_height == u32::try_from(tree.number_of_leaves())? - 1, "Block height mismatch");
+                // Ensure none of the blocks have been pruned.
+                if let Some(config) = self.storage.get_pruning_config()? {
+                    ensure!(
+                        !config.is_pruned(start_height),
+                        "Failed to remove last '{n}' blocks: block {start_height} has been pruned"
+                    );
+                }
                 // Output the block heights.
                 start_height..=end_height
             }
@@ -1106,7 +1794,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
             }
-            Ok(())
+            // Store the updated nodes of the block tree.
+            self.storage.update_tree(Some(&tree), &updated_tree)
         })?;
 
         // Update the block tree.
@@ -1165,6 +1854,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.storage_mode()
     }
 
+    /// Returns the block storage.
+    #[cfg(test)]
+    pub(crate) const fn storage(&self) -> &B {
+        &self.storage
+    }
+
     /// Pauses atomic writes.
     pub fn pause_atomic_writes(&self) -> Result<()> {
         self.storage.pause_atomic_writes()
@@ -1174,6 +1869,75 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
         self.storage.unpause_atomic_writes::<DISCARD_BATCH>()
     }
+
+    /// Checks the blocks, the block indexes, and the persisted block tree, repairing the indexes if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Check the blocks and the block indexes.
+        self.storage.check_integrity(report, repair)?;
+
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+        // Ensure the block tree is for the latest block.
+        let num_blocks = self.storage.id_map().len_confirmed();
+        if tree.number_of_leaves() != num_blocks {
+            let message = format!("Block tree has {} leaves, expected {num_blocks}", tree.number_of_leaves());
+            report.add_issue("Block(Tree)", message, false);
+        }
+        // Ensure the persisted block tree matches the block tree.
+        let persisted_tree = self.storage.get_tree(tree.number_of_leaves())?;
+        if persisted_tree.as_ref().map(|persisted| persisted.tree()) != Some(tree.tree()) {
+            if repair {
+                self.storage.update_tree(None, &tree)?;
+            }
+            report.add_issue("Block(Tree)", "Persisted block tree does not match the block tree".to_string(), repair);
+        }
+        report.add_checked("Block(Tree)", tree.tree().len());
+
+        Ok(())
+    }
+
+    /// Loads the block tree from the given storage.
+    /// If the persisted block tree is missing or stale, the block tree is rebuilt from the block hashes.
+    fn load_tree(storage: &B) -> Result<BlockTree<N>> {
+        // Determine the number of blocks in storage.
+        let number_of_leaves = match storage.id_map().keys_confirmed().max() {
+            Some(height) => usize::try_from(cow_to_copied!(height))? + 1,
+            None => 0,
+        };
+        // Load the persisted block tree, and ensure its root matches the latest state root.
+        let persisted_tree = match storage.get_tree(number_of_leaves)? {
+            Some(tree) => match number_of_leaves.checked_sub(1) {
+                Some(latest_height) => match storage.state_root_map().get_confirmed(&u32::try_from(latest_height)?)? {
+                    Some(state_root) if *cow_to_copied!(state_root) == *tree.root() => Some(tree),
+                    // If the roots do not match, the block tree is rebuilt below.
+                    _ => None,
+                },
+                None => Some(tree),
+            },
+            None => None,
+        };
+
+        // Compute the block tree.
+        match persisted_tree {
+            Some(tree) => Ok(tree),
+            None => {
+                // Prepare the leaves of the block tree.
+                let hashes = cfg_into_iter!(0..u32::try_from(number_of_leaves)?)
+                    .map(|height| match storage.get_block_hash(height)? {
+                        Some(hash) => Ok(hash.to_bits_le()),
+                        None => bail!("Missing block hash for block {height}"),
+                    })
+                    .collect::<Result<Vec<Vec<bool>>>>()?;
+                // Construct the block tree.
+                let tree = N::merkle_tree_bhp(&hashes)?;
+                // Persist the block tree, unless the storage is read-only.
+                if !storage.is_read_only() {
+                    storage.update_tree(None, &tree)?;
+                }
+                Ok(tree)
+            }
+        }
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1202,6 +1966,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if the block store is empty.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -1283,6 +2052,30 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_unconfirmed_transaction(transaction_id)
     }
 
+    /// Returns the scan checkpoint for the given `address`, which is the height of the next block to scan.
+    pub fn get_scan_checkpoint(&self, address: &Address<N>) -> Result<Option<u32>> {
+        self.storage.get_scan_checkpoint(address)
+    }
+
+    /// Returns the block pruning configuration, if pruning is enabled.
+    pub fn get_pruning_config(&self) -> Result<Option<BlockPruning>> {
+        self.storage.get_pruning_config()
+    }
+
+    /// Returns the latest transaction receipt for the given unconfirmed `transaction ID`.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionReceipt<N>>> {
+        self.storage.get_receipt(transaction_id)
+    }
+
+    /// Returns the transaction receipt for the given unconfirmed `transaction ID` in the block at the given height.
+    pub fn get_receipt_at(
+        &self,
+        transaction_id: &N::TransactionID,
+        height: u32,
+    ) -> Result<Option<TransactionReceipt<N>>> {
+        self.storage.get_receipt_at(transaction_id, height)
+    }
+
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         self.storage.get_block(block_hash)
@@ -1406,6 +2199,180 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_persisted_tree() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the empty block tree is persisted.
+        let persisted = block_store.storage.get_tree(0).unwrap().unwrap();
+        assert_eq!(persisted.root(), block_store.tree.read().root());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the persisted block tree matches the block tree.
+        let persisted = block_store.storage.get_tree(1).unwrap().unwrap();
+        assert_eq!(persisted.tree(), block_store.tree.read().tree());
+        assert_eq!(persisted.root(), block_store.tree.read().root());
+        assert_eq!(*persisted.root(), *block_store.current_state_root());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the persisted block tree matches the block tree.
+        let persisted = block_store.storage.get_tree(0).unwrap().unwrap();
+        assert_eq!(persisted.tree(), block_store.tree.read().tree());
+        assert_eq!(persisted.root(), block_store.tree.read().root());
+    }
+
+    #[test]
+    fn test_reload_tree() {
+        // Sample the block.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new block store, and a second block store that shares its storage.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let follower = BlockStore {
+            storage: block_store.storage.clone(),
+            tree: Arc::new(RwLock::new(block_store.tree.read().clone())),
+        };
+
+        // Insert the block into the first block store.
+        block_store.insert(&block).unwrap();
+        // Ensure the block tree of the second block store is stale.
+        assert_ne!(follower.current_state_root(), block_store.current_state_root());
+
+        // Reload the block tree of the second block store, which appends the block.
+        follower.reload_tree().unwrap();
+        // Ensure the block trees match.
+        assert_eq!(follower.tree.read().tree(), block_store.tree.read().tree());
+        assert_eq!(follower.current_state_root(), block_store.current_state_root());
+        assert_eq!(follower.current_block_height(), Some(block.height()));
+
+        // Ensure reloading an up-to-date block tree does not change it.
+        follower.reload_tree().unwrap();
+        assert_eq!(follower.tree.read().tree(), block_store.tree.read().tree());
+
+        // Remove the block from the first block store.
+        block_store.remove_last_n(1).unwrap();
+
+        // Reload the block tree of the second block store, which reloads it in full, as its latest block was removed.
+        follower.reload_tree().unwrap();
+        // Ensure the block trees match.
+        assert_eq!(follower.tree.read().tree(), block_store.tree.read().tree());
+        assert_eq!(follower.current_block_height(), None);
+    }
+
+    #[test]
+    fn test_function_index() {
+        // Sample the block.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let transition = block.transactions().transitions().next().unwrap();
+        let (program_id, function_name) = (transition.program_id(), transition.function_name());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let transition_store = block_store.transition_store();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the transitions of the function are indexed, in block order.
+        let expected = block
+            .transactions()
+            .transitions()
+            .enumerate()
+            .filter(|(_, t)| t.program_id() == program_id && t.function_name() == function_name)
+            .map(|(index, t)| (TransitionPosition::new(block.height(), u32::try_from(index).unwrap()), *t.id()))
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        let start = TransitionPosition::default();
+        assert_eq!(transition_store.num_function_transitions(program_id, function_name).unwrap(), expected.len());
+        assert_eq!(transition_store.get_function_transitions(program_id, function_name, start, 100).unwrap(), expected);
+
+        // Ensure the transitions are paginated from the given position.
+        let mut paginated = Vec::new();
+        let mut position = start;
+        loop {
+            let page = transition_store.get_function_transitions(program_id, function_name, position, 1).unwrap();
+            let Some((last, _)) = page.last() else { break };
+            position = last.next();
+            paginated.extend(page);
+        }
+        assert_eq!(paginated, expected);
+
+        // Ensure the integrity check covers the function index.
+        let mut report = IntegrityReport::default();
+        block_store.check_integrity(&mut report, false).unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(report.checked()["Transition(FunctionIndex)"], block.transactions().transitions().count());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the index is empty.
+        assert_eq!(transition_store.num_function_transitions(program_id, function_name).unwrap(), 0);
+        assert!(transition_store.get_function_transitions(program_id, function_name, start, 100).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_insert_get_remove_receipts() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Construct the receipts of the block transactions.
+        let receipts = block
+            .transactions()
+            .iter()
+            .map(|confirmed| TransactionReceipt::from_confirmed(block.height(), confirmed, None).unwrap())
+            .collect::<Vec<_>>();
+        assert!(!receipts.is_empty());
+
+        // Ensure the receipts do not exist.
+        for receipt in &receipts {
+            assert_eq!(None, block_store.get_receipt(&receipt.transaction_id()).unwrap());
+        }
+
+        // Insert the receipts.
+        block_store.insert_receipts(&receipts).unwrap();
+
+        // Retrieve the receipts.
+        for receipt in &receipts {
+            assert_eq!(Some(receipt), block_store.get_receipt(&receipt.transaction_id()).unwrap().as_ref());
+        }
+
+        // Insert a receipt for the same transaction ID in a later block.
+        let transaction_id = receipts[0].transaction_id();
+        let later_receipt =
+            TransactionReceipt::from_aborted(block.height() + 1, transaction_id, Some("Aborted".to_string())).unwrap();
+        block_store.insert_receipts(&[later_receipt.clone()]).unwrap();
+
+        // Ensure the later receipt does not overwrite the earlier one.
+        assert_eq!(Some(&later_receipt), block_store.get_receipt(&transaction_id).unwrap().as_ref());
+        assert_eq!(Some(&receipts[0]), block_store.get_receipt_at(&transaction_id, block.height()).unwrap().as_ref());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the receipts were removed with the block.
+        for receipt in &receipts {
+            assert_eq!(None, block_store.get_receipt_at(&receipt.transaction_id(), block.height()).unwrap());
+        }
+        // Ensure the receipt of the later block remains.
+        assert_eq!(Some(later_receipt), block_store.get_receipt(&transaction_id).unwrap());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the block pruning.
+///
+/// When pruning is enabled, the proofs and transition payloads of the blocks that fall outside the retention window
+/// are removed from storage, while the data needed for consensus (serial numbers, tags, commitments, state roots,
+/// the block tree, programs, and verifying keys) is kept.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockPruning {
+    /// The number of recent blocks to retain in full.
+    retention: u32,
+    /// The latest pruned block height, or `None` if no blocks have been pruned.
+    pruned_height: Option<u32>,
+}
+
+impl BlockPruning {
+    /// Initializes a new block pruning configuration.
+    pub(crate) const fn new(retention: u32, pruned_height: Option<u32>) -> Self {
+        Self { retention, pruned_height }
+    }
+
+    /// Returns the number of recent blocks to retain in full.
+    pub const fn retention(&self) -> u32 {
+        self.retention
+    }
+
+    /// Returns the latest pruned block height, if any blocks have been pruned.
+    pub const fn pruned_height(&self) -> Option<u32> {
+        self.pruned_height
+    }
+
+    /// Returns `true` if the block at the given height has been pruned.
+    pub fn is_pruned(&self, block_height: u32) -> bool {
+        matches!(self.pruned_height, Some(pruned_height) if block_height <= pruned_height)
+    }
+
+    /// Returns the block heights to prune, after the block at the given height is stored.
+    pub(crate) fn heights_to_prune(&self, block_height: u32) -> std::ops::Range<u32> {
+        // The pruning resumes after the latest pruned block height.
+        let start_height = self.pruned_height.map_or(0, |height| height.saturating_add(1));
+        // The pruning ends before the retention window.
+        let end_height = block_height.saturating_add(1).saturating_sub(self.retention);
+        start_height..end_height.max(start_height)
+    }
+}
@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{Map, NestedMap};
+use console::network::prelude::*;
+
+use indexmap::IndexMap;
+use std::collections::HashSet;
+
+/// An inconsistency found by the storage integrity check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityIssue {
+    /// The name of the inconsistent map, as in its `MapID` (e.g. `Block(ReverseID)`).
+    map: &'static str,
+    /// The description of the inconsistency.
+    message: String,
+    /// Whether the inconsistency was repaired.
+    is_repaired: bool,
+}
+
+impl IntegrityIssue {
+    /// Returns the name of the inconsistent map.
+    pub const fn map(&self) -> &'static str {
+        self.map
+    }
+
+    /// Returns the description of the inconsistency.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns `true` if the inconsistency was repaired.
+    pub const fn is_repaired(&self) -> bool {
+        self.is_repaired
+    }
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.is_repaired {
+            true => write!(f, "{}: {} (repaired)", self.map, self.message),
+            false => write!(f, "{}: {}", self.map, self.message),
+        }
+    }
+}
+
+/// The report of a storage integrity check.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// The number of entries checked in each map.
+    checked: IndexMap<&'static str, usize>,
+    /// The inconsistencies found.
+    issues: Vec<IntegrityIssue>,
+    /// The maps checked only against the local storage, as no block header commits to them.
+    local_only: Vec<&'static str>,
+}
+
+impl IntegrityReport {
+    /// Returns the number of entries checked in each map.
+    pub const fn checked(&self) -> &IndexMap<&'static str, usize> {
+        &self.checked
+    }
+
+    /// Returns the inconsistencies found.
+    pub fn issues(&self) -> &[IntegrityIssue] {
+        &self.issues
+    }
+
+    /// Returns the maps checked only against the local storage, as no block header commits to them.
+    /// Note: A map that is consistent with the local storage may still diverge from the network.
+    pub fn local_only(&self) -> &[&'static str] {
+        &self.local_only
+    }
+
+    /// Returns `true` if no inconsistencies remain, i.e. none were found or all were repaired.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.iter().all(IntegrityIssue::is_repaired)
+    }
+
+    /// Records that the given number of entries were checked in the given map.
+    pub(crate) fn add_checked(&mut self, map: &'static str, num_entries: usize) {
+        *self.checked.entry(map).or_default() += num_entries;
+    }
+
+    /// Records that the given map was checked only against the local storage.
+    pub(crate) fn add_local_only(&mut self, map: &'static str) {
+        if !self.local_only.contains(&map) {
+            self.local_only.push(map);
+        }
+    }
+
+    /// Records an inconsistency in the given map.
+    pub(crate) fn add_issue(&mut self, map: &'static str, message: String, is_repaired: bool) {
+        self.issues.push(IntegrityIssue { map, message, is_repaired });
+    }
+
+    /// Checks the given index against the entries expected from its source maps,
+    /// where `contains` returns `true` if a `(key, value)` pair of the index is in its source maps.
+    /// If `repair` is `true`, missing and incorrect entries are written, and dangling entries are removed.
+    pub(crate) fn check_index<'a, K, V, M>(
+        &mut self,
+        map: &'static str,
+        index: &'a M,
+        expected: impl IntoIterator<Item = (K, V)>,
+        contains: impl Fn(&K, &V) -> Result<bool>,
+        repair: bool,
+    ) -> Result<()>
+    where
+        K: 'a + Copy + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync + Debug,
+        V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync + Debug,
+        M: Map<'a, K, V>,
+    {
+        // Track the expected keys, so they are not treated as dangling.
+        let mut expected_keys = HashSet::new();
+
+        // Ensure each expected entry is in the index.
+        for (key, value) in expected {
+            expected_keys.insert(key);
+            let message = match index.get_confirmed(&key)? {
+                Some(candidate) if *candidate == value => continue,
+                Some(candidate) => format!("Entry for {key:?} is {:?}, expected {value:?}", &*candidate),
+                None => format!("Missing entry for {key:?}"),
+            };
+            if repair {
+                index.insert(key, value)?;
+            }
+            self.add_issue(map, message, repair);
+        }
+
+        // Ensure each entry in the index is in its source maps.
+        let mut num_entries = 0;
+        for (key, value) in index.iter_confirmed() {
+            num_entries += 1;
+            if expected_keys.contains(&*key) || contains(&key, &value)? {
+                continue;
+            }
+            if repair {
+                index.remove(&key)?;
+            }
+            self.add_issue(map, format!("Dangling entry for {:?}", &*key), repair);
+        }
+        self.add_checked(map, num_entries);
+
+        Ok(())
+    }
+
+    /// Checks the given nested index against the entries expected from its source maps,
+    /// where `contains` returns `true` if a `(map, key, value)` entry of the index is in its source maps.
+    /// If `repair` is `true`, missing and incorrect entries are written, and dangling entries are removed.
+    pub(crate) fn check_nested_index<'a, M, K, V, I>(
+        &mut self,
+        map: &'static str,
+        index: &'a I,
+        expected: impl IntoIterator<Item = (M, K, V)>,
+        contains: impl Fn(&M, &K, &V) -> Result<bool>,
+        repair: bool,
+    ) -> Result<()>
+    where
+        M: 'a + Copy + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync + Debug,
+        K: 'a + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync + Debug,
+        V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync + Debug,
+        I: NestedMap<'a, M, K, V>,
+    {
+        // Track the expected keys, so they are not treated as dangling.
+        let mut expected_keys = HashSet::new();
+
+        // Ensure each expected entry is in the index.
+        for (m, key, value) in expected {
+            let message = match index.get_value_confirmed(&m, &key)? {
+                Some(candidate) if *candidate == value => None,
+                Some(candidate) => Some(format!("Entry for {m:?} {key:?} is {:?}, expected {value:?}", &*candidate)),
+                None => Some(format!("Missing entry for {m:?} {key:?}")),
+            };
+            if let Some(message) = message {
+                if repair {
+                    index.insert(m, key.clone(), value)?;
+                }
+                self.add_issue(map, message, repair);
+            }
+            expected_keys.insert((m, key));
+        }
+
+        // Ensure each entry in the index is in its source maps.
+        let mut num_entries = 0;
+        for (m, key, value) in index.iter_confirmed() {
+            num_entries += 1;
+            if expected_keys.contains(&(*m, key.clone().into_owned())) || contains(&m, &key, &value)? {
+                continue;
+            }
+            if repair {
+                index.remove_key(&m, &key)?;
+            }
+            self.add_issue(map, format!("Dangling entry for {:?} {:?}", &*m, &*key), repair);
+        }
+        self.add_checked(map, num_entries);
+
+        Ok(())
+    }
+}
+
+impl Display for IntegrityReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the number of entries checked.
+        let num_entries: usize = self.checked.values().sum();
+        writeln!(f, "Checked {num_entries} entries in {} maps", self.checked.len())?;
+        // Write the maps checked only against the local storage.
+        if !self.local_only.is_empty() {
+            writeln!(f, "Checked against the local storage only: {}", self.local_only.join(", "))?;
+        }
+        // Write the inconsistencies.
+        match self.issues.is_empty() {
+            true => write!(f, "No inconsistencies found"),
+            false => {
+                write!(f, "Found {} inconsistencies", self.issues.len())?;
+                for issue in &self.issues {
+                    write!(f, "\n  {issue}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        memory::{MemoryMap, NestedMemoryMap},
+        MapRead,
+        NestedMapRead,
+    };
+
+    #[test]
+    fn test_check_index() {
+        // Initialize a source map, and its reverse index.
+        let source = MemoryMap::<u32, u32>::default();
+        let index = MemoryMap::<u32, u32>::default();
+        for (key, value) in [(0, 10), (1, 11), (2, 12)] {
+            source.insert(key, value).unwrap();
+        }
+        for (key, value) in [(10, 0), (11, 2), (13, 3)] {
+            index.insert(key, value).unwrap();
+        }
+        let expected = || source.iter_confirmed().map(|(k, v)| (*v, *k)).collect::<Vec<_>>();
+        let contains = |value: &u32, key: &u32| Ok(source.get_confirmed(key)?.is_some_and(|v| *v == *value));
+
+        // Ensure the incorrect, missing, and dangling entries are found.
+        let mut report = IntegrityReport::default();
+        report.check_index("Test", &index, expected(), contains, false).unwrap();
+        assert_eq!(report.issues().len(), 3);
+        assert!(!report.is_consistent());
+        assert_eq!(report.checked()["Test"], 3);
+        assert_eq!(index.get_confirmed(&13).unwrap().as_deref(), Some(&3));
+
+        // Repair the index.
+        let mut report = IntegrityReport::default();
+        report.check_index("Test", &index, expected(), contains, true).unwrap();
+        assert_eq!(report.issues().len(), 3);
+        assert!(report.is_consistent());
+
+        // Ensure the index is consistent.
+        let mut report = IntegrityReport::default();
+        report.check_index("Test", &index, expected(), contains, false).unwrap();
+        assert!(report.issues().is_empty());
+        assert_eq!(index.iter_confirmed().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), vec![(10, 0), (11, 1), (12, 2)]);
+    }
+
+    #[test]
+    fn test_check_nested_index() {
+        // Initialize a source map, and a nested index of its values by their parity.
+        let source = MemoryMap::<u32, u32>::default();
+        let index = NestedMemoryMap::<u32, u32, u32>::default();
+        for (key, value) in [(0, 10), (1, 11), (2, 12)] {
+            source.insert(key, value).unwrap();
+        }
+        for (m, key, value) in [(0, 10, 0), (1, 11, 2), (1, 13, 3)] {
+            index.insert(m, key, value).unwrap();
+        }
+        let expected = || source.iter_confirmed().map(|(k, v)| (*v % 2, *v, *k)).collect::<Vec<_>>();
+        let contains = |_: &u32, value: &u32, key: &u32| Ok(source.get_confirmed(key)?.is_some_and(|v| *v == *value));
+
+        // Ensure the incorrect, missing, and dangling entries are found.
+        let mut report = IntegrityReport::default();
+        report.check_nested_index("Test", &index, expected(), contains, false).unwrap();
+        assert_eq!(report.issues().len(), 3);
+        assert!(!report.is_consistent());
+        assert_eq!(report.checked()["Test"], 3);
+
+        // Repair the index.
+        let mut report = IntegrityReport::default();
+        report.check_nested_index("Test", &index, expected(), contains, true).unwrap();
+        assert_eq!(report.issues().len(), 3);
+        assert!(report.is_consistent());
+
+        // Ensure the index is consistent.
+        let mut report = IntegrityReport::default();
+        report.check_nested_index("Test", &index, expected(), contains, false).unwrap();
+        assert!(report.issues().is_empty());
+        assert_eq!(index.get_map_confirmed(&0).unwrap(), vec![(10, 0), (12, 2)]);
+        assert_eq!(index.get_map_confirmed(&1).unwrap(), vec![(11, 1)]);
+    }
+}
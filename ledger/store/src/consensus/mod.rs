@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod integrity;
+pub use integrity::*;
+
 use crate::{
+    atomic_batch_scope,
     BlockStorage,
     BlockStore,
     FinalizeStorage,
@@ -42,6 +46,23 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Initializes the consensus storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
+    /// Initializes the consensus storage with read-only access, alongside a primary instance that writes to it.
+    fn open_read_only<S: Clone + Into<StorageMode>>(_storage: S) -> Result<Self> {
+        bail!("Read-only access is not supported by this consensus storage")
+    }
+
+    /// Catches up with the writes of the primary instance, if the storage was opened with read-only access.
+    fn try_catch_up_with_primary(&self) -> Result<()> {
+        bail!("Read-only access is not supported by this consensus storage")
+    }
+
+    /// Records the metrics of the storage, such as the number of keys and the size of each map,
+    /// and the hit rate of the block cache.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Returns the finalize storage.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage>;
     /// Returns the block storage.
@@ -121,6 +142,14 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         Ok(Self { storage, _phantom: PhantomData })
     }
 
+    /// Initializes the consensus store with read-only access, alongside a primary instance that writes to it.
+    pub fn open_read_only<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the consensus storage.
+        let storage = C::open_read_only(storage)?;
+        // Return the consensus store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
     /// Initializes a consensus store from storage.
     pub fn from(storage: C) -> Self {
         Self { storage, _phantom: PhantomData }
@@ -185,4 +214,116 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Catches up with the writes of the primary instance, if the store was opened with read-only access.
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        // Catch up with the primary instance.
+        self.storage.try_catch_up_with_primary()?;
+        // Reload the block tree, as it is cached in memory.
+        self.block_store().reload_tree()
+    }
+
+    /// Records the metrics of the storage, such as the number of keys and the size of each map,
+    /// and the hit rate of the block cache.
+    /// Note: This may scan the entire storage, and is intended to be called periodically.
+    #[cfg(feature = "metrics")]
+    pub fn record_metrics(&self) -> Result<()> {
+        self.storage.record_metrics()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Checks the consistency of the storage, and returns a report of the inconsistencies found.
+    ///
+    /// The blocks, transactions, and transitions are checked to be complete, and each derived index
+    /// (e.g. the reverse maps, the block tree, and the finalize tree) is checked against its source maps.
+    /// The finalize checksum, rollbacks, history, and committees are checked against the latest block.
+    /// Note: No block header commits to the finalize checksum, so it is only checked against the local
+    /// key-value entries, and is reported in `IntegrityReport::local_only`.
+    /// If `repair` is `true`, the inconsistent indexes are rewritten from their source maps, and the dangling
+    /// entries are removed, in a single atomic batch.
+    /// Missing source data (e.g. a missing block header) cannot be repaired, and is only reported.
+    pub fn verify_integrity(&self, repair: bool) -> Result<IntegrityReport> {
+        let check = || {
+            let mut report = IntegrityReport::default();
+            self.block_store().check_integrity(&mut report, repair)?;
+            self.transaction_store().check_integrity(&mut report, repair)?;
+            self.transition_store().check_integrity(&mut report, repair)?;
+            let latest_height = self.block_store().current_block_height();
+            self.finalize_store().check_integrity(&mut report, latest_height, repair)?;
+            Ok(report)
+        };
+        match repair {
+            true => atomic_batch_scope!(self, { check() }),
+            false => check(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        helpers::{memory::ConsensusMemory, Map, MapRead, NestedMap, NestedMapRead},
+        CommitteeStorage,
+    };
+    use console::types::Field;
+    use ledger_block::{Block, Ratify, TransactionReceipt};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_verify_integrity() {
+        // Load the genesis block, and its committee.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let committee = block
+            .ratifications()
+            .iter()
+            .find_map(|ratify| match ratify {
+                Ratify::Genesis(committee, ..) => Some((**committee).clone()),
+                _ => None,
+            })
+            .unwrap();
+
+        // Initialize a new consensus store, and insert the genesis block and committee.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        store.block_store().insert(&block).unwrap();
+        store.finalize_store().committee_store().insert(block.height(), committee).unwrap();
+
+        // Ensure the storage is consistent.
+        let report = store.verify_integrity(false).unwrap();
+        assert!(report.issues().is_empty(), "{report}");
+        assert_eq!(report.checked()["Block(ID)"], 1);
+        assert_eq!(report.checked()["Committee(Committee)"], 1);
+        assert_eq!(report.local_only(), ["Program(Checksum)"]);
+
+        // Corrupt the storage, by removing an index entry, and inserting a dangling receipt, checksum, and round.
+        let transaction_id = *block.transaction_ids().next().unwrap();
+        let block_storage = store.block_store().storage();
+        block_storage.reverse_id_map().remove(&block.hash()).unwrap();
+        let receipt = TransactionReceipt::from_aborted(5, transaction_id, None).unwrap();
+        block_storage.receipts_map().insert(transaction_id, 5, receipt).unwrap();
+        store.finalize_store().storage().checksum_map().insert(1, Field::one()).unwrap();
+        let committee_storage = store.finalize_store().committee_store().storage();
+        committee_storage.round_to_height_map().insert(5, block.height()).unwrap();
+
+        // Ensure the inconsistencies are found, and are not repaired.
+        let report = store.verify_integrity(false).unwrap();
+        let maps: Vec<_> = report.issues().iter().map(|issue| issue.map()).collect();
+        assert_eq!(maps, ["Block(ReverseID)", "Block(Receipts)", "Program(Checksum)", "Committee(RoundToHeight)"]);
+        assert!(!report.is_consistent());
+        assert!(!block_storage.reverse_id_map().contains_key_confirmed(&block.hash()).unwrap());
+
+        // Repair the storage.
+        let report = store.verify_integrity(true).unwrap();
+        assert_eq!(report.issues().len(), 4);
+        assert!(report.is_consistent());
+
+        // Ensure the storage is consistent.
+        let report = store.verify_integrity(false).unwrap();
+        assert!(report.issues().is_empty(), "{report}");
+        assert_eq!(block_storage.reverse_id_map().get_confirmed(&block.hash()).unwrap().as_deref(), Some(&0));
+        assert!(block_storage.receipts_map().get_map_confirmed(&transaction_id).unwrap().is_empty());
+        assert!(!committee_storage.round_to_height_map().contains_key_confirmed(&5).unwrap());
+    }
 }
@@ -13,15 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransactionMemory, TransitionMemory},
+    BlockPruning,
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{account::Address, prelude::*, program::StatePath, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{Header, Ratifications, Rejected, Solutions, TransactionReceipt};
 use ledger_coinbase::PuzzleCommitment;
 use synthesizer_program::FinalizeOperation;
 
@@ -66,6 +67,18 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The receipts map.
+    receipts_map: NestedMemoryMap<N::TransactionID, u32, TransactionReceipt<N>>,
+    /// The scan checkpoint map.
+    scan_checkpoint_map: MemoryMap<Address<N>, u32>,
+    /// The block tree map.
+    tree_map: MemoryMap<u64, Field<N>>,
+    /// The block pruning map.
+    pruning_map: MemoryMap<u8, BlockPruning>,
+    /// The pruned state path map.
+    pruned_state_path_map: MemoryMap<Field<N>, StatePath<N>>,
+    /// The pruned rejected ID map.
+    pruned_rejected_id_map: MemoryMap<Field<N>, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -89,6 +102,12 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type ReceiptsMap = NestedMemoryMap<N::TransactionID, u32, TransactionReceipt<N>>;
+    type ScanCheckpointMap = MemoryMap<Address<N>, u32>;
+    type TreeMap = MemoryMap<u64, Field<N>>;
+    type PruningMap = MemoryMap<u8, BlockPruning>;
+    type PrunedStatePathMap = MemoryMap<Field<N>, StatePath<N>>;
+    type PrunedRejectedIDMap = MemoryMap<Field<N>, u32>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -117,6 +136,12 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            receipts_map: NestedMemoryMap::default(),
+            scan_checkpoint_map: MemoryMap::default(),
+            tree_map: MemoryMap::default(),
+            pruning_map: MemoryMap::default(),
+            pruned_state_path_map: MemoryMap::default(),
+            pruned_rejected_id_map: MemoryMap::default(),
             transaction_store,
         })
     }
@@ -206,6 +231,36 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap {
+        &self.receipts_map
+    }
+
+    /// Returns the scan checkpoint map.
+    fn scan_checkpoint_map(&self) -> &Self::ScanCheckpointMap {
+        &self.scan_checkpoint_map
+    }
+
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
+    /// Returns the block pruning map.
+    fn pruning_map(&self) -> &Self::PruningMap {
+        &self.pruning_map
+    }
+
+    /// Returns the pruned state path map.
+    fn pruned_state_path_map(&self) -> &Self::PrunedStatePathMap {
+        &self.pruned_state_path_map
+    }
+
+    /// Returns the pruned rejected ID map.
+    fn pruned_rejected_id_map(&self) -> &Self::PrunedRejectedIDMap {
+        &self.pruned_rejected_id_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
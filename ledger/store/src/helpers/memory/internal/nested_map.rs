@@ -344,6 +344,39 @@ impl<
         Ok(key_values)
     }
 
+    ///
+    /// Returns at most `limit` confirmed key-value pairs for the given map, starting from the given key.
+    ///
+    fn get_map_range_confirmed(&'a self, map: &M, start_key: &K, limit: usize) -> Result<Vec<(K, V)>> {
+        // Serialize 'm' and the start key.
+        let m = bincode::serialize(map)?;
+        let start_key = bincode::serialize(start_key)?;
+
+        // Acquire the read locks on 'map' and 'map_inner'.
+        let map_outer = self.map.read();
+        let map_inner = self.map_inner.read();
+        // Retrieve the keys for the serialized map.
+        let Some(keys) = map_outer.get(&m) else {
+            return Ok(Default::default());
+        };
+
+        // Return the key-value pairs from the start key.
+        keys.range(start_key..)
+            .take(limit)
+            .map(|k| {
+                // Deserialize 'k'.
+                let key: K = bincode::deserialize(k)?;
+                // Concatenate 'm' and 'k' with a 0-byte separator.
+                let mk = to_map_key(&m, k);
+                // Return the key-value pair.
+                match map_inner.get(&mk) {
+                    Some(value) => Ok((key, value.clone())),
+                    None => bail!("Missing the value of a nested map key"),
+                }
+            })
+            .collect()
+    }
+
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
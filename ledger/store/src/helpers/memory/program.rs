@@ -18,11 +18,16 @@ use crate::{
     helpers::memory::{MemoryMap, NestedMemoryMap},
     CommitteeStorage,
     CommitteeStore,
+    FinalizeHistory,
+    FinalizeRollback,
     FinalizeStorage,
+    FinalizeTreeNode,
+    FinalizeTreeNodeID,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -38,6 +43,16 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The finalize tree map.
+    tree_map: MemoryMap<FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>,
+    /// The history map.
+    history_map: NestedMemoryMap<Field<N>, u32, Option<Value<N>>>,
+    /// The history config map.
+    history_config_map: MemoryMap<u8, FinalizeHistory>,
+    /// The rollback map.
+    rollback_map: MemoryMap<u32, FinalizeRollback<N>>,
+    /// The checksum map.
+    checksum_map: MemoryMap<u32, Field<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +62,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type TreeMap = MemoryMap<FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>;
+    type HistoryMap = NestedMemoryMap<Field<N>, u32, Option<Value<N>>>;
+    type HistoryConfigMap = MemoryMap<u8, FinalizeHistory>;
+    type RollbackMap = MemoryMap<u32, FinalizeRollback<N>>;
+    type ChecksumMap = MemoryMap<u32, Field<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +77,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            tree_map: MemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            history_config_map: MemoryMap::default(),
+            rollback_map: MemoryMap::default(),
+            checksum_map: MemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -82,6 +107,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the finalize tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history config map.
+    fn history_config_map(&self) -> &Self::HistoryConfigMap {
+        &self.history_config_map
+    }
+
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the checksum map.
+    fn checksum_map(&self) -> &Self::ChecksumMap {
+        &self.checksum_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -12,7 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionPosition,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
@@ -23,6 +31,7 @@ use aleo_std_storage::StorageMode;
 
 /// An in-memory transition storage.
 #[derive(Clone)]
+#[allow(clippy::type_complexity)]
 pub struct TransitionMemory<N: Network> {
     /// The transition program IDs and function names.
     locator_map: MemoryMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>,
@@ -40,6 +49,8 @@ pub struct TransitionMemory<N: Network> {
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: MemoryMap<N::TransitionID, Field<N>>,
+    /// The function index map.
+    function_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>,
 }
 
 #[rustfmt::skip]
@@ -52,6 +63,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
     type SCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type FunctionIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -64,6 +76,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
             scm_map: MemoryMap::default(),
+            function_index_map: NestedMemoryMap::default(),
         })
     }
 
@@ -106,6 +119,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
 }
 
 /// An in-memory transition input storage.
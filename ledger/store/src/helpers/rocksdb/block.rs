@@ -14,20 +14,21 @@
 
 use crate::{
     helpers::rocksdb::{
-        internal::{self, DataMap, Database},
+        internal::{self, DataMap, Database, NestedDataMap},
         BlockMap,
         MapID,
         TransactionDB,
         TransitionDB,
     },
+    BlockPruning,
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{account::Address, prelude::*, program::StatePath, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{Header, Ratifications, Rejected, Solutions, TransactionReceipt};
 use ledger_coinbase::PuzzleCommitment;
 use synthesizer_program::FinalizeOperation;
 
@@ -72,6 +73,18 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The receipts map.
+    receipts_map: NestedDataMap<N::TransactionID, u32, TransactionReceipt<N>>,
+    /// The scan checkpoint map.
+    scan_checkpoint_map: DataMap<Address<N>, u32>,
+    /// The block tree map.
+    tree_map: DataMap<u64, Field<N>>,
+    /// The block pruning map.
+    pruning_map: DataMap<u8, BlockPruning>,
+    /// The pruned state path map.
+    pruned_state_path_map: DataMap<Field<N>, StatePath<N>>,
+    /// The pruned rejected ID map.
+    pruned_rejected_id_map: DataMap<Field<N>, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -95,6 +108,12 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type ReceiptsMap = NestedDataMap<N::TransactionID, u32, TransactionReceipt<N>>;
+    type ScanCheckpointMap = DataMap<Address<N>, u32>;
+    type TreeMap = DataMap<u64, Field<N>>;
+    type PruningMap = DataMap<u8, BlockPruning>;
+    type PrunedStatePathMap = DataMap<Field<N>, StatePath<N>>;
+    type PrunedRejectedIDMap = DataMap<Field<N>, u32>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -122,7 +141,13 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            receipts_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Block(BlockMap::Receipts))?,
+            scan_checkpoint_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ScanCheckpoint))?,
+            tree_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Tree))?,
+            pruning_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Pruning))?,
+            pruned_state_path_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PrunedStatePath))?,
+            pruned_rejected_id_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::PrunedRejectedID))?,
             transaction_store,
         })
     }
@@ -212,8 +237,43 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap {
+        &self.receipts_map
+    }
+
+    /// Returns the scan checkpoint map.
+    fn scan_checkpoint_map(&self) -> &Self::ScanCheckpointMap {
+        &self.scan_checkpoint_map
+    }
+
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
+    /// Returns the block pruning map.
+    fn pruning_map(&self) -> &Self::PruningMap {
+        &self.pruning_map
+    }
+
+    /// Returns the pruned state path map.
+    fn pruned_state_path_map(&self) -> &Self::PrunedStatePathMap {
+        &self.pruned_state_path_map
+    }
+
+    /// Returns the pruned rejected ID map.
+    fn pruned_rejected_id_map(&self) -> &Self::PrunedRejectedIDMap {
+        &self.pruned_rejected_id_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Returns `true` if the storage is read-only.
+    fn is_read_only(&self) -> bool {
+        self.tree_map.access_mode() != internal::AccessMode::ReadWrite
+    }
 }
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::rocksdb::{
+        internal::{self, Database},
+        BlockDB,
+        FinalizeDB,
+        TransactionDB,
+        TransitionDB,
+    },
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -40,6 +46,8 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
 
     /// Initializes the consensus storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Ensure the database is at the current schema version, before any map is read.
+        internal::RocksDB::open(N::ID, storage.clone())?.ensure_schema_version()?;
         // Initialize the finalize store.
         let finalize_store = FinalizeStore::<N, FinalizeDB<N>>::open(storage.clone())?;
         // Initialize the block store.
@@ -51,6 +59,25 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
         })
     }
 
+    /// Initializes the consensus storage as a secondary instance of the database, with read-only access.
+    fn open_read_only<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Open the database as a secondary instance, before any map is opened.
+        internal::RocksDB::open_with_access_mode(N::ID, storage.clone(), internal::AccessMode::Secondary)?;
+        // Initialize the consensus storage.
+        Self::open(storage)
+    }
+
+    /// Catches up with the writes of the primary instance.
+    fn try_catch_up_with_primary(&self) -> Result<()> {
+        internal::RocksDB::open(N::ID, self.storage_mode().clone())?.try_catch_up_with_primary()
+    }
+
+    /// Records the number of keys and the size of each map, and the hit rate of the block cache.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self) -> Result<()> {
+        internal::RocksDB::open(N::ID, self.storage_mode().clone())?.record_map_metrics()
+    }
+
     /// Returns the finalize store.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
         &self.finalize_store
@@ -28,6 +28,7 @@ pub enum MapID {
     TransitionInput(TransitionInputMap),
     TransitionOutput(TransitionOutputMap),
     Program(ProgramMap),
+    Schema(SchemaMap),
     #[cfg(test)]
     Test(TestMap),
 }
@@ -46,6 +47,7 @@ impl From<MapID> for u16 {
             MapID::TransitionInput(id) => id as u16,
             MapID::TransitionOutput(id) => id as u16,
             MapID::Program(id) => id as u16,
+            MapID::Schema(id) => id as u16,
             #[cfg(test)]
             MapID::Test(id) => id as u16,
         }
@@ -84,6 +86,12 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Receipts = DataID::BlockReceiptsMap as u16,
+    ScanCheckpoint = DataID::BlockScanCheckpointMap as u16,
+    Tree = DataID::BlockTreeMap as u16,
+    Pruning = DataID::BlockPruningMap as u16,
+    PrunedStatePath = DataID::BlockPrunedStatePathMap as u16,
+    PrunedRejectedID = DataID::BlockPrunedRejectedIDMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -187,6 +195,7 @@ pub enum TransitionMap {
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
     SCM = DataID::TransitionSCMMap as u16,
+    FunctionIndex = DataID::TransitionFunctionIndexMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -197,6 +206,20 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    Tree = DataID::KeyValueTreeMap as u16,
+    History = DataID::KeyValueHistoryMap as u16,
+    HistoryConfig = DataID::KeyValueHistoryConfigMap as u16,
+    Rollback = DataID::KeyValueRollbackMap as u16,
+    Checksum = DataID::KeyValueChecksumMap as u16,
+}
+
+/// The RocksDB map prefix for schema-related entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum SchemaMap {
+    Version = DataID::SchemaVersionMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -214,7 +237,7 @@ pub enum TestMap {
 
 /// The RocksDB map prefix.
 // Note: the order of these variants can NOT be changed once the database is populated:
-// - any new variant MUST be added as the last one (ignoring the Test one)
+// - any new variant MUST be added as the last one (ignoring the Test one), and become the `DataID::LAST` one
 // - any deprecated variant MUST remain in its position (it can't be removed)
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -290,6 +313,19 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    KeyValueTreeMap,
+    KeyValueHistoryMap,
+    KeyValueHistoryConfigMap,
+    KeyValueRollbackMap,
+    BlockReceiptsMap,
+    TransitionFunctionIndexMap,
+    BlockTreeMap,
+    BlockPruningMap,
+    SchemaVersionMap,
+    BlockPrunedStatePathMap,
+    BlockPrunedRejectedIDMap,
+    KeyValueChecksumMap,
+    BlockScanCheckpointMap,
 
     // Testing
     #[cfg(test)]
@@ -303,3 +339,136 @@ enum DataID {
     #[cfg(test)]
     Test5,
 }
+
+impl DataID {
+    /// The last map, whose column family is the last one to be opened.
+    #[cfg(not(test))]
+    const LAST: DataID = DataID::BlockScanCheckpointMap;
+    /// The last map, whose column family is the last one to be opened.
+    #[cfg(test)]
+    const LAST: DataID = DataID::Test5;
+
+    /// The maps of the ledger, in the order of their map IDs.
+    #[cfg(feature = "metrics")]
+    const ALL: [DataID; 72] = [
+        DataID::BFTTransmissionsMap,
+        DataID::BlockStateRootMap,
+        DataID::BlockReverseStateRootMap,
+        DataID::BlockIDMap,
+        DataID::BlockReverseIDMap,
+        DataID::BlockHeaderMap,
+        DataID::BlockAuthorityMap,
+        DataID::BlockCertificateMap,
+        DataID::BlockRatificationsMap,
+        DataID::BlockSolutionsMap,
+        DataID::BlockPuzzleCommitmentsMap,
+        DataID::BlockAbortedSolutionIDsMap,
+        DataID::BlockAbortedSolutionHeightsMap,
+        DataID::BlockTransactionsMap,
+        DataID::BlockAbortedTransactionIDsMap,
+        DataID::BlockRejectedOrAbortedTransactionIDMap,
+        DataID::BlockConfirmedTransactionsMap,
+        DataID::BlockRejectedDeploymentOrExecutionMap,
+        DataID::CurrentRoundMap,
+        DataID::RoundToHeightMap,
+        DataID::CommitteeMap,
+        DataID::DeploymentIDMap,
+        DataID::DeploymentEditionMap,
+        DataID::DeploymentReverseIDMap,
+        DataID::DeploymentOwnerMap,
+        DataID::DeploymentProgramMap,
+        DataID::DeploymentVerifyingKeyMap,
+        DataID::DeploymentCertificateMap,
+        DataID::ExecutionIDMap,
+        DataID::ExecutionReverseIDMap,
+        DataID::ExecutionInclusionMap,
+        DataID::FeeFeeMap,
+        DataID::FeeReverseFeeMap,
+        DataID::InputIDMap,
+        DataID::InputReverseIDMap,
+        DataID::InputConstantMap,
+        DataID::InputPublicMap,
+        DataID::InputPrivateMap,
+        DataID::InputRecordMap,
+        DataID::InputRecordTagMap,
+        DataID::InputExternalRecordMap,
+        DataID::OutputIDMap,
+        DataID::OutputReverseIDMap,
+        DataID::OutputConstantMap,
+        DataID::OutputPublicMap,
+        DataID::OutputPrivateMap,
+        DataID::OutputRecordMap,
+        DataID::OutputRecordNonceMap,
+        DataID::OutputExternalRecordMap,
+        DataID::OutputFutureMap,
+        DataID::TransactionIDMap,
+        DataID::TransitionLocatorMap,
+        DataID::TransitionTPKMap,
+        DataID::TransitionReverseTPKMap,
+        DataID::TransitionTCMMap,
+        DataID::TransitionReverseTCMMap,
+        DataID::TransitionSCMMap,
+        DataID::ProgramIDMap,
+        DataID::KeyValueMap,
+        DataID::KeyValueTreeMap,
+        DataID::KeyValueHistoryMap,
+        DataID::KeyValueHistoryConfigMap,
+        DataID::KeyValueRollbackMap,
+        DataID::BlockReceiptsMap,
+        DataID::TransitionFunctionIndexMap,
+        DataID::BlockTreeMap,
+        DataID::BlockPruningMap,
+        DataID::SchemaVersionMap,
+        DataID::BlockPrunedStatePathMap,
+        DataID::BlockPrunedRejectedIDMap,
+        DataID::KeyValueChecksumMap,
+        DataID::BlockScanCheckpointMap,
+    ];
+
+    /// The maps of large and mostly incompressible values, such as proofs, certificates and ciphertexts.
+    const BULK: [DataID; 8] = [
+        DataID::BFTTransmissionsMap,
+        DataID::BlockCertificateMap,
+        DataID::BlockSolutionsMap,
+        DataID::DeploymentVerifyingKeyMap,
+        DataID::DeploymentCertificateMap,
+        DataID::ExecutionInclusionMap,
+        DataID::FeeFeeMap,
+        DataID::OutputRecordMap,
+    ];
+}
+
+/// The number of maps, each of which is stored in its own column family.
+pub(super) const NUM_MAPS: u16 = DataID::LAST as u16 + 1;
+
+/// The names of the column families of the maps, indexed by their map ID.
+static COLUMN_FAMILY_NAMES: once_cell::sync::Lazy<Vec<String>> =
+    once_cell::sync::Lazy::new(|| (0..NUM_MAPS).map(|map_id| format!("map-{map_id}")).collect());
+
+/// Returns the name of the column family of the map with the given map ID.
+pub(super) fn column_family_name(map_id: u16) -> &'static str {
+    &COLUMN_FAMILY_NAMES[usize::from(map_id)]
+}
+
+/// The labels of the maps, indexed by their map ID, which are used to report their metrics.
+#[cfg(feature = "metrics")]
+static MAP_LABELS: once_cell::sync::Lazy<Vec<String>> = once_cell::sync::Lazy::new(|| {
+    (0..NUM_MAPS)
+        .map(|map_id| match DataID::ALL.iter().find(|data_id| **data_id as u16 == map_id) {
+            Some(data_id) => format!("{data_id:?}"),
+            // Note: The test maps are labelled with the name of their column family.
+            None => column_family_name(map_id).to_string(),
+        })
+        .collect()
+});
+
+/// Returns the label of the map with the given map ID, which is used to report its metrics.
+#[cfg(feature = "metrics")]
+pub(super) fn map_label(map_id: u16) -> &'static str {
+    &MAP_LABELS[usize::from(map_id)]
+}
+
+/// Returns `true` if the map with the given map ID holds large and mostly incompressible values.
+pub(super) fn is_bulk_map(map_id: u16) -> bool {
+    DataID::BULK.iter().any(|data_id| *data_id as u16 == map_id)
+}
@@ -46,6 +46,13 @@ pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + Deserial
     pub(super) checkpoints: Mutex<Vec<usize>>,
 }
 
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+    /// Returns the access mode of the database.
+    pub const fn access_mode(&self) -> AccessMode {
+        self.0.database.access_mode()
+    }
+}
+
 impl<
     'a,
     K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
@@ -67,7 +74,9 @@ impl<
                 // Prepare the prefixed key and serialized value.
                 let raw_key = self.create_prefixed_key(&key)?;
                 let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                #[cfg(feature = "metrics")]
+                stats::record_write(raw_key.len() + raw_value.len());
+                self.database.put_cf(self.column_family(), raw_key, raw_value)?;
             }
         }
 
@@ -88,7 +97,9 @@ impl<
             false => {
                 // Prepare the prefixed key.
                 let raw_key = self.create_prefixed_key(key)?;
-                self.database.delete(raw_key)?;
+                #[cfg(feature = "metrics")]
+                stats::record_write(raw_key.len());
+                self.database.delete_cf(self.column_family(), raw_key)?;
             }
         }
 
@@ -197,12 +208,15 @@ impl<
                 })
                 .collect::<Result<Vec<_>>>()?;
 
+            #[cfg(feature = "metrics")]
+            stats::record_map_batch(&self.context, prepared_operations.len());
+
             // Enqueue all the operations from the map in the database-wide batch.
             let mut atomic_batch = self.database.atomic_batch.lock();
             for (raw_key, raw_value) in prepared_operations {
                 match raw_value {
-                    Some(raw_value) => atomic_batch.put(raw_key, raw_value),
-                    None => atomic_batch.delete(raw_key),
+                    Some(raw_value) => atomic_batch.put_cf(self.column_family(), raw_key, raw_value),
+                    None => atomic_batch.delete_cf(self.column_family(), raw_key),
                 };
             }
         }
@@ -226,7 +240,7 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write_atomic_batch(batch)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -269,7 +283,7 @@ impl<
     ///
     fn len_confirmed(&self) -> usize {
         // A raw iterator doesn't allocate.
-        let mut iter = self.database.raw_iterator();
+        let mut iter = self.database.raw_iterator_cf(self.column_family());
         // Find the first key with the map prefix.
         iter.seek(&self.context);
 
@@ -375,21 +389,21 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        Iter::new(self.database.prefix_iterator(&self.context))
+        Iter::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 
     ///
     /// Returns an iterator over each key in the map.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        Keys::new(self.database.prefix_iterator(&self.context))
+        Keys::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 
     ///
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        Values::new(self.database.prefix_iterator(&self.context))
+        Values::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 }
 
@@ -518,6 +532,12 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
 }
 
 impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+    /// Returns the column family of the map.
+    #[inline]
+    fn column_family(&self) -> &rocksdb::ColumnFamily {
+        self.database.column_family(map_id(&self.context))
+    }
+
     #[inline]
     fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
     where
@@ -535,7 +555,7 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned(&raw_key)? {
+        match self.database.get_pinned_cf(self.column_family(), &raw_key)? {
             Some(data) => Ok(Some(data)),
             None => Ok(None),
         }
@@ -0,0 +1,1119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{nested_map::get_map_and_key, *};
+use crate::{
+    helpers::{memory::MemoryMap, MapRead},
+    program::{to_leaves, FinalizeTree, FinalizeTreeNode, FinalizeTreeNodeID},
+    TransactionType,
+    TransitionPosition,
+};
+use console::{
+    network::{MainnetV0, Network},
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use synthesizer_snark::Proof;
+
+use std::collections::HashMap;
+use tracing::info;
+
+/// The current schema version of the database.
+///
+/// Note: The schema version MUST be incremented, and a migration MUST be appended to `MIGRATIONS`,
+/// whenever the on-disk layout changes (e.g. a map is re-keyed, or the serialization of a value changes).
+pub const SCHEMA_VERSION: u32 = 4;
+
+/// The migrations of the database, where the migration at index `i` upgrades schema version `i` to `i + 1`.
+/// Schema version 0 is the layout of the databases that were created before the schema version was recorded.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { description: "Record the schema version", migrate: |_| Ok(()) },
+    Migration {
+        description: "Move each map into its own column family",
+        migrate: |context| context.move_into_column_families(),
+    },
+    Migration { description: "Build the finalize tree", migrate: |context| context.build_finalize_tree() },
+    Migration { description: "Build the function index", migrate: |context| context.build_function_index() },
+];
+
+// Ensure there is a migration to each schema version.
+const _: () = assert!(MIGRATIONS.len() == SCHEMA_VERSION as usize);
+
+/// A migration of the database from a schema version to the next one.
+#[derive(Copy, Clone)]
+pub struct Migration {
+    /// The description of the migration.
+    pub description: &'static str,
+    /// The function that stages the changes of the migration in the given context.
+    pub migrate: fn(&mut MigrationContext) -> Result<()>,
+}
+
+/// The progress of a migration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationProgress {
+    /// The schema version that the migration upgrades from.
+    from_version: u32,
+    /// The description of the migration.
+    description: &'static str,
+    /// The number of entries processed so far.
+    num_processed: usize,
+    /// Whether the migration is finished.
+    is_finished: bool,
+}
+
+impl MigrationProgress {
+    /// Returns the schema version that the migration upgrades from.
+    pub const fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// Returns the description of the migration.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the number of entries processed so far.
+    pub const fn num_processed(&self) -> usize {
+        self.num_processed
+    }
+
+    /// Returns `true` if the migration is finished.
+    pub const fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+}
+
+/// The report of a database migration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// The schema version before the migration.
+    from_version: u32,
+    /// The schema version after the migration, or the one it would reach in a dry run.
+    to_version: u32,
+    /// The description and the number of changed entries of each migration.
+    migrations: Vec<(&'static str, usize)>,
+    /// Whether the migration was a dry run.
+    is_dry_run: bool,
+}
+
+impl MigrationReport {
+    /// Returns the schema version before the migration.
+    pub const fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// Returns the schema version after the migration, or the one it would reach in a dry run.
+    pub const fn to_version(&self) -> u32 {
+        self.to_version
+    }
+
+    /// Returns the description and the number of changed (i.e. written or removed) entries of each migration.
+    pub fn migrations(&self) -> &[(&'static str, usize)] {
+        &self.migrations
+    }
+
+    /// Returns `true` if the migration was a dry run, in which case nothing was written.
+    pub const fn is_dry_run(&self) -> bool {
+        self.is_dry_run
+    }
+}
+
+/// The context of a migration, which reads the database as it was before the migration,
+/// and stages the changes of the migration in a batch that is written atomically,
+/// or in chunks of bounded size for the migrations that can be resumed from any chunk.
+pub struct MigrationContext<'a> {
+    /// The database.
+    database: &'a RocksDB,
+    /// The staged changes.
+    batch: rocksdb::WriteBatch,
+    /// The number of staged changes.
+    num_changes: usize,
+    /// Whether the migration is a dry run, in which case the staged changes are never written.
+    is_dry_run: bool,
+    /// The progress of the migration.
+    progress: MigrationProgress,
+    /// The callback that is given the progress of the migration.
+    on_progress: &'a mut dyn FnMut(&MigrationProgress),
+}
+
+impl<'a> MigrationContext<'a> {
+    /// The number of processed entries between two progress reports.
+    const PROGRESS_INTERVAL: usize = 10_000;
+    /// The maximum number of staged changes in a chunk of a resumable migration.
+    const MAX_CHUNK_CHANGES: usize = 20_000;
+    /// The maximum size in bytes of the staged changes in a chunk of a resumable migration.
+    const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+    /// Initializes a new migration context.
+    fn new(
+        database: &'a RocksDB,
+        from_version: u32,
+        description: &'static str,
+        is_dry_run: bool,
+        on_progress: &'a mut dyn FnMut(&MigrationProgress),
+    ) -> Self {
+        let progress = MigrationProgress { from_version, description, num_processed: 0, is_finished: false };
+        Self { database, batch: Default::default(), num_changes: 0, is_dry_run, progress, on_progress }
+    }
+
+    /// Returns the value of the given key in the given map.
+    pub fn get<K: Serialize, V: DeserializeOwned>(&self, map_id: MapID, key: &K) -> Result<Option<V>> {
+        match self.database.get_pinned_cf(self.column_family(map_id), self.raw_key(map_id, key)?)? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the entries of the given map.
+    pub fn entries<K: DeserializeOwned, V: DeserializeOwned>(
+        &self,
+        map_id: MapID,
+    ) -> impl '_ + Iterator<Item = Result<(K, V)>> {
+        let prefix = self.prefix(map_id);
+        self.database
+            .prefix_iterator_cf(self.column_family(map_id), prefix.clone())
+            .take_while(move |entry| entry.as_ref().map_or(true, |(key, _)| key.starts_with(&prefix)))
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((bincode::deserialize(&key[PREFIX_LEN..])?, bincode::deserialize(&value)?))
+            })
+    }
+
+    /// Returns the entries of the given nested map, as (`map`, `key`, `value`).
+    pub fn nested_entries<M: DeserializeOwned, K: DeserializeOwned, V: DeserializeOwned>(
+        &self,
+        map_id: MapID,
+    ) -> impl '_ + Iterator<Item = Result<(M, K, V)>> {
+        let prefix = self.prefix(map_id);
+        self.database
+            .prefix_iterator_cf(self.column_family(map_id), prefix.clone())
+            .take_while(move |entry| entry.as_ref().map_or(true, |(key, _)| key.starts_with(&prefix)))
+            .map(|entry| {
+                let (raw_key, value) = entry?;
+                let (map, key) = get_map_and_key(&raw_key)?;
+                Ok((bincode::deserialize(map)?, bincode::deserialize(key)?, bincode::deserialize(&value)?))
+            })
+    }
+
+    /// Stages the insertion of the given key-value pair into the given map.
+    pub fn insert<K: Serialize, V: Serialize>(&mut self, map_id: MapID, key: &K, value: &V) -> Result<()> {
+        let raw_key = self.raw_key(map_id, key)?;
+        self.batch.put_cf(self.column_family(map_id), raw_key, bincode::serialize(value)?);
+        self.num_changes += 1;
+        Ok(())
+    }
+
+    /// Stages the insertion of the given map-key-value triple into the given nested map.
+    pub fn insert_nested<M: Serialize, K: Serialize, V: Serialize>(
+        &mut self,
+        map_id: MapID,
+        map: &M,
+        key: &K,
+        value: &V,
+    ) -> Result<()> {
+        let raw_key = self.raw_nested_key(map_id, map, key)?;
+        self.batch.put_cf(self.column_family(map_id), raw_key, bincode::serialize(value)?);
+        self.num_changes += 1;
+        Ok(())
+    }
+
+    /// Stages the removal of the given key from the given map.
+    pub fn remove<K: Serialize>(&mut self, map_id: MapID, key: &K) -> Result<()> {
+        let raw_key = self.raw_key(map_id, key)?;
+        self.batch.delete_cf(self.column_family(map_id), raw_key);
+        self.num_changes += 1;
+        Ok(())
+    }
+
+    /// Rewrites each entry of the `source` map into the `target` map (which may be the same map),
+    /// using the given function, which returns the new key-value pair, or `None` to drop the entry.
+    /// The entries of the `source` map that are not rewritten in place are removed.
+    ///
+    /// Note: When re-keying a map in place, an entry must not be rewritten onto the key of another entry.
+    pub fn rewrite<K1, V1, K2, V2>(
+        &mut self,
+        source: MapID,
+        target: MapID,
+        mut rewrite: impl FnMut(K1, V1) -> Result<Option<(K2, V2)>>,
+    ) -> Result<()>
+    where
+        K1: DeserializeOwned,
+        V1: DeserializeOwned,
+        K2: Serialize,
+        V2: Serialize,
+    {
+        // Note: The database is read through its own reference, so the changes can be staged while iterating.
+        let database = self.database;
+        let prefix = self.prefix(source);
+        let (source_cf, target_cf) = (self.column_family(source), self.column_family(target));
+        for entry in database.prefix_iterator_cf(source_cf, &prefix) {
+            let (raw_key, raw_value) = entry?;
+            // Stop at the end of the source map.
+            if !raw_key.starts_with(&prefix) {
+                break;
+            }
+
+            // Rewrite the entry.
+            let key = bincode::deserialize(&raw_key[PREFIX_LEN..])?;
+            let value = bincode::deserialize(&raw_value)?;
+            match rewrite(key, value)? {
+                Some((key, value)) => {
+                    let new_raw_key = self.raw_key(target, &key)?;
+                    let new_raw_value = bincode::serialize(&value)?;
+                    // Skip the entries that are unchanged.
+                    if new_raw_key[..] != raw_key[..] || new_raw_value[..] != raw_value[..] {
+                        // Remove the entry, unless it is overwritten in place.
+                        if new_raw_key[..] != raw_key[..] {
+                            self.batch.delete_cf(source_cf, &raw_key);
+                            self.num_changes += 1;
+                        }
+                        self.batch.put_cf(target_cf, new_raw_key, new_raw_value);
+                        self.num_changes += 1;
+                    }
+                }
+                None => {
+                    self.batch.delete_cf(source_cf, &raw_key);
+                    self.num_changes += 1;
+                }
+            }
+
+            // Report the progress.
+            self.record_processed();
+        }
+        Ok(())
+    }
+
+    /// Records that an entry was processed, and periodically reports the progress.
+    pub fn record_processed(&mut self) {
+        self.progress.num_processed += 1;
+        if self.progress.num_processed % Self::PROGRESS_INTERVAL == 0 {
+            (self.on_progress)(&self.progress);
+        }
+    }
+
+    /// Writes the staged changes as a chunk, once they reach the maximum number or size of a chunk.
+    ///
+    /// Note: This is only meant for the migrations that can be resumed from any chunk, i.e. which stage
+    /// exactly the remaining changes when they are run again after being interrupted.
+    fn write_full_chunk(&mut self) -> Result<()> {
+        if self.batch.len() >= Self::MAX_CHUNK_CHANGES || self.batch.size_in_bytes() >= Self::MAX_CHUNK_SIZE {
+            let batch = std::mem::take(&mut self.batch);
+            // Note: In a dry run, the staged changes are only counted.
+            if !self.is_dry_run {
+                self.database.write(batch)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the entries of the maps from the default column family, where all the maps were stored
+    /// up to schema version 1, into the column family of each map.
+    ///
+    /// The entries are moved in chunks of bounded size, each of which is written atomically. The entries that
+    /// remain in the default column family after an interruption are moved when the migration is run again.
+    fn move_into_column_families(&mut self) -> Result<()> {
+        // Note: The database is read through its own reference, so the changes can be staged while iterating.
+        let database = self.database;
+        let mut iter = database.raw_iterator();
+        iter.seek_to_first();
+        while let (Some(raw_key), Some(raw_value)) = (iter.key(), iter.value()) {
+            // Ensure the entry belongs to a map.
+            ensure!(raw_key.len() >= PREFIX_LEN, "Found an entry without a map prefix in the database");
+            let map_id = map_id(raw_key);
+            ensure!(map_id < NUM_MAPS, "Found an entry of an unknown map ({map_id}) in the database");
+
+            // Move the entry.
+            self.batch.put_cf(database.column_family(map_id), raw_key, raw_value);
+            self.batch.delete(raw_key);
+            self.num_changes += 2;
+            self.write_full_chunk()?;
+
+            // Report the progress.
+            self.record_processed();
+            iter.next();
+        }
+        iter.status()?;
+        Ok(())
+    }
+
+    /// Rebuilds the finalize tree from the key-value entries of the finalize storage.
+    ///
+    /// The finalize tree was added after the key-value entries, so the databases that were populated before
+    /// have no tree, or a tree that does not cover the entries finalized before it.
+    fn build_finalize_tree(&mut self) -> Result<()> {
+        // Note: A database without key-value entries has no finalize tree to build, whatever its network.
+        if self.is_empty(MapID::Program(ProgramMap::KeyValueID))? && self.is_empty(MapID::Program(ProgramMap::Tree))? {
+            return Ok(());
+        }
+        match self.database.network_id {
+            MainnetV0::ID => self.build_finalize_tree_for::<MainnetV0>(),
+            network_id => bail!("Cannot build the finalize tree of an unknown network ({network_id})"),
+        }
+    }
+
+    /// Rebuilds the finalize tree of the given network from the key-value entries of the finalize storage.
+    fn build_finalize_tree_for<N: Network>(&mut self) -> Result<()> {
+        // Retrieve the key-value entries.
+        let entries = self
+            .nested_entries::<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>(MapID::Program(
+                ProgramMap::KeyValueID,
+            ))
+            .collect::<Result<Vec<_>>>()?;
+        for _ in &entries {
+            self.record_processed();
+        }
+
+        // Build the finalize tree in memory.
+        let tree_map = MemoryMap::<FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>::default();
+        let mut tree = FinalizeTree::new(&tree_map);
+        tree.update(to_leaves(entries)?)?;
+        tree.commit()?;
+
+        // Replace the stored finalize tree.
+        let map_id = MapID::Program(ProgramMap::Tree);
+        let nodes = tree_map
+            .iter_confirmed()
+            .map(|(node_id, node)| Ok((self.raw_key(map_id, &*node_id)?, bincode::serialize(&*node)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+        self.replace(map_id, nodes)
+    }
+
+    /// Rebuilds the function index from the transitions of the stored blocks.
+    ///
+    /// The function index was added after the blocks, so the databases that were populated before
+    /// have no index entries for the transitions of their earlier blocks.
+    fn build_function_index(&mut self) -> Result<()> {
+        // Note: A database without blocks has no function index to build, whatever its network.
+        if self.is_empty(MapID::Block(BlockMap::ID))?
+            && self.is_empty(MapID::Transition(TransitionMap::FunctionIndex))?
+        {
+            return Ok(());
+        }
+        match self.database.network_id {
+            MainnetV0::ID => self.build_function_index_for::<MainnetV0>(),
+            network_id => bail!("Cannot build the function index of an unknown network ({network_id})"),
+        }
+    }
+
+    /// Rebuilds the function index of the given network from the transitions of the stored blocks.
+    ///
+    /// Note: The transition IDs and locators are kept when a block is pruned, so pruned blocks are indexed as well.
+    fn build_function_index_for<N: Network>(&mut self) -> Result<()> {
+        // Note: The database is read through its own reference, so the progress can be recorded while iterating.
+        let database = self.database;
+        let prefix = self.prefix(MapID::Block(BlockMap::ID));
+        let map_id = MapID::Transition(TransitionMap::FunctionIndex);
+        let mut entries = HashMap::new();
+        for entry in database.prefix_iterator_cf(self.column_family(MapID::Block(BlockMap::ID)), &prefix) {
+            let (raw_key, raw_value) = entry?;
+            // Stop at the end of the block ID map.
+            if !raw_key.starts_with(&prefix) {
+                break;
+            }
+
+            // Retrieve the transactions of the block.
+            let height: u32 = bincode::deserialize(&raw_key[PREFIX_LEN..])?;
+            let block_hash: N::BlockHash = bincode::deserialize(&raw_value)?;
+            let transactions_map = MapID::Block(BlockMap::Transactions);
+            let Some(transaction_ids) = self.get::<_, Vec<N::TransactionID>>(transactions_map, &block_hash)? else {
+                bail!("Missing the transactions of block {height} ('{block_hash}')")
+            };
+            // Retrieve the transitions of the block, in order.
+            let mut transition_ids = Vec::new();
+            for transaction_id in &transaction_ids {
+                transition_ids.extend(self.get_transition_ids::<N>(transaction_id)?);
+            }
+            // Index the transitions by their program ID and function name.
+            for (index, transition_id) in transition_ids.into_iter().enumerate() {
+                let locator_map = MapID::Transition(TransitionMap::Locator);
+                let Some(locator) = self.get::<_, (ProgramID<N>, Identifier<N>)>(locator_map, &transition_id)? else {
+                    bail!("Missing the program ID and function name of transition '{transition_id}'")
+                };
+                let position = TransitionPosition::new(height, u32::try_from(index)?);
+                entries.insert(self.raw_nested_key(map_id, &locator, &position)?, bincode::serialize(&transition_id)?);
+            }
+
+            // Report the progress.
+            self.record_processed();
+        }
+
+        // Replace the stored function index.
+        self.replace(map_id, entries)
+    }
+
+    /// Returns the IDs of the transitions of the given transaction, in order, where the fee transition is the last one.
+    fn get_transition_ids<N: Network>(&self, transaction_id: &N::TransactionID) -> Result<Vec<N::TransitionID>> {
+        // Retrieve the ID of the fee transition of the transaction.
+        let get_fee_transition_id = || -> Result<N::TransitionID> {
+            let fee_map = MapID::Fee(FeeMap::Fee);
+            match self.get::<_, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>(fee_map, transaction_id)? {
+                Some((transition_id, ..)) => Ok(transition_id),
+                None => bail!("Missing the fee of transaction '{transaction_id}'"),
+            }
+        };
+
+        match self.get::<_, TransactionType>(MapID::Transaction(TransactionMap::ID), transaction_id)? {
+            Some(TransactionType::Deploy) | Some(TransactionType::Fee) => Ok(vec![get_fee_transition_id()?]),
+            Some(TransactionType::Execute) => {
+                let execution_map = MapID::Execution(ExecutionMap::ID);
+                let Some((mut transition_ids, has_fee)) =
+                    self.get::<_, (Vec<N::TransitionID>, bool)>(execution_map, transaction_id)?
+                else {
+                    bail!("Missing the transitions of transaction '{transaction_id}'")
+                };
+                if has_fee {
+                    transition_ids.push(get_fee_transition_id()?);
+                }
+                Ok(transition_ids)
+            }
+            None => bail!("Missing the type of transaction '{transaction_id}'"),
+        }
+    }
+
+    /// Replaces the entries of the given map with the given entries, as (`raw key`, `raw value`).
+    /// Only the entries that are added, changed, or removed are staged.
+    fn replace(&mut self, map_id: MapID, mut entries: HashMap<Vec<u8>, Vec<u8>>) -> Result<()> {
+        // Note: The database is read through its own reference, so the changes can be staged while iterating.
+        let database = self.database;
+        let prefix = self.prefix(map_id);
+        let column_family = self.column_family(map_id);
+        for entry in database.prefix_iterator_cf(column_family, &prefix) {
+            let (raw_key, raw_value) = entry?;
+            // Stop at the end of the map.
+            if !raw_key.starts_with(&prefix) {
+                break;
+            }
+            match entries.get(&raw_key[..]) {
+                // Skip the entries that are unchanged.
+                Some(new_raw_value) if new_raw_value[..] == raw_value[..] => {
+                    entries.remove(&raw_key[..]);
+                }
+                // Overwrite the entries that are changed.
+                Some(_) => (),
+                // Remove the entries that are no longer present.
+                None => {
+                    self.batch.delete_cf(column_family, &raw_key);
+                    self.num_changes += 1;
+                }
+            }
+        }
+        // Write the entries that are added or changed.
+        for (raw_key, raw_value) in entries {
+            self.batch.put_cf(column_family, raw_key, raw_value);
+            self.num_changes += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the given map has no entries.
+    fn is_empty(&self, map_id: MapID) -> Result<bool> {
+        let prefix = self.prefix(map_id);
+        match self.database.prefix_iterator_cf(self.column_family(map_id), &prefix).next() {
+            Some(entry) => Ok(!entry?.0.starts_with(&prefix)),
+            None => Ok(true),
+        }
+    }
+
+    /// Returns the column family of the given map.
+    fn column_family(&self, map_id: MapID) -> &'a rocksdb::ColumnFamily {
+        self.database.column_family(u16::from(map_id))
+    }
+
+    /// Returns the prefix of the given map.
+    fn prefix(&self, map_id: MapID) -> Vec<u8> {
+        let mut prefix = self.database.network_id.to_le_bytes().to_vec();
+        prefix.extend_from_slice(&u16::from(map_id).to_le_bytes());
+        prefix
+    }
+
+    /// Returns the raw key of the given key in the given map.
+    fn raw_key<K: Serialize>(&self, map_id: MapID, key: &K) -> Result<Vec<u8>> {
+        let mut raw_key = self.prefix(map_id);
+        bincode::serialize_into(&mut raw_key, key)?;
+        Ok(raw_key)
+    }
+
+    /// Returns the raw key of the given map and key in the given nested map.
+    fn raw_nested_key<M: Serialize, K: Serialize>(&self, map_id: MapID, map: &M, key: &K) -> Result<Vec<u8>> {
+        let mut raw_key = self.prefix(map_id);
+        let map_size = u32::try_from(bincode::serialized_size(map)?)?;
+        raw_key.extend_from_slice(&map_size.to_le_bytes());
+        bincode::serialize_into(&mut raw_key, map)?;
+        bincode::serialize_into(&mut raw_key, key)?;
+        Ok(raw_key)
+    }
+
+    /// Reports that the migration is finished, and returns the staged changes and their number.
+    fn finish(mut self) -> (rocksdb::WriteBatch, usize) {
+        self.progress.is_finished = true;
+        (self.on_progress)(&self.progress);
+        (self.batch, self.num_changes)
+    }
+}
+
+impl RocksDB {
+    /// Returns the schema version of the database, or `None` if the database is empty.
+    /// A database that is not empty and has no schema version was created before the schema version was recorded,
+    /// and is at schema version 0.
+    pub fn schema_version(&self) -> Result<Option<u32>> {
+        // Note: Up to schema version 1, the schema version was stored in the default column family, with all the maps.
+        let version = match self.get_pinned_cf(self.schema_column_family(), self.schema_version_key())? {
+            Some(version) => Some(version),
+            None => self.get_pinned(self.schema_version_key())?,
+        };
+        match version {
+            Some(version) => Ok(Some(bincode::deserialize(&version)?)),
+            None => {
+                // Determine whether the database is empty, i.e. whether it holds no maps in the default column family.
+                // Note: The maps in their own column families were created at the current schema version.
+                let mut iter = self.raw_iterator();
+                iter.seek_to_first();
+                iter.status()?;
+                match iter.valid() {
+                    true => Ok(Some(0)),
+                    false => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// Ensures the database is at the current schema version.
+    ///
+    /// An empty database is stamped with the current schema version, and an older database is migrated in place.
+    /// A database opened with read-only access is never written to, and must be migrated by its primary instance.
+    pub fn ensure_schema_version(&self) -> Result<()> {
+        self.ensure_schema_version_with(MIGRATIONS)
+    }
+
+    /// Migrates the database to the current schema version, in place, and returns the report of the migration.
+    /// The given callback is periodically given the progress of each migration.
+    ///
+    /// Each migration is written atomically, along with the schema version it upgrades to,
+    /// so an interrupted migration is resumed from the last completed migration.
+    /// The exception is the move of the maps into their column families, which is written in chunks,
+    /// and is resumed from the last written chunk.
+    ///
+    /// In a dry run, the changes are only counted, and nothing is written. Note that each migration then reads
+    /// the database as it is, so the counts of the later migrations are estimates when several are pending.
+    pub fn migrate(&self, dry_run: bool, on_progress: impl FnMut(&MigrationProgress)) -> Result<MigrationReport> {
+        self.migrate_with(MIGRATIONS, dry_run, on_progress)
+    }
+
+    /// Ensures the database is at the schema version of the given migrations.
+    fn ensure_schema_version_with(&self, migrations: &[Migration]) -> Result<()> {
+        let target_version = u32::try_from(migrations.len())?;
+        match self.schema_version()? {
+            Some(version) if version == target_version => Ok(()),
+            Some(version) if version > target_version => {
+                bail!("The database schema version ({version}) is newer than the supported version ({target_version})")
+            }
+            _ if self.access_mode != AccessMode::ReadWrite => {
+                bail!("The database must be migrated to schema version {target_version} by its primary instance")
+            }
+            // Stamp the empty database with the schema version.
+            None => Ok(self.put_cf(
+                self.schema_column_family(),
+                self.schema_version_key(),
+                bincode::serialize(&target_version)?,
+            )?),
+            // Migrate the database.
+            Some(version) => {
+                info!("Migrating the database from schema version {version} to {target_version}...");
+                self.migrate_with(migrations, false, |progress| match progress.is_finished() {
+                    true => info!("Migrated the database to schema version {}", progress.from_version() + 1),
+                    false => info!(
+                        "Migrating the database to schema version {} ({}): {} entries processed",
+                        progress.from_version() + 1,
+                        progress.description(),
+                        progress.num_processed()
+                    ),
+                })?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Migrates the database with the given migrations, and returns the report of the migration.
+    fn migrate_with(
+        &self,
+        migrations: &[Migration],
+        dry_run: bool,
+        mut on_progress: impl FnMut(&MigrationProgress),
+    ) -> Result<MigrationReport> {
+        let target_version = u32::try_from(migrations.len())?;
+        // Ensure the database can be written to.
+        ensure!(dry_run || self.access_mode == AccessMode::ReadWrite, "Cannot migrate a read-only database");
+        // Ensure no atomic batch is in progress, as the migrations are written directly.
+        ensure!(
+            self.atomic_depth.load(Ordering::SeqCst) == 0 && !self.are_atomic_writes_paused(),
+            "Cannot migrate the database during an atomic batch"
+        );
+
+        // Retrieve the schema version; an empty database needs no migration.
+        let from_version = self.schema_version()?.unwrap_or(target_version);
+        ensure!(
+            from_version <= target_version,
+            "The database schema version ({from_version}) is newer than the supported version ({target_version})"
+        );
+
+        let mut report =
+            MigrationReport { from_version, to_version: from_version, migrations: vec![], is_dry_run: dry_run };
+        for (version, migration) in (from_version..).zip(migrations.iter().skip(usize::try_from(from_version)?)) {
+            // Stage the changes of the migration.
+            let mut context = MigrationContext::new(self, version, migration.description, dry_run, &mut on_progress);
+            (migration.migrate)(&mut context)?;
+            let (mut batch, num_changes) = context.finish();
+
+            // Write the changes, along with the new schema version.
+            if !dry_run {
+                batch.put_cf(
+                    self.schema_column_family(),
+                    self.schema_version_key(),
+                    bincode::serialize(&(version + 1))?,
+                );
+                self.write(batch)?;
+            }
+
+            report.migrations.push((migration.description, num_changes));
+            report.to_version = version + 1;
+        }
+        Ok(report)
+    }
+
+    /// Returns the column family of the schema version.
+    fn schema_column_family(&self) -> &rocksdb::ColumnFamily {
+        self.column_family(u16::from(MapID::Schema(SchemaMap::Version)))
+    }
+
+    /// Returns the raw key of the schema version.
+    fn schema_version_key(&self) -> Vec<u8> {
+        let mut key = self.network_id.to_le_bytes().to_vec();
+        key.extend_from_slice(&u16::from(MapID::Schema(SchemaMap::Version)).to_le_bytes());
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        helpers::{
+            memory::FinalizeMemory,
+            rocksdb::{internal::tests::temp_dir, BlockMap, TestMap},
+            MapRead,
+        },
+        FinalizeStorage,
+        FinalizeStore,
+    };
+    use console::{
+        network::{MainnetV0, Network},
+        prelude::{FromBytes, FromStr, Zero},
+        types::Field,
+    };
+    use ledger_block::{Block, Header, Transaction};
+    use synthesizer_program::FinalizeStoreTrait;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// The number of entries in the fixture.
+    const NUM_ENTRIES: usize = 25_000;
+
+    /// The migrations up to schema version 3, where schema version 3 moves the `Test` map of `u32` to `String`
+    /// into the `Test2` map of `u64` to `(String, u32)`, where the `u32` is the length of the string.
+    const TEST_MIGRATIONS: &[Migration] = &[MIGRATIONS[0], MIGRATIONS[1], Migration {
+        description: "Move the test map",
+        migrate: |context| {
+            context.rewrite(MapID::Test(TestMap::Test), MapID::Test(TestMap::Test2), |key: u32, value: String| {
+                let length = u32::try_from(value.len())?;
+                Ok(Some((u64::from(key), (value, length))))
+            })
+        },
+    }];
+
+    /// Writes the fixture at schema version 0, i.e. with no schema version and the `Test` map of `u32` to `String`,
+    /// in the default column family.
+    fn write_fixture(database: &RocksDB) {
+        let mut batch = rocksdb::WriteBatch::default();
+        for key in 0..u32::try_from(NUM_ENTRIES).unwrap() {
+            let mut raw_key = database.network_id.to_le_bytes().to_vec();
+            raw_key.extend_from_slice(&u16::from(MapID::Test(TestMap::Test)).to_le_bytes());
+            bincode::serialize_into(&mut raw_key, &key).unwrap();
+            batch.put(raw_key, bincode::serialize(&key.to_string()).unwrap());
+        }
+        database.write(batch).unwrap();
+    }
+
+    /// Writes the given entry of the given map at schema version 1, i.e. in the default column family.
+    fn write_v1_entry<K: Serialize, V: Serialize>(database: &RocksDB, map_id: MapID, key: &K, value: &V) {
+        let mut raw_key = database.network_id.to_le_bytes().to_vec();
+        raw_key.extend_from_slice(&u16::from(map_id).to_le_bytes());
+        bincode::serialize_into(&mut raw_key, key).unwrap();
+        database.put(raw_key, bincode::serialize(value).unwrap()).unwrap();
+    }
+
+    /// Returns the entries of the given map.
+    fn entries<K: DeserializeOwned, V: DeserializeOwned>(database: &RocksDB, map_id: MapID) -> Vec<(K, V)> {
+        let mut on_progress = |_: &MigrationProgress| {};
+        let context = MigrationContext::new(database, 0, "", true, &mut on_progress);
+        context.entries(map_id).collect::<Result<Vec<_>>>().unwrap()
+    }
+
+    /// Returns the number of entries in the default column family.
+    fn num_default_entries(database: &RocksDB) -> usize {
+        database.iterator(rocksdb::IteratorMode::Start).count()
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_new_database_is_stamped() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        // Ensure the new database has no schema version.
+        assert_eq!(database.schema_version().unwrap(), None);
+
+        // Ensure the new database is stamped with the current schema version.
+        database.ensure_schema_version().unwrap();
+        assert_eq!(database.schema_version().unwrap(), Some(SCHEMA_VERSION));
+        assert_eq!(num_default_entries(&database), 0);
+
+        // Ensure there is nothing to migrate.
+        let report = database.migrate(false, |_| {}).unwrap();
+        assert_eq!(report.from_version(), SCHEMA_VERSION);
+        assert_eq!(report.to_version(), SCHEMA_VERSION);
+        assert!(report.migrations().is_empty());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_fixture() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+        // Ensure the fixture is at schema version 0.
+        assert_eq!(database.schema_version().unwrap(), Some(0));
+
+        // Ensure a dry run counts the changes, and writes nothing.
+        // Note: The test map is still in the default column family during the dry run, so moving it counts no changes.
+        let report = database.migrate_with(TEST_MIGRATIONS, true, |_| {}).unwrap();
+        assert!(report.is_dry_run());
+        assert_eq!((report.from_version(), report.to_version()), (0, 3));
+        assert_eq!(report.migrations(), &[
+            ("Record the schema version", 0),
+            ("Move each map into its own column family", 2 * NUM_ENTRIES),
+            ("Move the test map", 0)
+        ]);
+        assert_eq!(database.schema_version().unwrap(), Some(0));
+        assert_eq!(num_default_entries(&database), NUM_ENTRIES);
+        assert!(entries::<u64, (String, u32)>(&database, MapID::Test(TestMap::Test2)).is_empty());
+
+        // Migrate the fixture.
+        let mut progress = vec![];
+        let report = database.migrate_with(TEST_MIGRATIONS, false, |p| progress.push(p.clone())).unwrap();
+        assert!(!report.is_dry_run());
+        assert_eq!((report.from_version(), report.to_version()), (0, 3));
+        assert_eq!(database.schema_version().unwrap(), Some(3));
+
+        // Ensure the progress was reported periodically, and at the end of each migration.
+        let finished = progress.iter().filter(|p| p.is_finished()).map(|p| p.from_version()).collect::<Vec<_>>();
+        assert_eq!(finished, vec![0, 1, 2]);
+        assert_eq!(progress.len(), 3 + 2 * (NUM_ENTRIES / MigrationContext::PROGRESS_INTERVAL));
+        assert_eq!(progress.last().unwrap().num_processed(), NUM_ENTRIES);
+
+        // Ensure the entries were moved.
+        assert_eq!(num_default_entries(&database), 0);
+        assert!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).is_empty());
+        let moved = entries::<u64, (String, u32)>(&database, MapID::Test(TestMap::Test2));
+        assert_eq!(moved.len(), NUM_ENTRIES);
+        for (key, (value, length)) in moved {
+            assert_eq!(value, key.to_string());
+            assert_eq!(length as usize, value.len());
+        }
+
+        // Ensure the migrated database is up to date.
+        let report = database.migrate_with(TEST_MIGRATIONS, false, |_| {}).unwrap();
+        assert!(report.migrations().is_empty());
+        database.ensure_schema_version_with(TEST_MIGRATIONS).unwrap();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_fixture_on_open() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+
+        // Ensure the fixture is migrated to the current schema version, moving its entries into their column family.
+        database.ensure_schema_version().unwrap();
+        assert_eq!(database.schema_version().unwrap(), Some(SCHEMA_VERSION));
+        assert_eq!(num_default_entries(&database), 0);
+        assert_eq!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).len(), NUM_ENTRIES);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_legacy_schema_version() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+        // Record schema version 1 in the default column family, where it was stored before the column families.
+        database.put(database.schema_version_key(), bincode::serialize(&1u32).unwrap()).unwrap();
+        assert_eq!(database.schema_version().unwrap(), Some(1));
+
+        // Ensure the fixture is migrated from schema version 1.
+        let report = database.migrate(false, |_| {}).unwrap();
+        assert_eq!((report.from_version(), report.to_version()), (1, SCHEMA_VERSION));
+        assert_eq!(database.schema_version().unwrap(), Some(SCHEMA_VERSION));
+        assert_eq!(num_default_entries(&database), 0);
+        assert_eq!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).len(), NUM_ENTRIES);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_v1_ledger() {
+        // Note: The function index is built for the network of the database.
+        let database = RocksDB { network_id: CurrentNetwork::ID, ..RocksDB::open_testing(temp_dir(), None).unwrap() };
+
+        // Write the genesis block at schema version 1, as the ledger stored it before the column families.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+        write_v1_entry(&database, MapID::Block(BlockMap::StateRoot), &0u32, &block.previous_state_root());
+        write_v1_entry(&database, MapID::Block(BlockMap::ID), &0u32, &block.hash());
+        write_v1_entry(&database, MapID::Block(BlockMap::ReverseID), &block.hash(), &0u32);
+        write_v1_entry(&database, MapID::Block(BlockMap::Header), &block.hash(), block.header());
+        write_v1_entry(&database, MapID::Block(BlockMap::Transactions), &block.hash(), &transaction_ids);
+        for confirmed in block.transactions().iter() {
+            let transaction = confirmed.transaction();
+            let transaction_id = transaction.id();
+            let Transaction::Execute(_, execution, fee) = transaction else {
+                unreachable!("The genesis block holds only executions")
+            };
+            let transition_ids = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+            write_v1_entry(
+                &database,
+                MapID::Transaction(TransactionMap::ID),
+                &transaction_id,
+                &TransactionType::Execute,
+            );
+            write_v1_entry(
+                &database,
+                MapID::Execution(ExecutionMap::ID),
+                &transaction_id,
+                &(transition_ids, fee.is_some()),
+            );
+            if let Some(fee) = fee {
+                let fee = (*fee.transition_id(), fee.global_state_root(), fee.proof().cloned());
+                write_v1_entry(&database, MapID::Fee(FeeMap::Fee), &transaction_id, &fee);
+            }
+            for transition in transaction.transitions() {
+                let locator = (*transition.program_id(), *transition.function_name());
+                write_v1_entry(&database, MapID::Transition(TransitionMap::Locator), transition.id(), &locator);
+            }
+        }
+        write_v1_entry(&database, MapID::Schema(SchemaMap::Version), &(), &1u32);
+        assert_eq!(database.schema_version().unwrap(), Some(1));
+
+        // Ensure the database is migrated to the current schema version.
+        database.ensure_schema_version().unwrap();
+        assert_eq!(database.schema_version().unwrap(), Some(SCHEMA_VERSION));
+        assert_eq!(num_default_entries(&database), 0);
+
+        // Ensure the block is read from the maps in their column families.
+        type BlockHash = <CurrentNetwork as Network>::BlockHash;
+        type TransactionID = <CurrentNetwork as Network>::TransactionID;
+        let id_map = RocksDB::open_map_testing_in::<u32, BlockHash, _>(database.clone(), MapID::Block(BlockMap::ID));
+        let header_map = RocksDB::open_map_testing_in::<BlockHash, Header<CurrentNetwork>, _>(
+            database.clone(),
+            MapID::Block(BlockMap::Header),
+        );
+        let transactions_map = RocksDB::open_map_testing_in::<BlockHash, Vec<TransactionID>, _>(
+            database.clone(),
+            MapID::Block(BlockMap::Transactions),
+        );
+        let hash = id_map.get_confirmed(&0).unwrap().unwrap().into_owned();
+        assert_eq!(hash, block.hash());
+        assert_eq!(header_map.get_confirmed(&hash).unwrap().as_deref(), Some(block.header()));
+        assert_eq!(transactions_map.get_confirmed(&hash).unwrap().as_deref(), Some(&transaction_ids));
+        assert_eq!(id_map.iter_confirmed().count(), 1);
+
+        // Ensure the function index holds the transitions of the block, in order.
+        let mut on_progress = |_: &MigrationProgress| {};
+        let context = MigrationContext::new(&database, 0, "", true, &mut on_progress);
+        let mut function_index = context
+            .nested_entries::<(ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>), TransitionPosition, _>(
+                MapID::Transition(TransitionMap::FunctionIndex),
+            )
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        function_index.sort_unstable_by_key(|(_, position, _)| *position);
+        let expected = block
+            .transitions()
+            .enumerate()
+            .map(|(index, transition)| {
+                let locator = (*transition.program_id(), *transition.function_name());
+                (locator, TransitionPosition::new(0, index as u32), *transition.id())
+            })
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        assert_eq!(function_index, expected);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_finalize_tree() {
+        // Note: The finalize tree is built for the network of the database.
+        let database = RocksDB { network_id: CurrentNetwork::ID, ..RocksDB::open_testing(temp_dir(), None).unwrap() };
+        let version = bincode::serialize(&2u32).unwrap();
+        database.put_cf(database.schema_column_family(), database.schema_version_key(), version).unwrap();
+
+        // Write the key-value entries at schema version 2, i.e. before the finalize tree was maintained,
+        // along with a stale node of the finalize tree.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let entries = (0..10)
+            .map(|i| (Plaintext::from_str(&format!("{i}field")).unwrap(), Value::from_str(&format!("{i}u64")).unwrap()))
+            .collect::<Vec<(Plaintext<CurrentNetwork>, Value<CurrentNetwork>)>>();
+        let stale_node_id = (200u8, Field::<CurrentNetwork>::zero());
+        let mut on_progress = |_: &MigrationProgress| {};
+        let mut context = MigrationContext::new(&database, 0, "", false, &mut on_progress);
+        for (key, value) in &entries {
+            context
+                .insert_nested(MapID::Program(ProgramMap::KeyValueID), &(program_id, mapping_name), key, value)
+                .unwrap();
+        }
+        let stale_node = FinalizeTreeNode::<CurrentNetwork>::Leaf(Field::zero(), Field::zero());
+        context.insert(MapID::Program(ProgramMap::Tree), &stale_node_id, &stale_node).unwrap();
+        database.write(context.finish().0).unwrap();
+
+        // Ensure the finalize tree is built, and the stale node is removed.
+        let report = database.migrate(false, |_| {}).unwrap();
+        assert_eq!((report.from_version(), report.to_version()), (2, SCHEMA_VERSION));
+        assert_eq!(report.migrations()[0].0, "Build the finalize tree");
+        let tree_map = RocksDB::open_map_testing_in::<
+            FinalizeTreeNodeID<CurrentNetwork>,
+            FinalizeTreeNode<CurrentNetwork>,
+            _,
+        >(database.clone(), MapID::Program(ProgramMap::Tree));
+        assert!(tree_map.get_confirmed(&stale_node_id).unwrap().is_none());
+
+        // Ensure the finalize tree matches the one maintained by a finalize store with the same entries.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for (key, value) in entries {
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        assert_eq!(FinalizeTree::new(&tree_map).root().unwrap(), finalize_store.get_checksum_confirmed().unwrap());
+        assert_eq!(tree_map.iter_confirmed().count(), finalize_store.storage().tree_map().iter_confirmed().count());
+
+        // Ensure the migration is idempotent.
+        let mut on_progress = |_: &MigrationProgress| {};
+        let mut context = MigrationContext::new(&database, 2, "", true, &mut on_progress);
+        context.build_finalize_tree().unwrap();
+        assert_eq!(context.finish().1, 0);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_move_into_column_families_in_chunks() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+        database.put(database.schema_version_key(), bincode::serialize(&1u32).unwrap()).unwrap();
+
+        // Ensure a dry run writes no chunk.
+        let mut num_remaining = vec![];
+        database
+            .migrate(true, |progress| {
+                if !progress.is_finished() {
+                    num_remaining.push(num_default_entries(&database));
+                }
+            })
+            .unwrap();
+        assert_eq!(num_remaining, vec![NUM_ENTRIES + 1; NUM_ENTRIES / MigrationContext::PROGRESS_INTERVAL]);
+
+        // Ensure the entries are moved in chunks, which are written during the migration.
+        // Note: A chunk is written every 10,000 entries, as each move is a write and a removal.
+        let mut num_remaining = vec![];
+        database
+            .migrate(false, |progress| {
+                if !progress.is_finished() {
+                    num_remaining.push(num_default_entries(&database));
+                }
+            })
+            .unwrap();
+        assert_eq!(num_remaining, vec![NUM_ENTRIES + 1 - 10_000, NUM_ENTRIES + 1 - 20_000]);
+        assert_eq!(num_default_entries(&database), 0);
+        assert_eq!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).len(), NUM_ENTRIES);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_resume_move_into_column_families() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+        database.put(database.schema_version_key(), bincode::serialize(&1u32).unwrap()).unwrap();
+
+        // Move the first chunk of entries of the test map, as an interrupted migration would.
+        let num_moved = 10_000;
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut prefix = database.network_id.to_le_bytes().to_vec();
+        prefix.extend_from_slice(&u16::from(MapID::Test(TestMap::Test)).to_le_bytes());
+        let column_family = database.column_family(u16::from(MapID::Test(TestMap::Test)));
+        for entry in database.prefix_iterator(&prefix).take(num_moved) {
+            let (raw_key, raw_value) = entry.unwrap();
+            batch.put_cf(column_family, &raw_key, raw_value);
+            batch.delete(raw_key);
+        }
+        database.write(batch).unwrap();
+        assert_eq!(database.schema_version().unwrap(), Some(1));
+
+        // Ensure the migration moves the remaining entries, along with the schema version.
+        let report = database.migrate(false, |_| {}).unwrap();
+        assert_eq!((report.from_version(), report.to_version()), (1, SCHEMA_VERSION));
+        let expected = [
+            ("Move each map into its own column family", 2 * (NUM_ENTRIES + 1 - num_moved)),
+            ("Build the finalize tree", 0),
+            ("Build the function index", 0),
+        ];
+        assert_eq!(report.migrations(), &expected);
+        assert_eq!(num_default_entries(&database), 0);
+        assert_eq!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).len(), NUM_ENTRIES);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_interrupted_migration() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        write_fixture(&database);
+
+        // Ensure a failed migration leaves the database at the last completed migration.
+        let migrations = [MIGRATIONS[0], MIGRATIONS[1], Migration {
+            description: "Fail",
+            migrate: |_| bail!("The migration failed"),
+        }];
+        assert!(database.migrate_with(&migrations, false, |_| {}).is_err());
+        assert_eq!(database.schema_version().unwrap(), Some(2));
+        assert_eq!(entries::<u32, String>(&database, MapID::Test(TestMap::Test)).len(), NUM_ENTRIES);
+
+        // Ensure the migration is resumed from the last completed migration.
+        let report = database.migrate_with(TEST_MIGRATIONS, false, |_| {}).unwrap();
+        assert_eq!((report.from_version(), report.to_version()), (2, 3));
+        assert_eq!(entries::<u64, (String, u32)>(&database, MapID::Test(TestMap::Test2)).len(), NUM_ENTRIES);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_newer_schema_version() {
+        let database = RocksDB::open_testing(temp_dir(), None).unwrap();
+        let version = bincode::serialize(&(SCHEMA_VERSION + 1)).unwrap();
+        database.put_cf(database.schema_column_family(), database.schema_version_key(), version).unwrap();
+
+        // Ensure a database with a newer schema version is rejected.
+        assert!(database.ensure_schema_version().is_err());
+        assert!(database.migrate(true, |_| {}).is_err());
+    }
+}
@@ -18,9 +18,15 @@ pub use id::*;
 mod map;
 pub use map::*;
 
+mod migration;
+pub use migration::*;
+
 mod nested_map;
 pub use nested_map::*;
 
+#[cfg(feature = "metrics")]
+mod stats;
+
 #[cfg(test)]
 mod tests;
 
@@ -42,6 +48,24 @@ use std::{
 
 pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
 
+/// Returns the map ID of the given map context, i.e. the prefix of the keys of the map.
+fn map_id(context: &[u8]) -> u16 {
+    u16::from_le_bytes([context[2], context[3]])
+}
+
+/// The access mode of the database.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AccessMode {
+    /// The database is opened as the primary instance, with read and write access.
+    #[default]
+    ReadWrite,
+    /// The database is opened with read-only access, as of the moment it is opened.
+    ReadOnly,
+    /// The database is opened as a secondary instance with read-only access,
+    /// which can catch up with the writes of the primary instance.
+    Secondary,
+}
+
 pub trait Database {
     /// Opens the database.
     fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self>
@@ -83,6 +107,8 @@ pub struct RocksDB {
     network_id: u16,
     /// The storage mode.
     storage_mode: StorageMode,
+    /// The access mode.
+    access_mode: AccessMode,
     /// The low-level database transaction that gets executed atomically at the end
     /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
     pub(super) atomic_batch: Arc<Mutex<rocksdb::WriteBatch>>,
@@ -107,44 +133,8 @@ impl Database for RocksDB {
     /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
     fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
-        static DB: OnceCell<RocksDB> = OnceCell::new();
-
-        // Retrieve the database.
-        let database = DB
-            .get_or_try_init(|| {
-                // Customize database options.
-                let mut options = rocksdb::Options::default();
-                options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-                // Register the prefix length.
-                let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-                options.set_prefix_extractor(prefix_extractor);
-
-                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
-                let rocksdb = {
-                    options.increase_parallelism(2);
-                    options.set_max_background_jobs(4);
-                    options.create_if_missing(true);
-
-                    Arc::new(rocksdb::DB::open(&options, primary)?)
-                };
-
-                Ok::<_, anyhow::Error>(RocksDB {
-                    rocksdb,
-                    network_id,
-                    storage_mode: storage.clone().into(),
-                    atomic_batch: Default::default(),
-                    atomic_depth: Default::default(),
-                    atomic_writes_paused: Default::default(),
-                })
-            })?
-            .clone();
-
-        // Ensure the database network ID and storage mode match.
-        match database.network_id == network_id && database.storage_mode == storage.into() {
-            true => Ok(database),
-            false => bail!("Mismatching network ID or storage mode in the database"),
-        }
+        // Note: If the database is already open, it is returned in its existing access mode.
+        Self::initialize(network_id, storage, AccessMode::ReadWrite)
     }
 
     /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
@@ -206,6 +196,166 @@ impl Database for RocksDB {
 }
 
 impl RocksDB {
+    /// The size of the block cache, which is shared by the column families of all the maps.
+    const BLOCK_CACHE_SIZE: usize = 256 * 1024 * 1024;
+    /// The block size of the maps of large and mostly incompressible values.
+    const BULK_BLOCK_SIZE: usize = 64 * 1024;
+    /// The total size of the memtables of all the column families.
+    const WRITE_BUFFER_SIZE: usize = 512 * 1024 * 1024;
+
+    /// Opens the database in the given access mode.
+    ///
+    /// This must be called before any store is opened, as the database is opened once per process.
+    /// If the database is already open in a different access mode, an error is returned.
+    pub fn open_with_access_mode<S: Clone + Into<StorageMode>>(
+        network_id: u16,
+        storage: S,
+        access_mode: AccessMode,
+    ) -> Result<Self> {
+        // Open the database.
+        let database = Self::initialize(network_id, storage, access_mode)?;
+        // Ensure the database access mode matches.
+        match database.access_mode == access_mode {
+            true => Ok(database),
+            false => bail!("The database is already open in {:?} mode", database.access_mode),
+        }
+    }
+
+    /// Opens the database once per process, in the given access mode.
+    fn initialize<S: Clone + Into<StorageMode>>(network_id: u16, storage: S, access_mode: AccessMode) -> Result<Self> {
+        static DB: OnceCell<RocksDB> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
+                // The secondary instance keeps its own logs, in a directory that is unique to the process.
+                let secondary =
+                    std::env::temp_dir().join(format!("aleo-ledger-{network_id}-secondary-{}", std::process::id()));
+                let rocksdb = Arc::new(Self::open_instance(&primary, &secondary, access_mode)?);
+
+                Ok::<_, anyhow::Error>(RocksDB {
+                    rocksdb,
+                    network_id,
+                    storage_mode: storage.clone().into(),
+                    access_mode,
+                    atomic_batch: Default::default(),
+                    atomic_depth: Default::default(),
+                    atomic_writes_paused: Default::default(),
+                })
+            })?
+            .clone();
+
+        // Ensure the database network ID and storage mode match.
+        match database.network_id == network_id && database.storage_mode == storage.into() {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or storage mode in the database"),
+        }
+    }
+
+    /// Opens the RocksDB instance in the given directory, in the given access mode.
+    /// A secondary instance keeps its logs in the given `secondary` directory.
+    fn open_instance(
+        primary: &std::path::Path,
+        secondary: &std::path::Path,
+        access_mode: AccessMode,
+    ) -> Result<rocksdb::DB> {
+        // Customize database options.
+        let mut options = rocksdb::Options::default();
+        options.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        // Register the prefix length.
+        let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+        options.set_prefix_extractor(prefix_extractor);
+
+        // Bound the memory of the memtables, which are kept per column family.
+        options.set_db_write_buffer_size(Self::WRITE_BUFFER_SIZE);
+        // Collect the statistics of the block cache, to report its hit rate.
+        #[cfg(feature = "metrics")]
+        options.enable_statistics();
+        // Prepare the column families of the maps, which share the block cache.
+        let block_cache = rocksdb::Cache::new_lru_cache(Self::BLOCK_CACHE_SIZE);
+        let column_families = Self::column_family_descriptors(&block_cache);
+
+        match access_mode {
+            AccessMode::ReadWrite => {
+                options.increase_parallelism(2);
+                options.set_max_background_jobs(4);
+                options.create_if_missing(true);
+                options.create_missing_column_families(true);
+
+                Ok(rocksdb::DB::open_cf_descriptors(&options, primary, column_families)?)
+            }
+            AccessMode::ReadOnly => {
+                Self::ensure_column_families_exist(&options, primary)?;
+
+                Ok(rocksdb::DB::open_cf_descriptors_read_only(&options, primary, column_families, false)?)
+            }
+            AccessMode::Secondary => {
+                Self::ensure_column_families_exist(&options, primary)?;
+
+                // Note: A secondary instance is required to keep all of its files open.
+                options.set_max_open_files(-1);
+
+                Ok(rocksdb::DB::open_cf_descriptors_as_secondary(&options, primary, secondary, column_families)?)
+            }
+        }
+    }
+
+    /// Returns the descriptors of the column families of the maps, with the options tuned for the values of each map.
+    fn column_family_descriptors(block_cache: &rocksdb::Cache) -> Vec<rocksdb::ColumnFamilyDescriptor> {
+        (0..NUM_MAPS)
+            .map(|map_id| {
+                let mut block_options = rocksdb::BlockBasedOptions::default();
+                block_options.set_block_cache(block_cache);
+                // Skip the reads of the missing keys with a bloom filter.
+                block_options.set_bloom_filter(10.0, false);
+
+                let mut options = rocksdb::Options::default();
+                // Register the prefix length, as the keys keep the prefix of their map.
+                options.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN));
+                match is_bulk_map(map_id) {
+                    // Skip the compression of the incompressible values, and read them in larger blocks.
+                    true => {
+                        options.set_compression_type(rocksdb::DBCompressionType::None);
+                        block_options.set_block_size(Self::BULK_BLOCK_SIZE);
+                    }
+                    false => options.set_compression_type(rocksdb::DBCompressionType::Lz4),
+                }
+                options.set_block_based_table_factory(&block_options);
+
+                rocksdb::ColumnFamilyDescriptor::new(column_family_name(map_id), options)
+            })
+            .collect()
+    }
+
+    /// Ensures the column families of all the maps exist, as they cannot be created with read-only access.
+    fn ensure_column_families_exist(options: &rocksdb::Options, path: &std::path::Path) -> Result<()> {
+        let column_families = rocksdb::DB::list_cf(options, path)?;
+        ensure!(
+            (0..NUM_MAPS).all(|map_id| column_families.iter().any(|name| name == column_family_name(map_id))),
+            "The database must be migrated to the current schema version by its primary instance"
+        );
+        Ok(())
+    }
+
+    /// Returns the column family of the map with the given map ID.
+    pub(super) fn column_family(&self, map_id: u16) -> &rocksdb::ColumnFamily {
+        // Note: The column families of all the maps are opened along with the database.
+        self.rocksdb.cf_handle(column_family_name(map_id)).expect("The column family of every map is open")
+    }
+
+    /// Returns the access mode of the database.
+    pub const fn access_mode(&self) -> AccessMode {
+        self.access_mode
+    }
+
+    /// Catches up with the writes of the primary instance, if the database is a secondary instance.
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        ensure!(self.access_mode == AccessMode::Secondary, "The database is not a secondary instance");
+        Ok(self.rocksdb.try_catch_up_with_primary()?)
+    }
+
     /// Pause the execution of atomic writes for the entire database.
     fn pause_atomic_writes(&self) -> Result<()> {
         // This operation is only intended to be performed before or after
@@ -242,7 +392,7 @@ impl RocksDB {
         // writes have been paused becomes executed as a single atomic batch.
         let batch = mem::take(&mut *self.atomic_batch.lock());
         if !DISCARD_BATCH {
-            self.rocksdb.write(batch)?;
+            self.write_atomic_batch(batch)?;
         }
 
         // Unset the flag indicating that the pause is in effect.
@@ -251,6 +401,26 @@ impl RocksDB {
         Ok(())
     }
 
+    /// Writes the given atomic batch to the database.
+    fn write_atomic_batch(&self, batch: rocksdb::WriteBatch) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        let (num_operations, num_bytes, timer) = (batch.len(), batch.size_in_bytes(), std::time::Instant::now());
+        self.rocksdb.write(batch)?;
+        #[cfg(feature = "metrics")]
+        stats::record_atomic_batch(num_operations, num_bytes, timer.elapsed());
+        Ok(())
+    }
+
+    /// Records the number of keys and the size in bytes of each map, with a full scan of the database,
+    /// along with the hit rate of the block cache.
+    ///
+    /// Note: The sizes are those of the serialized entries, before compression.
+    #[cfg(feature = "metrics")]
+    pub fn record_map_metrics(&self) -> Result<()> {
+        stats::record_map_sizes(self)?;
+        stats::record_block_cache(self)
+    }
+
     /// Checks whether the atomic writes are currently paused.
     fn are_atomic_writes_paused(&self) -> bool {
         self.atomic_writes_paused.load(Ordering::SeqCst)
@@ -282,10 +452,20 @@ impl RocksDB {
             let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
             options.set_prefix_extractor(prefix_extractor);
 
+            // Bound the memory of the memtables, which are kept per column family.
+            options.set_db_write_buffer_size(Self::WRITE_BUFFER_SIZE);
+            // Collect the statistics of the block cache, to report its hit rate.
+            #[cfg(feature = "metrics")]
+            options.enable_statistics();
+            // Prepare the column families of the maps, which share the block cache.
+            let block_cache = rocksdb::Cache::new_lru_cache(Self::BLOCK_CACHE_SIZE);
+            let column_families = Self::column_family_descriptors(&block_cache);
+
             let rocksdb = {
                 options.increase_parallelism(2);
                 options.set_max_background_jobs(4);
                 options.create_if_missing(true);
+                options.create_missing_column_families(true);
 
                 // Keep these around as options for configuration testing.
 
@@ -299,13 +479,14 @@ impl RocksDB {
                 // options.set_bottommost_compression_type(rocksdb::DBCompressionType::None);
                 // options.set_write_buffer_size(1 << 28);
 
-                Arc::new(rocksdb::DB::open(&options, primary)?)
+                Arc::new(rocksdb::DB::open_cf_descriptors(&options, primary, column_families)?)
             };
 
             Ok::<_, anyhow::Error>(RocksDB {
                 rocksdb,
                 network_id: u16::MAX,
                 storage_mode: storage_mode.clone(),
+                access_mode: AccessMode::ReadWrite,
                 atomic_batch: Default::default(),
                 atomic_depth: Default::default(),
                 atomic_writes_paused: Default::default(),
@@ -319,6 +500,26 @@ impl RocksDB {
         }
     }
 
+    /// Opens another instance of the given test database, in the given access mode.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing_instance(database: &RocksDB, access_mode: AccessMode) -> Result<Self> {
+        // Retrieve the directory of the test database.
+        let StorageMode::Custom(primary) = &database.storage_mode else {
+            bail!("The test database must be in a custom directory")
+        };
+        let secondary = primary.with_extension("secondary");
+
+        Ok(RocksDB {
+            rocksdb: Arc::new(Self::open_instance(primary, &secondary, access_mode)?),
+            network_id: database.network_id,
+            storage_mode: database.storage_mode.clone(),
+            access_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        })
+    }
+
     /// Opens the test map.
     #[cfg(any(test, feature = "test"))]
     pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
@@ -328,19 +529,28 @@ impl RocksDB {
     ) -> Result<DataMap<K, V>> {
         // Open the RocksDB test database.
         let database = Self::open_testing(temp_dir, dev)?;
+        // Return the DataMap.
+        Ok(Self::open_map_testing_in(database, map_id))
+    }
 
+    /// Opens the test map in the given test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing_in<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        database: RocksDB,
+        map_id: T,
+    ) -> DataMap<K, V> {
         // Combine contexts to create a new scope.
         let mut context = database.network_id.to_le_bytes().to_vec();
         context.extend_from_slice(&(map_id.into()).to_le_bytes());
 
         // Return the DataMap.
-        Ok(DataMap(Arc::new(InnerDataMap {
+        DataMap(Arc::new(InnerDataMap {
             database,
             context,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoints: Default::default(),
-        })))
+        }))
     }
 
     /// Opens the test nested map.
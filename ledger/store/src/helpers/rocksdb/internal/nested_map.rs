@@ -54,6 +54,12 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
 impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
     NestedDataMap<M, K, V>
 {
+    /// Returns the column family of the map.
+    #[inline]
+    fn column_family(&self) -> &rocksdb::ColumnFamily {
+        self.database.column_family(map_id(&self.context))
+    }
+
     #[inline]
     fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>> {
         let mut raw_map = self.context.clone();
@@ -75,14 +81,14 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     #[inline]
     fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<rocksdb::DBPinnableSlice>> {
         let raw_map_key = self.create_prefixed_map_key(map, key)?;
-        match self.database.get_pinned(&raw_map_key)? {
+        match self.database.get_pinned_cf(self.column_family(), &raw_map_key)? {
             Some(data) => Ok(Some(data)),
             None => Ok(None),
         }
     }
 }
 #[inline]
-fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
+pub(super) fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
     // Retrieve the map length.
     let map_len = u32::from_bytes_le(
         map_key.get(PREFIX_LEN..PREFIX_LEN + 4).ok_or_else(|| anyhow!("NestedMap map_len index out of range"))?,
@@ -120,7 +126,9 @@ impl<
                 // Prepare the prefixed map-key and serialized value.
                 let raw_key = self.create_prefixed_map_key(&map, &key)?;
                 let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                #[cfg(feature = "metrics")]
+                stats::record_write(raw_key.len() + raw_value.len());
+                self.database.put_cf(self.column_family(), raw_key, raw_value)?;
             }
         }
         Ok(())
@@ -143,7 +151,7 @@ impl<
                 let mut batch = rocksdb::WriteBatch::default();
 
                 // Construct an iterator over the DB with the specified prefix.
-                let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
+                let iterator = self.database.prefix_iterator_cf(self.column_family(), &self.create_prefixed_map(map)?);
 
                 // Iterate over the entries in the DB with the specified prefix.
                 for entry in iterator {
@@ -156,7 +164,7 @@ impl<
 
                     // If the 'entry_map' matches 'serialized_map', delete the key.
                     if entry_map == serialized_map {
-                        batch.delete(map_key);
+                        batch.delete_cf(self.column_family(), map_key);
                     } else {
                         // If the 'entry_map' no longer matches the 'serialized_map',
                         // we've moved past the relevant keys and can break the loop.
@@ -165,6 +173,8 @@ impl<
                 }
 
                 // Deleting the batched keys atomically from RocksDB.
+                #[cfg(feature = "metrics")]
+                stats::record_write(batch.size_in_bytes());
                 self.database.write(batch)?;
             }
         }
@@ -183,7 +193,9 @@ impl<
             false => {
                 // Prepare the prefixed map-key.
                 let map_key = self.create_prefixed_map_key(map, key)?;
-                self.database.delete(map_key)?;
+                #[cfg(feature = "metrics")]
+                stats::record_write(map_key.len());
+                self.database.delete_cf(self.column_family(), map_key)?;
             }
         }
         Ok(())
@@ -273,21 +285,31 @@ impl<
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
         if !operations.is_empty() {
+            #[cfg(feature = "metrics")]
+            stats::record_map_batch(&self.context, operations.len());
+
             // Enqueue all the operations from the map in the database-wide batch.
             let mut atomic_batch = self.database.atomic_batch.lock();
 
             for (map, key, value) in operations {
                 match (key, value) {
                     (Some(key), Some(value)) => {
-                        atomic_batch.put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?)
+                        atomic_batch.put_cf(
+                        self.column_family(),
+                        self.create_prefixed_map_key(&map, &key)?,
+                        bincode::serialize(&value)?,
+                    )
+                    }
+                    (Some(key), None) => {
+                        atomic_batch.delete_cf(self.column_family(), self.create_prefixed_map_key(&map, &key)?)
                     }
-                    (Some(key), None) => atomic_batch.delete(self.create_prefixed_map_key(&map, &key)?),
                     (None, None) => {
                         // Serialize the map.
                         let serialized_map = bincode::serialize(&map)?;
 
                         // Construct an iterator over the DB with the specified prefix.
-                        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(&map)?);
+                        let iterator =
+                            self.database.prefix_iterator_cf(self.column_family(), &self.create_prefixed_map(&map)?);
 
                         // Iterate over the entries in the DB with the specified prefix.
                         for entry in iterator {
@@ -300,7 +322,7 @@ impl<
 
                             // If the 'entry_map' matches 'serialized_map', delete the key.
                             if entry_map == serialized_map {
-                                atomic_batch.delete(map_key);
+                                atomic_batch.delete_cf(self.column_family(), map_key);
                             } else {
                                 // If the 'entry_map' no longer matches the 'serialized_map',
                                 // we've moved past the relevant keys and can break the loop.
@@ -332,7 +354,7 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write_atomic_batch(batch)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -365,7 +387,7 @@ impl<
         let serialized_map = &prefix[PREFIX_LEN + 4..];
 
         // A raw iterator doesn't allocate.
-        let mut iter = self.database.raw_iterator();
+        let mut iter = self.database.raw_iterator_cf(self.column_family());
         // Find the first key with the nested map prefix.
         iter.seek(&prefix);
 
@@ -435,7 +457,7 @@ impl<
         let mut entries = Vec::new();
 
         // Construct an iterator over the DB with the specified prefix.
-        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
+        let iterator = self.database.prefix_iterator_cf(self.column_family(), &self.create_prefixed_map(map)?);
 
         // Iterate over the entries in the DB with the specified prefix.
         for entry in iterator {
@@ -468,6 +490,42 @@ impl<
             .collect::<Result<_, bincode::Error>>()?)
     }
 
+    ///
+    /// Returns at most `limit` confirmed key-value pairs for the given map, starting from the given key.
+    ///
+    fn get_map_range_confirmed(&'a self, map: &M, start_key: &K, limit: usize) -> Result<Vec<(K, V)>> {
+        // Serialize the map.
+        let serialized_map = bincode::serialize(map)?;
+
+        // A raw iterator doesn't allocate.
+        let mut iter = self.database.raw_iterator_cf(self.column_family());
+        // Find the first key at or after the start key.
+        iter.seek(self.create_prefixed_map_key(map, start_key)?);
+
+        // Collect the entries, until the limit or the end of the nested map.
+        let mut entries = Vec::new();
+        while entries.len() < limit {
+            let (Some(map_key), Some(value)) = (iter.key(), iter.value()) else {
+                break;
+            };
+            // Extract the bytes belonging to the map and the key.
+            let Ok((entry_map, entry_key)) = get_map_and_key(map_key) else {
+                break;
+            };
+            // If the 'entry_map' no longer matches the 'serialized_map',
+            // we've moved past the relevant keys and can break the loop.
+            if entry_map != serialized_map {
+                break;
+            }
+            entries.push((bincode::deserialize::<K>(entry_key)?, bincode::deserialize::<V>(value)?));
+            iter.next();
+        }
+        // Ensure the iteration did not stop on an error.
+        iter.status()?;
+
+        Ok(entries)
+    }
+
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
@@ -569,21 +627,21 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        NestedIter::new(self.database.prefix_iterator(&self.context))
+        NestedIter::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 
     ///
     /// Returns an iterator over each key.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        NestedKeys::new(self.database.prefix_iterator(&self.context))
+        NestedKeys::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 
     ///
     /// Returns an iterator over each value.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        NestedValues::new(self.database.prefix_iterator(&self.context))
+        NestedValues::new(self.database.prefix_iterator_cf(self.column_family(), &self.context))
     }
 }
 
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use metrics::store::*;
+use std::time::Duration;
+
+/// The label key of the per-map metrics.
+const MAP_LABEL: &str = "map";
+
+/// The name of the RocksDB statistic that counts the block cache hits.
+const BLOCK_CACHE_HIT: &str = "rocksdb.block.cache.hit";
+/// The name of the RocksDB statistic that counts the block cache misses.
+const BLOCK_CACHE_MISS: &str = "rocksdb.block.cache.miss";
+
+/// Records the number of operations that the map with the given context added to the database-wide atomic batch.
+pub(super) fn record_map_batch(context: &[u8], num_operations: usize) {
+    metrics::histogram_label(MAP_BATCH_OPERATIONS, MAP_LABEL, map_label(map_id(context)), num_operations as f64);
+}
+
+/// Records a direct write (i.e. outside of an atomic batch) of the given number of bytes.
+pub(super) fn record_write(num_bytes: usize) {
+    metrics::increment_counter_by(WRITTEN_BYTES, num_bytes as u64);
+}
+
+/// Records the commit of an atomic batch with the given number of operations and size in bytes.
+pub(super) fn record_atomic_batch(num_operations: usize, num_bytes: usize, latency: Duration) {
+    // Skip the empty batches, which are not written.
+    if num_operations == 0 {
+        return;
+    }
+    metrics::histogram(ATOMIC_BATCH_OPERATIONS, num_operations as f64);
+    metrics::histogram(ATOMIC_BATCH_SIZE, num_bytes as f64);
+    metrics::histogram(ATOMIC_BATCH_LATENCY, latency.as_secs_f64());
+    metrics::increment_counter_by(WRITTEN_BYTES, num_bytes as u64);
+}
+
+/// Records the number of keys and the size in bytes of each map in the given database.
+pub(super) fn record_map_sizes(database: &RocksDB) -> Result<()> {
+    for map_id in 0..NUM_MAPS {
+        // Scan the entries of the map.
+        let (mut num_keys, mut num_bytes) = (0usize, 0usize);
+        let mut iter = database.raw_iterator_cf(database.column_family(map_id));
+        iter.seek_to_first();
+        while let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+            num_keys += 1;
+            num_bytes += key.len() + value.len();
+            iter.next();
+        }
+        iter.status()?;
+
+        // Report the size of the map.
+        metrics::gauge_label(MAP_KEYS, MAP_LABEL, map_label(map_id), num_keys as f64);
+        metrics::gauge_label(MAP_SIZE, MAP_LABEL, map_label(map_id), num_bytes as f64);
+    }
+    Ok(())
+}
+
+/// Records the block cache hits and misses of the given database, and its block cache hit rate.
+pub(super) fn record_block_cache(database: &RocksDB) -> Result<()> {
+    // Note: The statistics are only collected by the databases opened with the `metrics` feature.
+    let Some(statistics) = database.property_value(rocksdb::properties::OPTIONS_STATISTICS)? else {
+        return Ok(());
+    };
+    let (hits, misses) = block_cache_lookups(&statistics);
+
+    metrics::counter(BLOCK_CACHE_HITS, hits);
+    metrics::counter(BLOCK_CACHE_MISSES, misses);
+    // Skip the hit rate until the block cache is used.
+    if let Some(lookups) = hits.checked_add(misses).filter(|lookups| *lookups > 0) {
+        metrics::gauge(BLOCK_CACHE_HIT_RATE, hits as f64 / lookups as f64);
+    }
+    Ok(())
+}
+
+/// Returns the number of block cache hits and misses in the given RocksDB statistics,
+/// whose tickers are reported as lines of the form `<name> COUNT : <count>`.
+fn block_cache_lookups(statistics: &str) -> (u64, u64) {
+    let (mut hits, mut misses) = (0, 0);
+    for line in statistics.lines() {
+        let mut tokens = line.split_whitespace();
+        let (Some(name), Some("COUNT"), Some(":"), Some(count)) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        else {
+            continue;
+        };
+        match name {
+            BLOCK_CACHE_HIT => hits = count.parse().unwrap_or_default(),
+            BLOCK_CACHE_MISS => misses = count.parse().unwrap_or_default(),
+            _ => continue,
+        }
+    }
+    (hits, misses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::rocksdb::{internal::tests::temp_dir, BlockMap, MapID, TestMap};
+
+    #[test]
+    fn test_map_labels() {
+        // Ensure the maps are labelled with their names.
+        assert_eq!(map_label(MapID::Block(BlockMap::StateRoot).into()), "BlockStateRootMap");
+        assert_eq!(map_label(MapID::Block(BlockMap::PrunedRejectedID).into()), "BlockPrunedRejectedIDMap");
+        // Ensure the test maps are labelled with the name of their column family.
+        assert_eq!(map_label(MapID::Test(TestMap::Test).into()), column_family_name(MapID::Test(TestMap::Test).into()));
+
+        // Ensure every map of the ledger has a distinct label.
+        let num_ledger_maps = u16::from(MapID::Test(TestMap::Test));
+        let labels = (0..num_ledger_maps).map(map_label).collect::<std::collections::HashSet<_>>();
+        assert_eq!(labels.len(), usize::from(num_ledger_maps));
+        assert!(labels.iter().all(|label| label.ends_with("Map")));
+    }
+
+    #[test]
+    fn test_block_cache_lookups() {
+        // Ensure the counts are read from the tickers of the block cache.
+        let statistics = "rocksdb.block.cache.miss COUNT : 3\n\
+                          rocksdb.block.cache.hit COUNT : 12\n\
+                          rocksdb.block.cache.add COUNT : 3\n\
+                          rocksdb.db.get.micros P50 : 1.000000 P95 : 2.000000 COUNT : 7 SUM : 9\n";
+        assert_eq!(block_cache_lookups(statistics), (12, 3));
+
+        // Ensure the missing tickers are counted as zero.
+        assert_eq!(block_cache_lookups(""), (0, 0));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_record_block_cache() {
+        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+
+        // Ensure the test database collects the statistics of its block cache.
+        let statistics = database.property_value(rocksdb::properties::OPTIONS_STATISTICS).unwrap().unwrap();
+        assert!(statistics.contains(BLOCK_CACHE_HIT));
+        assert!(statistics.contains(BLOCK_CACHE_MISS));
+        record_block_cache(&database).unwrap();
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::helpers::{
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{AccessMode, MapID, RocksDB, TestMap as TestMapID},
     Map,
     MapRead,
 };
@@ -114,6 +114,40 @@ fn test_insert_and_keys() {
     assert_eq!(None, keys.next());
 }
 
+#[test]
+#[serial]
+fn test_secondary_and_read_only() {
+    // Open the primary instance, and insert an initial entry.
+    let primary = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+    let primary_map = RocksDB::open_map_testing_in(primary.clone(), MapID::Test(TestMapID::Test));
+    primary_map.insert(1u32, "1".to_string()).expect("Failed to insert");
+
+    // Open a secondary and a read-only instance of the same database.
+    let secondary = RocksDB::open_testing_instance(&primary, AccessMode::Secondary).expect("Failed to open secondary");
+    let secondary_map = RocksDB::open_map_testing_in::<u32, String, _>(secondary.clone(), MapID::Test(TestMapID::Test));
+    let read_only = RocksDB::open_testing_instance(&primary, AccessMode::ReadOnly).expect("Failed to open read-only");
+    let read_only_map = RocksDB::open_map_testing_in::<u32, String, _>(read_only.clone(), MapID::Test(TestMapID::Test));
+
+    // Both instances see the initial entry.
+    assert_eq!(secondary_map.get_confirmed(&1).unwrap().as_deref(), Some(&"1".to_string()));
+    assert_eq!(read_only_map.get_confirmed(&1).unwrap().as_deref(), Some(&"1".to_string()));
+
+    // A new write of the primary instance is only seen by the secondary instance once it catches up.
+    primary_map.insert(2u32, "2".to_string()).expect("Failed to insert");
+    assert!(secondary_map.get_confirmed(&2).unwrap().is_none());
+    secondary.try_catch_up_with_primary().expect("Failed to catch up");
+    assert_eq!(secondary_map.get_confirmed(&2).unwrap().as_deref(), Some(&"2".to_string()));
+
+    // The read-only instance remains as of the moment it was opened, and can't catch up.
+    assert!(read_only_map.get_confirmed(&2).unwrap().is_none());
+    assert!(read_only.try_catch_up_with_primary().is_err());
+
+    // Neither instance accepts writes.
+    assert!(secondary_map.insert(3, "3".to_string()).is_err());
+    assert!(read_only_map.insert(3, "3".to_string()).is_err());
+    assert!(primary_map.get_confirmed(&3).unwrap().is_none());
+}
+
 #[test]
 #[serial]
 fn test_insert_and_values() {
@@ -18,11 +18,16 @@ use crate::{
     helpers::rocksdb::{self, CommitteeMap, DataMap, Database, MapID, NestedDataMap, ProgramMap},
     CommitteeStorage,
     CommitteeStore,
+    FinalizeHistory,
+    FinalizeRollback,
     FinalizeStorage,
+    FinalizeTreeNode,
+    FinalizeTreeNodeID,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -38,6 +43,16 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The finalize tree map.
+    tree_map: DataMap<FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>,
+    /// The history map.
+    history_map: NestedDataMap<Field<N>, u32, Option<Value<N>>>,
+    /// The history config map.
+    history_config_map: DataMap<u8, FinalizeHistory>,
+    /// The rollback map.
+    rollback_map: DataMap<u32, FinalizeRollback<N>>,
+    /// The checksum map.
+    checksum_map: DataMap<u32, Field<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +62,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type TreeMap = DataMap<FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>;
+    type HistoryMap = NestedDataMap<Field<N>, u32, Option<Value<N>>>;
+    type HistoryConfigMap = DataMap<u8, FinalizeHistory>;
+    type RollbackMap = DataMap<u32, FinalizeRollback<N>>;
+    type ChecksumMap = DataMap<u32, Field<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +77,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            tree_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Tree))?,
+            history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            history_config_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryConfig))?,
+            rollback_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Rollback))?,
+            checksum_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Checksum))?,
             storage_mode: storage.into(),
         })
     }
@@ -70,7 +95,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            tree_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Tree))?,
+            history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            history_config_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryConfig))?,
+            rollback_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Rollback))?,
+            checksum_map: rocksdb::RocksDB::open_map_testing(temp_dir, dev, MapID::Program(ProgramMap::Checksum))?,
             storage_mode: dev.into(),
         })
     }
@@ -90,6 +120,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the finalize tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history config map.
+    fn history_config_map(&self) -> &Self::HistoryConfigMap {
+        &self.history_config_map
+    }
+
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the checksum map.
+    fn checksum_map(&self) -> &Self::ChecksumMap {
+        &self.checksum_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
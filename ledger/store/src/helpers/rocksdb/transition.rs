@@ -13,11 +13,21 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::rocksdb::{
+        self,
+        DataMap,
+        Database,
+        MapID,
+        NestedDataMap,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
     OutputStore,
+    TransitionPosition,
     TransitionStorage,
 };
 use console::{
@@ -30,6 +40,7 @@ use aleo_std_storage::StorageMode;
 
 /// A database transition storage.
 #[derive(Clone)]
+#[allow(clippy::type_complexity)]
 pub struct TransitionDB<N: Network> {
     /// The transition program IDs and function names.
     locator_map: DataMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>,
@@ -47,6 +58,8 @@ pub struct TransitionDB<N: Network> {
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: DataMap<N::TransitionID, Field<N>>,
+    /// The function index map.
+    function_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>,
 }
 
 #[rustfmt::skip]
@@ -59,6 +72,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
     type SCMMap = DataMap<N::TransitionID, Field<N>>;
+    type FunctionIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -71,6 +85,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(),  MapID::Transition(TransitionMap::ReverseTCM))?,
             scm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::SCM))?,
+            function_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::FunctionIndex))?,
         })
     }
 
@@ -113,6 +128,11 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
 }
 
 /// An database transition input storage.
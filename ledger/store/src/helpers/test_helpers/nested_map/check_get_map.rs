@@ -121,10 +121,44 @@ fn check_get_same_map(map: &impl for<'a> NestedMap<'a, usize, usize, String>) {
     ensure_map_is_empty(map);
 }
 
+fn check_get_map_range(map: &impl for<'a> NestedMap<'a, usize, usize, String>) {
+    ensure_map_is_empty(map);
+
+    const MAP: usize = 0;
+
+    assert_eq!(map.get_map_range_confirmed(&MAP, &0, NUM_ITEMS).unwrap(), Vec::new());
+
+    // Insert the items into the map, and an item into another map.
+    for i in 0..NUM_ITEMS {
+        map.insert(MAP, i, i.to_string()).unwrap();
+    }
+    map.insert(MAP + 1, 0, 0.to_string()).unwrap();
+    let expected: Vec<_> = (0..NUM_ITEMS).map(|i| (i, i.to_string())).collect();
+
+    // Ensure the range starts from the given key, and is bounded by the limit and the end of the map.
+    assert_eq!(map.get_map_range_confirmed(&MAP, &0, NUM_TOTAL_ITEMS).unwrap(), expected);
+    assert_eq!(map.get_map_range_confirmed(&MAP, &2, 3).unwrap(), expected[2..5]);
+    assert_eq!(map.get_map_range_confirmed(&MAP, &(NUM_ITEMS - 1), 3).unwrap(), expected[NUM_ITEMS - 1..]);
+    assert_eq!(map.get_map_range_confirmed(&MAP, &NUM_ITEMS, 3).unwrap(), Vec::new());
+    assert_eq!(map.get_map_range_confirmed(&MAP, &0, 0).unwrap(), Vec::new());
+
+    // Ensure a missing start key starts the range from the next key.
+    map.remove_key(&MAP, &2).unwrap();
+    assert_eq!(map.get_map_range_confirmed(&MAP, &2, 2).unwrap(), expected[3..5]);
+
+    map.remove_map(&MAP).unwrap();
+    map.remove_map(&(MAP + 1)).unwrap();
+
+    ensure_map_is_empty(map);
+}
+
 pub fn check_get_map(map: impl for<'a> NestedMap<'a, usize, usize, String>) {
     println!("Checking get unique maps");
     check_get_unique_maps(&map);
 
     println!("Checking get same map");
     check_get_same_map(&map);
+
+    println!("Checking get map range");
+    check_get_map_range(&map);
 }
@@ -122,6 +122,12 @@ pub trait NestedMapRead<
     ///
     fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>>;
 
+    ///
+    /// Returns at most `limit` confirmed key-value pairs for the given map, starting from the given key.
+    /// The pairs are in the order of their serialized keys.
+    ///
+    fn get_map_range_confirmed(&'a self, map: &M, start_key: &K, limit: usize) -> Result<Vec<(K, V)>>;
+
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
@@ -17,6 +17,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead},
+    IntegrityReport,
 };
 use console::network::prelude::*;
 use ledger_committee::Committee;
@@ -25,7 +26,7 @@ use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
 
-const ROUND_KEY: u8 = 0;
+pub(crate) const ROUND_KEY: u8 = 0;
 
 /// A trait for committee storage.
 pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
@@ -166,12 +167,22 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     fn remove(&self, height: u32) -> Result<()> {
+        // Note: The reads are speculative, so that the committees of consecutive blocks can be removed
+        // in a single atomic batch.
+        let get_height_for_round = |round: u64| -> Result<Option<u32>> {
+            Ok(self.round_to_height_map().get_speculative(&round)?.map(|height| cow_to_copied!(height)))
+        };
         // Retrieve the current round.
-        let current_round = self.current_round()?;
+        let Some(current_round) = self.current_round_map().get_speculative(&ROUND_KEY)? else {
+            bail!("Current round not found in committee storage")
+        };
+        let current_round = cow_to_copied!(current_round);
         // Retrieve the current height.
-        let current_height = self.current_height()?;
+        let Some(current_height) = get_height_for_round(current_round)? else {
+            bail!("Current height not found in committee storage")
+        };
         // Retrieve the committee for the given height.
-        let Some(committee) = self.get_committee(height)? else {
+        let Some(committee) = self.committee_map().get_speculative(&height)? else {
             bail!("Committee not found for height {height} in committee storage");
         };
         // Retrieve the round for the given height.
@@ -183,7 +194,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the earliest round to be removed (inclusive).
         let mut earliest_round = committee_round;
-        while earliest_round > 0 && self.get_height_for_round(earliest_round)? == Some(height) {
+        while earliest_round > 0 && get_height_for_round(earliest_round)? == Some(height) {
             earliest_round = earliest_round.saturating_sub(1);
         }
         let is_multiple = earliest_round != committee_round;
@@ -193,7 +204,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the latest round to be removed (exclusive).
         let mut latest_round = committee_round;
-        while self.get_height_for_round(latest_round)? == Some(height) {
+        while get_height_for_round(latest_round)? == Some(height) {
             latest_round = latest_round.saturating_add(1);
         }
 
@@ -202,7 +213,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         if is_latest_committee {
             while next_current_round > 0 {
                 // If the next current height is less than the current height, then we have found the next current round.
-                if let Some(next_current_height) = self.get_height_for_round(next_current_round)? {
+                if let Some(next_current_height) = get_height_for_round(next_current_round)? {
                     if next_current_height < current_height {
                         break;
                     }
@@ -235,6 +246,54 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Checks the committees against the given `latest height`, and the round-to-height index and
+    /// current round against the committees.
+    /// If `repair` is `true`, the committees past the latest height are removed,
+    /// and the inconsistent rounds are rewritten from the committees.
+    fn check_integrity(&self, report: &mut IntegrityReport, latest_height: Option<u32>, repair: bool) -> Result<()> {
+        // Ensure each committee belongs to a block.
+        report.check_index(
+            "Committee(Committee)",
+            self.committee_map(),
+            [],
+            |height, _| Ok(latest_height.is_some_and(|latest_height| *height <= latest_height)),
+            repair,
+        )?;
+
+        // Retrieve the starting round of the committee at each height.
+        let mut starting_rounds = Vec::new();
+        if let Some(latest_height) = latest_height {
+            for height in 0..=latest_height {
+                match self.committee_map().get_confirmed(&height)? {
+                    Some(committee) => starting_rounds.push((height, committee.starting_round())),
+                    None => {
+                        let message = format!("Missing committee for height {height}");
+                        report.add_issue("Committee(Committee)", message, false);
+                    }
+                }
+            }
+        }
+
+        // Check the round-to-height index.
+        // Note: Each round up to the starting round of the next committee maps to the height of the committee.
+        let mut expected = Vec::new();
+        for (index, (height, starting_round)) in starting_rounds.iter().enumerate() {
+            let end_round = starting_rounds.get(index + 1).map_or(*starting_round + 1, |(_, round)| *round);
+            expected.extend((*starting_round..end_round).map(|round| (round, *height)));
+        }
+        report.check_index(
+            "Committee(RoundToHeight)",
+            self.round_to_height_map(),
+            expected,
+            |_, _| Ok(false),
+            repair,
+        )?;
+
+        // Check the current round.
+        let expected = starting_rounds.last().map(|(_, starting_round)| (ROUND_KEY, *starting_round));
+        report.check_index("Committee(CurrentRound)", self.current_round_map(), expected, |_, _| Ok(false), repair)
+    }
+
     /// Returns the current round.
     fn current_round(&self) -> Result<u64> {
         match self.current_round_map().get_confirmed(&ROUND_KEY)? {
@@ -360,6 +419,11 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Returns the committee storage.
+    pub(crate) const fn storage(&self) -> &C {
+        &self.storage
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
@@ -374,6 +438,17 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn remove(&self, height: u32) -> Result<()> {
         self.storage.remove(height)
     }
+    /// Checks the committees against the given `latest height`, and the round-to-height index and
+    /// current round against the committees.
+    /// If `repair` is `true`, the inconsistent entries are repaired.
+    pub(crate) fn check_integrity(
+        &self,
+        report: &mut IntegrityReport,
+        latest_height: Option<u32>,
+        repair: bool,
+    ) -> Result<()> {
+        self.storage.check_integrity(report, latest_height, repair)
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
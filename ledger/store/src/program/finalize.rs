@@ -16,8 +16,22 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead, NestedMap, NestedMapRead},
-    program::{CommitteeStorage, CommitteeStore},
+    helpers::{memory::MemoryMap, Map, MapRead, NestedMap, NestedMapRead},
+    program::{
+        export_snapshot,
+        import_snapshot,
+        CommitteeStorage,
+        CommitteeStore,
+        FinalizeHistory,
+        FinalizeProof,
+        FinalizeRollback,
+        FinalizeSnapshotHeader,
+        FinalizeTree,
+        FinalizeTreeNode,
+        FinalizeTreeNodeID,
+        MAX_ROLLBACK_DEPTH,
+    },
+    IntegrityReport,
 };
 use console::{
     network::prelude::*,
@@ -29,7 +43,13 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The key of the finalize history configuration in the history config map.
+const HISTORY_KEY: u8 = 0;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -60,11 +80,39 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the value ID for the given `key ID` and `value`.
+fn to_value_id<N: Network>(key_id: &Field<N>, value: &Value<N>) -> Result<Field<N>> {
+    // Compute the value ID.
+    N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())
+}
+
+/// Returns the leaves of the finalize tree, as a list of (`key ID`, `value ID`), for the given key-value entries.
+#[allow(clippy::type_complexity)]
+pub(crate) fn to_leaves<N: Network>(
+    entries: Vec<((ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>)>,
+) -> Result<Vec<(Field<N>, Option<Field<N>>)>> {
+    cfg_into_iter!(entries)
+        .map(|((program_id, mapping_name), key, value)| {
+            let key_id = to_key_id(&program_id, &mapping_name, &key)?;
+            Ok((key_id, Some(to_value_id(&key_id, &value)?)))
+        })
+        .collect()
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
 /// and the `value ID := Hash ( key ID || Hash(value) )`.
 ///
+/// The key-value pairs are authenticated by the finalize tree, a sparse Merkle tree
+/// that is keyed by the `key ID`, with leaves of (`key ID`, `value ID`).
+/// The finalize tree is updated with the pending key-value entries when an atomic batch is finished.
+///
+/// If the finalize history is enabled, the changes to the key-value entries are also recorded by `key ID`
+/// and block height, which allows the value of a key to be retrieved at a past block height.
+///
+/// The reverse of the changes of each block is recorded by block height, which allows the block to be rolled back.
+///
 /// `FinalizeStorage` emulates the following data structure:
 /// ```text
 /// // (program_id => (mapping_name => (key => value)))
@@ -77,6 +125,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(depth, prefix)` to `finalize tree node`.
+    type TreeMap: for<'a> Map<'a, FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>;
+    /// The mapping of `key ID` to `[(block height, value)]`, where a value of `None` denotes a removed key.
+    type HistoryMap: for<'a> NestedMap<'a, Field<N>, u32, Option<Value<N>>>;
+    /// The mapping of `history key` to `finalize history configuration`.
+    type HistoryConfigMap: for<'a> Map<'a, u8, FinalizeHistory>;
+    /// The mapping of `block height` to `finalize rollback`.
+    type RollbackMap: for<'a> Map<'a, u32, FinalizeRollback<N>>;
+    /// The mapping of `block height` to the finalize checksum after the block.
+    type ChecksumMap: for<'a> Map<'a, u32, Field<N>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -91,6 +149,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the finalize tree map.
+    fn tree_map(&self) -> &Self::TreeMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the history config map.
+    fn history_config_map(&self) -> &Self::HistoryConfigMap;
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap;
+    /// Returns the checksum map.
+    fn checksum_map(&self) -> &Self::ChecksumMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -100,6 +168,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.tree_map().start_atomic();
+        self.history_map().start_atomic();
+        self.history_config_map().start_atomic();
+        self.rollback_map().start_atomic();
+        self.checksum_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -107,6 +180,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.tree_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.history_config_map().is_atomic_in_progress()
+            || self.rollback_map().is_atomic_in_progress()
+            || self.checksum_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +192,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.tree_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.history_config_map().atomic_checkpoint();
+        self.rollback_map().atomic_checkpoint();
+        self.checksum_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -121,6 +204,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.tree_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.history_config_map().clear_latest_checkpoint();
+        self.rollback_map().clear_latest_checkpoint();
+        self.checksum_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +216,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.tree_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.history_config_map().atomic_rewind();
+        self.rollback_map().atomic_rewind();
+        self.checksum_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -135,13 +228,41 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.tree_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.history_config_map().abort_atomic();
+        self.rollback_map().abort_atomic();
+        self.checksum_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
+        // Update the finalize tree with the pending key-value entries.
+        let mut tree = FinalizeTree::new(self.tree_map());
+        tree.update(self.get_pending_leaves()?)?;
+        // Store the finalize checksum after the block whose rollback was recorded in the batch, if any.
+        let recorded_heights: Vec<_> = self
+            .rollback_map()
+            .iter_pending()
+            .filter(|(_, rollback)| rollback.is_some())
+            .map(|(height, _)| cow_to_copied!(height))
+            .collect();
+        if !recorded_heights.is_empty() {
+            let checksum = tree.root()?;
+            for height in recorded_heights {
+                self.checksum_map().insert(height, checksum)?;
+            }
+        }
+        tree.commit()?;
+
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.tree_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.history_config_map().finish_atomic()?;
+        self.rollback_map().finish_atomic()?;
+        self.checksum_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -202,7 +323,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -234,7 +355,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -355,6 +476,185 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Enables the finalize history, retaining the given number of blocks of history, or the full history if `None`.
+    /// If the finalize history is already enabled, the retention window is updated.
+    ///
+    /// Note: The history starts at the first block that is recorded after the finalize history is enabled.
+    fn enable_history(&self, retention: Option<u32>) -> Result<()> {
+        // Retrieve the recorded block heights, if any.
+        let heights = match self.history_config_map().get_speculative(&HISTORY_KEY)? {
+            Some(config) => config.start_height().zip(config.latest_height()),
+            None => None,
+        };
+
+        atomic_batch_scope!(self, {
+            // Update the finalize history configuration.
+            self.history_config_map().insert(HISTORY_KEY, FinalizeHistory::new(retention, heights))?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the pending key-value changes as the changes at the given block height,
+    /// and prunes the changes that are no longer in the retention window.
+    /// If the finalize history is not enabled, this method does nothing.
+    ///
+    /// Note: This method must be called in the atomic batch that finalizes the block.
+    fn record_history(&self, block_height: u32) -> Result<()> {
+        // Retrieve the finalize history configuration.
+        let config = match self.history_config_map().get_speculative(&HISTORY_KEY)? {
+            Some(config) => cow_to_copied!(config),
+            None => return Ok(()),
+        };
+        // Ensure the block height follows the recorded history.
+        if let Some(latest_height) = config.latest_height() {
+            ensure!(block_height > latest_height, "Block {block_height} is already in the finalize history");
+        }
+        // Compute the start height of the retention window.
+        let start_height = config.next_start_height(block_height);
+        // Retrieve the pending key-value changes.
+        let changes = self.get_pending_changes()?;
+
+        atomic_batch_scope!(self, {
+            for ((program_id, mapping_name), key, value) in changes {
+                // Compute the key ID.
+                let key_id = to_key_id(&program_id, &mapping_name, &key)?;
+                // Retrieve the recorded history of the key.
+                let mut history = self.history_map().get_map_speculative(&key_id)?;
+                history.sort_unstable_by_key(|(height, _)| *height);
+
+                // If the key has no recorded changes, record its value at the start of the retention window.
+                if history.is_empty() && start_height < block_height {
+                    if let Some(previous) = self.get_value_confirmed(program_id, mapping_name, &key)? {
+                        self.history_map().insert(key_id, start_height, Some(previous))?;
+                    }
+                }
+                // Remove the changes before the retention window, except for the latest one,
+                // which holds the value of the key at the start of the retention window.
+                if let Some(index) = history.iter().rposition(|(height, _)| *height <= start_height) {
+                    for (height, _) in &history[..index] {
+                        self.history_map().remove_key(&key_id, height)?;
+                    }
+                }
+                // Record the change at the block height.
+                self.history_map().insert(key_id, block_height, value)?;
+            }
+            // Update the recorded block heights.
+            self.history_config_map()
+                .insert(HISTORY_KEY, FinalizeHistory::new(config.retention(), Some((start_height, block_height))))?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the reverse of the pending finalize state changes, to roll back the block at the given height.
+    /// The finalize checksum after the block is stored when the atomic batch is finished.
+    /// The rollbacks and checksums of the blocks older than `MAX_ROLLBACK_DEPTH` are removed.
+    ///
+    /// Note: This method must be called in the atomic batch that finalizes the block, after its last change.
+    fn record_rollback(&self, block_height: u32) -> Result<()> {
+        // Ensure no other block is recorded in the atomic batch, as the batch stores a single checksum.
+        if self.rollback_map().iter_pending().any(|(_, rollback)| rollback.is_some()) {
+            bail!("Cannot record the rollback of block {block_height} - another block was recorded in the batch")
+        }
+        // Retrieve the previous mapping names of the pending program changes.
+        let mut programs = IndexMap::new();
+        for (program_id, _) in self.program_id_map().iter_pending() {
+            let program_id = cow_to_copied!(program_id);
+            if !programs.contains_key(&program_id) {
+                let mapping_names =
+                    self.program_id_map().get_confirmed(&program_id)?.map(|names| cow_to_cloned!(names));
+                programs.insert(program_id, mapping_names);
+            }
+        }
+        // Retrieve the previous values of the pending key-value changes.
+        let entries = self
+            .get_pending_changes()?
+            .into_iter()
+            .map(|((program_id, mapping_name), key, _)| {
+                let value = self.get_value_confirmed(program_id, mapping_name, &key)?;
+                Ok((program_id, mapping_name, key, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_batch_scope!(self, {
+            // Store the finalize rollback for the block.
+            self.rollback_map().insert(block_height, FinalizeRollback::new(programs.into_iter().collect(), entries))?;
+            // Remove the finalize rollback and checksum of the block that is now past the rollback depth.
+            if let Some(expired_height) = block_height.checked_sub(MAX_ROLLBACK_DEPTH) {
+                self.rollback_map().remove(&expired_height)?;
+                self.checksum_map().remove(&expired_height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Rolls back the finalize state changes of the block at the given height, which must be the latest recorded block.
+    /// This restores the mappings, key-value entries, and committee to their state before the block,
+    /// and removes the block from the finalize history, if it is enabled.
+    ///
+    /// Note: Changes that were pruned from the finalize history by the block are not restored.
+    fn rollback_block(&self, block_height: u32) -> Result<()> {
+        // Note: The reads are speculative, so that consecutive blocks can be rolled back in a single atomic batch.
+        // Ensure the block is the latest recorded block.
+        if self.rollback_map().contains_key_speculative(&block_height.saturating_add(1))? {
+            bail!("Cannot roll back block {block_height} - it is not the latest block")
+        }
+        // Retrieve the finalize rollback for the block.
+        let Some(rollback) = self.rollback_map().get_speculative(&block_height)? else {
+            bail!(
+                "Cannot roll back block {block_height} - no finalize rollback was recorded for it \
+                 (only the latest {MAX_ROLLBACK_DEPTH} blocks, finalized since rollbacks were recorded, can be rolled back)"
+            )
+        };
+        let rollback = cow_to_cloned!(rollback);
+        // Retrieve the finalize history configuration, if the block is in the recorded history.
+        let history = self
+            .history_config_map()
+            .get_speculative(&HISTORY_KEY)?
+            .map(|config| cow_to_copied!(config))
+            .filter(|config| config.latest_height() == Some(block_height));
+
+        atomic_batch_scope!(self, {
+            // Restore the previous mapping names of the programs.
+            for (program_id, mapping_names) in rollback.programs() {
+                match mapping_names {
+                    Some(mapping_names) => self.program_id_map().insert(*program_id, mapping_names.clone())?,
+                    None => self.program_id_map().remove(program_id)?,
+                }
+            }
+            // Restore the previous values of the keys.
+            for (program_id, mapping_name, key, value) in rollback.entries() {
+                match value {
+                    Some(value) => {
+                        self.key_value_map().insert((*program_id, *mapping_name), key.clone(), value.clone())?
+                    }
+                    None => self.key_value_map().remove_key(&(*program_id, *mapping_name), key)?,
+                }
+            }
+            // Remove the committee for the block, if one was stored.
+            if self.committee_store().storage().committee_map().contains_key_speculative(&block_height)? {
+                self.committee_store().remove(block_height)?;
+            }
+            // Remove the block from the finalize history.
+            if let Some(config) = history {
+                for (program_id, mapping_name, key, _) in rollback.entries() {
+                    self.history_map().remove_key(&to_key_id(program_id, mapping_name, key)?, &block_height)?;
+                }
+                // Update the recorded block heights.
+                let heights = config.start_height().filter(|start_height| *start_height < block_height);
+                let heights = heights.map(|start_height| (start_height, block_height - 1));
+                self.history_config_map().insert(HISTORY_KEY, FinalizeHistory::new(config.retention(), heights))?;
+            }
+            // Remove the finalize rollback and checksum for the block.
+            self.rollback_map().remove(&block_height)?;
+            self.checksum_map().remove(&block_height)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -454,72 +754,250 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
-    /// Returns the confirmed checksum of the finalize storage.
-    fn get_checksum_confirmed(&self) -> Result<Field<N>> {
-        // Compute all mapping checksums.
-        let preimage: std::collections::BTreeMap<_, _> = self
+    /// Returns the finalize history configuration, if the finalize history is enabled.
+    fn get_history_config(&self) -> Result<Option<FinalizeHistory>> {
+        Ok(self.history_config_map().get_confirmed(&HISTORY_KEY)?.map(|config| cow_to_copied!(config)))
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given block height.
+    /// If the finalize history is not enabled, or the block height is not in the recorded history, an error is returned.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the finalize history configuration.
+        let Some(config) = self.get_history_config()? else {
+            bail!("Cannot retrieve '{program_id}/{mapping_name}' at block {block_height} - the history is not enabled")
+        };
+        // Ensure the block height is in the recorded history.
+        if !config.contains_height(block_height) {
+            bail!("Cannot retrieve '{program_id}/{mapping_name}' at block {block_height} - the block is not in the history")
+        }
+        // Retrieve the recorded history of the key.
+        let history = self.history_map().get_map_confirmed(&to_key_id(&program_id, &mapping_name, key)?)?;
+        // If the key has no recorded changes, it has not changed since the start of the history.
+        if history.is_empty() {
+            return self.get_value_confirmed(program_id, mapping_name, key);
+        }
+        // Return the value from the latest change at or before the block height.
+        Ok(history
+            .into_iter()
+            .filter(|(height, _)| *height <= block_height)
+            .max_by_key(|(height, _)| *height)
+            .and_then(|(_, value)| value))
+    }
+
+    /// Returns the recorded changes for the given `program ID`, `mapping name`, and `key`,
+    /// as a list of (`block height`, `value`) in increasing order of block height,
+    /// where a value of `None` denotes that the key was removed at that block height.
+    ///
+    /// Note: If the key existed at the start of the recorded history, the first entry holds its value at the start.
+    fn get_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Vec<(u32, Option<Value<N>>)>> {
+        // Ensure the finalize history is enabled.
+        if self.get_history_config()?.is_none() {
+            bail!("Cannot retrieve the history of '{program_id}/{mapping_name}' - the history is not enabled")
+        }
+        // Retrieve the recorded history of the key.
+        let mut history = self.history_map().get_map_confirmed(&to_key_id(&program_id, &mapping_name, key)?)?;
+        history.sort_unstable_by_key(|(height, _)| *height);
+        Ok(history)
+    }
+
+    /// Builds the finalize tree from the key-value entries, if the tree is missing,
+    /// i.e. if the key-value entries were written before the finalize tree was maintained.
+    fn initialize_tree(&self) -> Result<()> {
+        // Note: A finalize tree over key-value entries has at least one node, so an empty tree map is missing.
+        if self.tree_map().keys_confirmed().next().is_some() || self.key_value_map().keys_confirmed().next().is_none() {
+            return Ok(());
+        }
+        // Compute the leaves of the finalize tree from the key-value entries.
+        let entries: Vec<_> = self
             .key_value_map()
             .iter_confirmed()
-            .map(|(m, k, v)| {
-                let m = cow_to_copied!(m);
-                let k = cow_to_cloned!(k);
-                let v = cow_to_cloned!(v);
-
-                let mut preimage = Vec::new();
-                m.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-                k.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the mapping checksum as `Hash( m || k )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage)?;
-
-                v.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the entry checksum as `Hash( m || k || v )`.
-                let entry_checksum = N::hash_bhp1024(&preimage)?;
-                // Return the mapping checksum and entry checksum.
-                Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
-            })
-            .try_collect()?;
-        // Compute the checksum as `Hash( all mapping checksums )`.
-        N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+            .map(|(mapping, key, value)| (cow_to_copied!(mapping), cow_to_cloned!(key), cow_to_cloned!(value)))
+            .collect();
+        let leaves = to_leaves(entries)?;
+
+        atomic_batch_scope!(self, {
+            // Build the finalize tree.
+            let mut tree = FinalizeTree::new(self.tree_map());
+            tree.update(leaves)?;
+            tree.commit()?;
+
+            Ok(())
+        })
     }
 
-    /// Returns the pending checksum of the finalize storage.
-    fn get_checksum_pending(&self) -> Result<Field<N>> {
-        // Compute all mapping checksums.
-        let preimage: std::collections::BTreeMap<_, _> = self
+    /// Returns the confirmed value, with a proof of its inclusion (or exclusion) in the finalize tree,
+    /// for the given `program ID`, `mapping name`, and `key`.
+    fn get_value_with_proof(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<(Option<Value<N>>, FinalizeProof<N>)> {
+        // Retrieve the value.
+        let value = self.get_value_confirmed(program_id, mapping_name, key)?;
+        // Compute the proof.
+        let proof = FinalizeTree::new(self.tree_map()).prove(&to_key_id(&program_id, &mapping_name, key)?)?;
+        Ok((value, proof))
+    }
+
+    /// Checks that each mapping is initialized, checks the finalize tree against the key-value entries,
+    /// and checks the finalize checksum, rollbacks, history, and committees against the given `latest height`.
+    /// If `repair` is `true`, the finalize tree is rebuilt from the key-value entries,
+    /// and the entries recorded past the latest height are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, latest_height: Option<u32>, repair: bool) -> Result<()> {
+        // Retrieve the key-value entries.
+        let entries: Vec<_> = self
             .key_value_map()
-            .iter_pending()
-            .map(|(m, k, v)| {
-                let m = cow_to_copied!(m);
-
-                let mut preimage = Vec::new();
-                m.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-                if let Some(k) = k {
-                    cow_to_cloned!(k).write_bits_le(&mut preimage);
+            .iter_confirmed()
+            .map(|(mapping, key, value)| (cow_to_copied!(mapping), cow_to_cloned!(key), cow_to_cloned!(value)))
+            .collect();
+
+        // Ensure each mapping of the key-value entries is initialized.
+        let mappings: IndexSet<_> = entries.iter().map(|(mapping, _, _)| *mapping).collect();
+        for (program_id, mapping_name) in &mappings {
+            if !self.contains_mapping_confirmed(program_id, mapping_name)? {
+                let message = format!("Mapping '{program_id}/{mapping_name}' is not initialized");
+                report.add_issue("Program(ProgramID)", message, false);
+            }
+        }
+
+        // Compute the leaves of the finalize tree from the key-value entries.
+        let leaves = to_leaves(entries)?;
+        report.add_checked("Program(KeyValueID)", leaves.len());
+        report.add_checked("Program(ProgramID)", self.program_id_map().len_confirmed());
+
+        // Compute the expected finalize tree.
+        // Note: The finalize tree is canonical, so its nodes are determined by its leaves.
+        let expected = MemoryMap::default();
+        let mut tree = FinalizeTree::new(&expected);
+        tree.update(leaves)?;
+        tree.commit()?;
+
+        // Check the finalize tree.
+        report.check_index(
+            "Program(Tree)",
+            self.tree_map(),
+            expected.iter_confirmed().map(|(node_id, node)| (*node_id, *node)),
+            |node_id, node| Ok(expected.get_confirmed(node_id)?.is_some_and(|n| *n == *node)),
+            repair,
+        )?;
+
+        // Ensure each checksum and rollback belongs to a block.
+        let is_block = |height: &u32| latest_height.is_some_and(|latest_height| *height <= latest_height);
+        report.check_index("Program(Checksum)", self.checksum_map(), [], |height, _| Ok(is_block(height)), repair)?;
+        report.check_index("Program(Rollback)", self.rollback_map(), [], |height, _| Ok(is_block(height)), repair)?;
+
+        // Check the finalize checksum after the latest block against the key-value entries.
+        // Note: The checksum is not recorded for blocks that were finalized before checksums were recorded.
+        // Note: The finalize root of a block header commits to the finalize operations of the block, including
+        // the ratified ones, which are not stored, so the checksum cannot be checked against the headers.
+        if let Some(latest_height) = latest_height {
+            report.add_local_only("Program(Checksum)");
+            let checksum = FinalizeTree::new(&expected).root()?;
+            match self.checksum_map().get_confirmed(&latest_height)? {
+                Some(candidate) if *candidate == checksum => (),
+                Some(candidate) => {
+                    let message = format!("Checksum of block {latest_height} is {}, expected {checksum}", *candidate);
+                    report.add_issue("Program(Checksum)", message, false);
                 }
-                false.write_bits_le(&mut preimage); // Separator.
+                None if self.rollback_map().contains_key_confirmed(&latest_height)? => {
+                    report.add_issue("Program(Checksum)", format!("Missing checksum of block {latest_height}"), false);
+                }
+                None => (),
+            }
+        }
 
-                // Compute the mapping checksum as `Hash( m || k )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage)?;
+        // Check the finalize history against the latest block.
+        let config = self.history_config_map().get_confirmed(&HISTORY_KEY)?.map(|config| cow_to_copied!(config));
+        let history_height = config.and_then(|config| config.latest_height());
+        if let Some(history_height) = history_height {
+            if !is_block(&history_height) {
+                let message =
+                    format!("Finalize history is recorded up to block {history_height}, past the latest block");
+                report.add_issue("Program(HistoryConfig)", message, false);
+            }
+        }
+        report.add_checked("Program(HistoryConfig)", usize::from(config.is_some()));
+        // Note: The change at or before the start of the retention window is kept, so only the latest height is checked.
+        report.check_nested_index(
+            "Program(History)",
+            self.history_map(),
+            [],
+            |_, height, _| Ok(history_height.is_some_and(|history_height| *height <= history_height)),
+            repair,
+        )?;
 
-                if let Some(v) = v {
-                    cow_to_cloned!(v).write_bits_le(&mut preimage);
-                }
-                false.write_bits_le(&mut preimage); // Separator.
+        // Check the committees against the latest block.
+        self.committee_store().check_integrity(report, latest_height, repair)
+    }
 
-                // Compute the entry checksum as `Hash( m || k || v )`.
-                let entry_checksum = N::hash_bhp1024(&preimage)?;
-                // Return the mapping checksum and entry checksum.
-                Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
+    /// Returns the confirmed checksum of the finalize storage, as the root of the finalize tree.
+    fn get_checksum_confirmed(&self) -> Result<Field<N>> {
+        FinalizeTree::new(self.tree_map()).root()
+    }
+
+    /// Returns the pending checksum of the finalize storage, as the root of the finalize tree
+    /// after the pending key-value entries are applied.
+    fn get_checksum_pending(&self) -> Result<Field<N>> {
+        let mut tree = FinalizeTree::new(self.tree_map());
+        tree.update(self.get_pending_leaves()?)?;
+        tree.root()
+    }
+
+    /// Returns the pending changes to the finalize tree, as a list of (`key ID`, `value ID`),
+    /// where a `value ID` of `None` denotes a removed key-value entry.
+    #[allow(clippy::type_complexity)]
+    fn get_pending_leaves(&self) -> Result<Vec<(Field<N>, Option<Field<N>>)>> {
+        // Compute the key ID and value ID for each change.
+        cfg_into_iter!(self.get_pending_changes()?)
+            .map(|((program_id, mapping_name), key, value)| {
+                let key_id = to_key_id(&program_id, &mapping_name, &key)?;
+                let value_id = value.map(|value| to_value_id(&key_id, &value)).transpose()?;
+                Ok((key_id, value_id))
             })
-            .try_collect()?;
-        // Compute the checksum as `Hash( all mapping checksums )`.
-        N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Returns the pending changes to the key-value entries, as a list of (`(program ID, mapping name)`, `key`, `value`),
+    /// where a `value` of `None` denotes a removed key-value entry.
+    #[allow(clippy::type_complexity)]
+    fn get_pending_changes(&self) -> Result<Vec<((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>)>> {
+        // Collect the latest pending value for each key, and the mappings that were removed or replaced.
+        // Note: The keys are indexed by their bytes, as (`mapping`, `key bytes`).
+        let mut removed_mappings = IndexSet::new();
+        let mut changes = IndexMap::new();
+        for (mapping, key, value) in self.key_value_map().iter_pending() {
+            let mapping = cow_to_copied!(mapping);
+            match key {
+                Some(key) => {
+                    let key = cow_to_cloned!(key);
+                    changes.insert((mapping, key.to_bytes_le()?), (key, value.map(|value| cow_to_cloned!(value))));
+                }
+                None => {
+                    // Remove the pending entries of the mapping.
+                    changes.iter_mut().filter(|((m, _), _)| *m == mapping).for_each(|(_, (_, value))| *value = None);
+                    removed_mappings.insert(mapping);
+                }
+            }
+        }
+        // Remove the confirmed entries of the removed mappings, unless they were inserted again.
+        for mapping in removed_mappings {
+            for (key, _) in self.key_value_map().get_map_confirmed(&mapping)? {
+                changes.entry((mapping, key.to_bytes_le()?)).or_insert((key, None));
+            }
+        }
+        Ok(changes.into_iter().map(|((mapping, _), (key, value))| (mapping, key, value)).collect())
     }
 }
 
@@ -546,6 +1024,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Build the finalize tree, if the finalize state was written before the tree was maintained.
+        storage.initialize_tree()?;
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -589,6 +1069,22 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Returns the finalize storage.
+    #[cfg(test)]
+    pub(crate) const fn storage(&self) -> &P {
+        &self.storage
+    }
+
+    /// Checks the finalize storage against the given `latest height`, repairing it if `repair` is `true`.
+    pub(crate) fn check_integrity(
+        &self,
+        report: &mut IntegrityReport,
+        latest_height: Option<u32>,
+        repair: bool,
+    ) -> Result<()> {
+        self.storage.check_integrity(report, latest_height, repair)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -694,6 +1190,31 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.remove_mapping(program_id, mapping_name)
     }
 
+    /// Enables the finalize history, retaining the given number of blocks of history, or the full history if `None`.
+    pub fn enable_history(&self, retention: Option<u32>) -> Result<()> {
+        self.storage.enable_history(retention)
+    }
+
+    /// Records the pending key-value changes as the changes at the given block height, if the finalize history is enabled.
+    pub fn record_history(&self, block_height: u32) -> Result<()> {
+        self.storage.record_history(block_height)
+    }
+
+    /// Records the reverse of the pending finalize state changes, to roll back the block at the given height.
+    pub fn record_rollback(&self, block_height: u32) -> Result<()> {
+        self.storage.record_rollback(block_height)
+    }
+
+    /// Returns `true` if a finalize rollback was recorded for the block at the given height.
+    pub fn contains_rollback(&self, block_height: u32) -> Result<bool> {
+        self.storage.rollback_map().contains_key_confirmed(&block_height)
+    }
+
+    /// Rolls back the finalize state changes of the block at the given height, which must be the latest recorded block.
+    pub fn rollback_block(&self, block_height: u32) -> Result<()> {
+        self.storage.rollback_block(block_height)
+    }
+
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
@@ -707,6 +1228,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.contains_program_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` and `mapping name` exist, including pending changes.
+    pub fn contains_mapping_speculative(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
+        self.storage.contains_mapping_speculative(program_id, mapping_name)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     pub fn contains_key_confirmed(
         &self,
@@ -762,10 +1288,79 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the finalize history configuration, if the finalize history is enabled.
+    pub fn get_history_config(&self) -> Result<Option<FinalizeHistory>> {
+        self.storage.get_history_config()
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given block height.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns the recorded changes for the given `program ID`, `mapping name`, and `key`,
+    /// as a list of (`block height`, `value`) in increasing order of block height.
+    pub fn get_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Vec<(u32, Option<Value<N>>)>> {
+        self.storage.get_history(program_id, mapping_name, key)
+    }
+
+    /// Returns the confirmed value, with a proof of its inclusion (or exclusion) in the finalize tree,
+    /// for the given `program ID`, `mapping name`, and `key`.
+    ///
+    /// Note: The proof is against the finalize store checksum (the root of the finalize tree),
+    /// which is a local checksum that the blocks do not commit to.
+    pub fn get_value_with_proof(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<(Option<Value<N>>, FinalizeProof<N>)> {
+        self.storage.get_value_with_proof(program_id, mapping_name, key)
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
     }
+
+    /// Returns the pending checksum of the finalize store.
+    pub fn get_checksum_pending(&self) -> Result<Field<N>> {
+        self.storage.get_checksum_pending()
+    }
+
+    /// Writes a snapshot of the confirmed finalize state to the writer, for the given block height and block hash.
+    /// Note: The caller must ensure the finalize state corresponds to the given block.
+    pub fn export_snapshot<W: Write>(
+        &self,
+        block_height: u32,
+        block_hash: N::BlockHash,
+        writer: W,
+    ) -> Result<FinalizeSnapshotHeader<N>> {
+        export_snapshot(&self.storage, block_height, block_hash, writer)
+    }
+
+    /// Reads a snapshot of the finalize state and committee store from the reader into this (empty) finalize store.
+    /// The snapshot header must match the given trusted header, which must be obtained from a trusted source,
+    /// and its checksums must match the imported finalize state and committee store.
+    /// Note: Starting a ledger from the snapshot also requires the blocks up to the trusted block, synced separately.
+    pub fn import_snapshot<R: Read>(
+        &self,
+        trusted_header: &FinalizeSnapshotHeader<N>,
+        reader: R,
+    ) -> Result<FinalizeSnapshotHeader<N>> {
+        import_snapshot(&self.storage, trusted_header, reader)
+    }
 }
 
 #[cfg(test)]
@@ -1268,6 +1863,501 @@ mod tests {
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_get_value_with_proof() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("other").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the checksum of the empty finalize store is zero.
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), Field::zero());
+
+        // Initialize the mappings.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.initialize_mapping(program_id, other_mapping_name).unwrap();
+
+        // Insert the list of keys and values.
+        for item in 0..100 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, other_mapping_name, key, value).unwrap();
+        }
+        let checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Ensure the values are proven against the checksum.
+        for item in 0..110 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let (value, proof) = finalize_store.get_value_with_proof(program_id, mapping_name, &key).unwrap();
+            assert_eq!(value.is_some(), item < 100);
+            let key_id = to_key_id(&program_id, &mapping_name, &key).unwrap();
+            let value_id = value.as_ref().map(|value| to_value_id(&key_id, value).unwrap());
+            assert!(proof.verify(&checksum, &key_id, value_id.as_ref()));
+            // Ensure the proof does not verify for a different value.
+            let other_value_id = to_value_id(&key_id, &Value::from_str("123456789u64").unwrap()).unwrap();
+            assert!(!proof.verify(&checksum, &key_id, Some(&other_value_id)));
+        }
+
+        // Update a value, and ensure the checksum changes.
+        let key = Plaintext::from_str("0field").unwrap();
+        finalize_store
+            .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+            .unwrap();
+        assert_ne!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+        // Restore the value, and ensure the checksum is restored.
+        finalize_store.update_key_value(program_id, mapping_name, key, Value::from_str("0u64").unwrap()).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+
+        // Ensure a rewound atomic batch does not change the checksum.
+        finalize_store.start_atomic();
+        finalize_store.atomic_checkpoint();
+        finalize_store.remove_mapping(program_id, other_mapping_name).unwrap();
+        assert_ne!(finalize_store.get_checksum_pending().unwrap(), checksum);
+        finalize_store.atomic_rewind();
+        assert_eq!(finalize_store.get_checksum_pending().unwrap(), checksum);
+        finalize_store.finish_atomic().unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+
+        // Ensure replacing a mapping with its own entries does not change the checksum.
+        let entries = finalize_store.get_mapping_confirmed(program_id, other_mapping_name).unwrap();
+        finalize_store.replace_mapping(program_id, other_mapping_name, entries).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+
+        // Ensure removing the mappings empties the finalize tree.
+        finalize_store.remove_mapping(program_id, other_mapping_name).unwrap();
+        finalize_store.remove_program(&program_id).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), Field::zero());
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory.clone()).unwrap();
+
+        // Insert the list of keys and values.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for item in 0..50 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        let checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Ensure the finalize store is consistent.
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, false).unwrap();
+        assert!(report.issues().is_empty());
+        assert_eq!(report.checked()["Program(KeyValueID)"], 50);
+
+        // Corrupt the finalize tree, by removing a node and inserting a dangling node.
+        let (node_id, _) = program_memory.tree_map().iter_confirmed().next().unwrap();
+        program_memory.tree_map().remove(&node_id).unwrap();
+        program_memory.tree_map().insert((255, Field::one()), FinalizeTreeNode::Internal(Field::one())).unwrap();
+
+        // Ensure the inconsistencies are found, and are not repaired.
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, false).unwrap();
+        assert_eq!(report.issues().len(), 2);
+        assert!(!report.is_consistent());
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, false).unwrap();
+        assert_eq!(report.issues().len(), 2);
+
+        // Repair the finalize tree.
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, true).unwrap();
+        assert_eq!(report.issues().len(), 2);
+        assert!(report.is_consistent());
+
+        // Ensure the finalize store is consistent, and the checksum is restored.
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, false).unwrap();
+        assert!(report.issues().is_empty());
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+    }
+
+    #[test]
+    fn test_initialize_tree_on_open() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store, with the key-value entries.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory.clone()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for item in 0..50 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        let checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Remove the finalize tree, as in a finalize store written before the tree was maintained.
+        let node_ids: Vec<_> = program_memory.tree_map().keys_confirmed().map(|node_id| *node_id).collect();
+        for node_id in node_ids {
+            program_memory.tree_map().remove(&node_id).unwrap();
+        }
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), Field::zero());
+
+        // Ensure opening the finalize store builds the finalize tree.
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+        let mut report = IntegrityReport::default();
+        finalize_store.check_integrity(&mut report, None, false).unwrap();
+        assert!(report.issues().is_empty());
+    }
+
+    #[test]
+    fn test_export_import_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program IDs and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let other_program_id = ProgramID::<CurrentNetwork>::from_str("world.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let empty_mapping_name = Identifier::from_str("empty").unwrap();
+
+        // Initialize a new finalize store, with the mappings and entries.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.initialize_mapping(program_id, empty_mapping_name).unwrap();
+        finalize_store.initialize_mapping(other_program_id, mapping_name).unwrap();
+        for item in 0..50 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+            if item % 2 == 0 {
+                finalize_store.insert_key_value(other_program_id, mapping_name, key, value).unwrap();
+            }
+        }
+
+        // Insert the committees, up to the snapshot height.
+        let block_height = 5;
+        for height in 0..=block_height {
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(2 * height as u64, rng);
+            finalize_store.committee_store().insert(height, committee).unwrap();
+        }
+
+        // Export the snapshot.
+        let block_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+        let mut snapshot = Vec::new();
+        let header = finalize_store.export_snapshot(block_height, block_hash, &mut snapshot).unwrap();
+        assert_eq!(header.checksum(), finalize_store.get_checksum_confirmed().unwrap());
+
+        assert_eq!(header.block_height(), block_height);
+        assert_eq!(header.block_hash(), block_hash);
+
+        // Ensure the snapshot is rejected for a different block.
+        let candidate_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        let other_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+        for trusted_header in [
+            FinalizeSnapshotHeader::new(block_height + 1, block_hash, header.checksum(), header.committee_checksum()),
+            FinalizeSnapshotHeader::new(block_height, other_hash, header.checksum(), header.committee_checksum()),
+        ] {
+            assert!(candidate_store.import_snapshot(&trusted_header, &snapshot[..]).is_err());
+        }
+        // Ensure the snapshot is rejected for different trusted checksums.
+        for trusted_header in [
+            FinalizeSnapshotHeader::new(block_height, block_hash, Field::rand(rng), header.committee_checksum()),
+            FinalizeSnapshotHeader::new(block_height, block_hash, header.checksum(), Field::rand(rng)),
+        ] {
+            assert!(candidate_store.import_snapshot(&trusted_header, &snapshot[..]).is_err());
+        }
+
+        // Ensure a tampered snapshot is rejected, and leaves the finalize store empty.
+        let mut tampered = snapshot.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(candidate_store.import_snapshot(&header, &tampered[..]).is_err());
+        assert!(!candidate_store.contains_program_confirmed(&program_id).unwrap());
+        assert_eq!(candidate_store.get_checksum_confirmed().unwrap(), Field::zero());
+
+        // Ensure a snapshot with a tampered committee store, under the trusted header, is rejected.
+        let other_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        for height in 0..=block_height {
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(2 * height as u64, rng);
+            other_store.committee_store().insert(height, committee).unwrap();
+        }
+        let mut other_snapshot = Vec::new();
+        let other_header = other_store.export_snapshot(block_height, block_hash, &mut other_snapshot).unwrap();
+        assert_ne!(other_header.committee_checksum(), header.committee_checksum());
+        let header_size = header.to_bytes_le().unwrap().len();
+        let tampered = [&snapshot[..header_size], &other_snapshot[header_size..]].concat();
+        assert!(candidate_store.import_snapshot(&header, &tampered[..]).is_err());
+
+        // Ensure the committee store is empty.
+        assert!(candidate_store.committee_store().current_committee().is_err());
+
+        // Import the snapshot, and ensure the finalize state matches.
+        assert_eq!(candidate_store.import_snapshot(&header, &snapshot[..]).unwrap(), header);
+        assert_eq!(candidate_store.get_checksum_confirmed().unwrap(), header.checksum());
+        // Ensure the committee store matches.
+        let (committee_store, candidate_committee_store) =
+            (finalize_store.committee_store(), candidate_store.committee_store());
+        assert_eq!(candidate_committee_store.current_round().unwrap(), committee_store.current_round().unwrap());
+        assert_eq!(candidate_committee_store.current_height().unwrap(), block_height);
+        for round in 0..=committee_store.current_round().unwrap() {
+            assert_eq!(
+                candidate_committee_store.get_committee_for_round(round).unwrap(),
+                committee_store.get_committee_for_round(round).unwrap()
+            );
+        }
+        for program_id in [program_id, other_program_id] {
+            let mapping_names = finalize_store.get_mapping_names_confirmed(&program_id).unwrap().unwrap();
+            assert_eq!(candidate_store.get_mapping_names_confirmed(&program_id).unwrap(), Some(mapping_names.clone()));
+            for mapping_name in mapping_names {
+                assert_eq!(
+                    candidate_store.get_mapping_confirmed(program_id, mapping_name).unwrap(),
+                    finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap()
+                );
+            }
+        }
+
+        // Ensure the snapshot cannot be imported into a non-empty finalize store.
+        assert!(candidate_store.import_snapshot(&header, &snapshot[..]).is_err());
+    }
+
+    #[test]
+    fn test_rollback_block() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("other").unwrap();
+        let key = |item: u64| Plaintext::from_str(&format!("{item}field")).unwrap();
+        let value = |item: u64| Value::from_str(&format!("{item}u64")).unwrap();
+
+        // Initialize a new finalize store, with the history enabled.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.enable_history(None).unwrap();
+
+        // Finalize the given block, and return the checksum and mappings before the block.
+        let finalize_block = |height: u32, operations: &dyn Fn()| {
+            let checksum = finalize_store.get_checksum_confirmed().unwrap();
+            let mappings = [mapping_name, other_mapping_name]
+                .map(|mapping_name| finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap_or_default());
+            finalize_store.start_atomic();
+            operations();
+            finalize_store.record_rollback(height).unwrap();
+            finalize_store.record_history(height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+            (checksum, mappings)
+        };
+
+        // Block 1 initializes the mappings and inserts entries.
+        let state_1 = finalize_block(1, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.initialize_mapping(program_id, other_mapping_name).unwrap();
+            for item in 0..10 {
+                finalize_store.insert_key_value(program_id, mapping_name, key(item), value(item)).unwrap();
+                finalize_store.insert_key_value(program_id, other_mapping_name, key(item), value(item)).unwrap();
+            }
+        });
+        // Block 2 updates and removes entries.
+        let state_2 = finalize_block(2, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key(0), value(100)).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key(10), value(110)).unwrap();
+            finalize_store.remove_key_value(program_id, mapping_name, &key(1)).unwrap();
+        });
+        // Block 3 removes a mapping.
+        let state_3 = finalize_block(3, &|| {
+            finalize_store.remove_mapping(program_id, other_mapping_name).unwrap();
+        });
+        assert!(!finalize_store.contains_mapping_confirmed(&program_id, &other_mapping_name).unwrap());
+
+        // Ensure only the latest block can be rolled back.
+        assert!(finalize_store.rollback_block(2).is_err());
+        assert!(finalize_store.rollback_block(4).is_err());
+
+        // Ensure consecutive blocks can be rolled back in a single atomic batch, which leaves no changes if aborted.
+        let checksum = finalize_store.get_checksum_confirmed().unwrap();
+        finalize_store.start_atomic();
+        finalize_store.rollback_block(3).unwrap();
+        finalize_store.rollback_block(2).unwrap();
+        assert_eq!(finalize_store.get_checksum_pending().unwrap(), state_2.0);
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+        assert!(finalize_store.contains_rollback(3).unwrap());
+
+        // Roll back each block, and ensure the finalize state is restored.
+        for (height, (checksum, mappings)) in [(3, state_3), (2, state_2)] {
+            finalize_store.rollback_block(height).unwrap();
+            assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+            assert_eq!(finalize_store.get_history_config().unwrap().unwrap().latest_height(), Some(height - 1));
+            for (mapping_name, mapping) in [mapping_name, other_mapping_name].into_iter().zip(mappings) {
+                let mut candidate = finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap();
+                let mut expected = mapping;
+                candidate.sort_by_key(|(key, _)| key.to_string());
+                expected.sort_by_key(|(key, _)| key.to_string());
+                assert_eq!(candidate, expected);
+            }
+        }
+        assert_eq!(finalize_store.get_history(program_id, mapping_name, &key(0)).unwrap(), vec![(1, Some(value(0)))]);
+
+        // Roll back the first block, and ensure the program is removed.
+        finalize_store.rollback_block(1).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), state_1.0);
+        assert!(!finalize_store.contains_program_confirmed(&program_id).unwrap());
+        assert_eq!(finalize_store.get_history_config().unwrap().unwrap().start_height(), None);
+        // Ensure a block cannot be rolled back twice.
+        assert!(!finalize_store.contains_rollback(1).unwrap());
+        let error = finalize_store.rollback_block(1).unwrap_err();
+        assert!(error.to_string().contains("no finalize rollback was recorded"));
+    }
+
+    #[test]
+    fn test_rollback_depth() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Finalize a block past the rollback depth, updating the entry in each block.
+        for height in 1..=MAX_ROLLBACK_DEPTH + 1 {
+            finalize_store.start_atomic();
+            let value = Value::from_str(&format!("{height}u64")).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+            finalize_store.record_rollback(height).unwrap();
+            // Ensure a second block cannot be recorded in the same batch.
+            assert!(finalize_store.record_rollback(height + 1).is_err());
+            finalize_store.finish_atomic().unwrap();
+
+            // Ensure the stored checksum matches the finalize tree after the block.
+            let checksum = finalize_store.get_checksum_confirmed().unwrap();
+            assert_eq!(
+                finalize_store.storage().checksum_map().get_confirmed(&height).unwrap().as_deref(),
+                Some(&checksum)
+            );
+        }
+
+        // Ensure only the blocks within the rollback depth are kept.
+        assert!(!finalize_store.contains_rollback(1).unwrap());
+        assert!(finalize_store.storage().checksum_map().get_confirmed(&1).unwrap().is_none());
+        assert_eq!(finalize_store.storage().rollback_map().keys_confirmed().count(), MAX_ROLLBACK_DEPTH as usize);
+        assert_eq!(finalize_store.storage().checksum_map().keys_confirmed().count(), MAX_ROLLBACK_DEPTH as usize);
+
+        // Ensure the blocks within the rollback depth can be rolled back.
+        for height in (2..=MAX_ROLLBACK_DEPTH + 1).rev() {
+            finalize_store.rollback_block(height).unwrap();
+        }
+        let value = finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap().unwrap();
+        assert_eq!(value, Value::from_str("1u64").unwrap());
+        // Ensure the block past the rollback depth cannot be rolled back.
+        assert!(finalize_store.rollback_block(1).is_err());
+    }
+
+    #[test]
+    fn test_finalize_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+        let value = |amount: u64| Value::from_str(&format!("{amount}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Initialize the mapping, and insert a key before the history is enabled.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, other_key.clone(), value(7)).unwrap();
+
+        // Ensure the history is not available before it is enabled.
+        assert!(finalize_store.get_history_config().unwrap().is_none());
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).is_err());
+        assert!(finalize_store.get_history(program_id, mapping_name, &key).is_err());
+
+        // Enable the history.
+        finalize_store.enable_history(None).unwrap();
+
+        // Finalize the given blocks, where each block sets the key to the given value (or removes it, if `None`).
+        let finalize_blocks = |blocks: Vec<(u32, Option<u64>)>| {
+            for (height, amount) in blocks {
+                finalize_store.start_atomic();
+                match amount {
+                    Some(amount) => {
+                        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(amount)).unwrap();
+                    }
+                    None => {
+                        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+                    }
+                }
+                finalize_store.record_history(height).unwrap();
+                finalize_store.finish_atomic().unwrap();
+            }
+        };
+        finalize_blocks(vec![(1, Some(10)), (3, Some(30)), (4, None), (6, Some(60))]);
+
+        // Ensure the values are correct at each height.
+        let expected = [(1, Some(10)), (2, Some(10)), (3, Some(30)), (4, None), (5, None), (6, Some(60))];
+        for (height, amount) in expected {
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, amount.map(value), "Mismatch at height {height}");
+            // Ensure the unchanged key retains its value from before the history.
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &other_key, height).unwrap();
+            assert_eq!(candidate, Some(value(7)));
+        }
+        // Ensure the heights outside of the history are rejected.
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).is_err());
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 7).is_err());
+        // Ensure a block height cannot be recorded twice.
+        finalize_store.start_atomic();
+        assert!(finalize_store.record_history(6).is_err());
+        finalize_store.abort_atomic();
+
+        // Ensure the history of the key is correct.
+        let history = finalize_store.get_history(program_id, mapping_name, &key).unwrap();
+        assert_eq!(history, vec![(1, Some(value(10))), (3, Some(value(30))), (4, None), (6, Some(value(60)))]);
+
+        // Enable a retention window of 2 blocks.
+        finalize_store.enable_history(Some(2)).unwrap();
+        finalize_blocks(vec![(8, Some(80))]);
+
+        // Ensure the history starts at the retention window, and retains the value at its start.
+        let config = finalize_store.get_history_config().unwrap().unwrap();
+        assert_eq!((config.retention(), config.start_height(), config.latest_height()), (Some(2), Some(6), Some(8)));
+        let history = finalize_store.get_history(program_id, mapping_name, &key).unwrap();
+        assert_eq!(history, vec![(6, Some(value(60))), (8, Some(value(80)))]);
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 5).is_err());
+        for (height, amount) in [(6, 60), (7, 60), (8, 80)] {
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, Some(value(amount)));
+        }
+
+        // Ensure a key that changes for the first time retains its value at the start of the retention window.
+        finalize_store.start_atomic();
+        finalize_store.update_key_value(program_id, mapping_name, other_key.clone(), value(90)).unwrap();
+        finalize_store.record_history(9).unwrap();
+        finalize_store.finish_atomic().unwrap();
+        let history = finalize_store.get_history(program_id, mapping_name, &other_key).unwrap();
+        assert_eq!(history, vec![(7, Some(value(7))), (9, Some(value(90)))]);
+        assert_eq!(
+            finalize_store.get_value_at_height(program_id, mapping_name, &other_key, 8).unwrap(),
+            Some(value(7))
+        );
+
+        // Ensure an aborted block is not recorded.
+        finalize_store.start_atomic();
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        finalize_store.record_history(10).unwrap();
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.get_history_config().unwrap().unwrap().latest_height(), Some(9));
+        assert_eq!(finalize_store.get_history(program_id, mapping_name, &key).unwrap().len(), 2);
+    }
+
     /// If you want to customize the DB size, run:
     /// ```ignore
     /// NUM_ITEMS=100000 cargo test test_finalize_timings -- --nocapture
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the finalize history.
+///
+/// When the finalize history is enabled, the changes to the key-value entries are recorded at each block height,
+/// which allows the value of a key to be retrieved at any block height in the recorded history.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizeHistory {
+    /// The number of blocks of history to retain, or `None` to retain the full history.
+    retention: Option<u32>,
+    /// The block heights of the recorded history, as (`start height`, `latest height`),
+    /// or `None` if no blocks have been recorded.
+    heights: Option<(u32, u32)>,
+}
+
+impl FinalizeHistory {
+    /// Initializes a new finalize history configuration.
+    pub(crate) const fn new(retention: Option<u32>, heights: Option<(u32, u32)>) -> Self {
+        Self { retention, heights }
+    }
+
+    /// Returns the number of blocks of history to retain, or `None` if the full history is retained.
+    pub const fn retention(&self) -> Option<u32> {
+        self.retention
+    }
+
+    /// Returns the earliest block height in the recorded history, if any blocks have been recorded.
+    pub fn start_height(&self) -> Option<u32> {
+        self.heights.map(|(start_height, _)| start_height)
+    }
+
+    /// Returns the latest block height in the recorded history, if any blocks have been recorded.
+    pub fn latest_height(&self) -> Option<u32> {
+        self.heights.map(|(_, latest_height)| latest_height)
+    }
+
+    /// Returns `true` if the given block height is in the recorded history.
+    pub fn contains_height(&self, block_height: u32) -> bool {
+        matches!(self.heights, Some((start_height, latest_height)) if (start_height..=latest_height).contains(&block_height))
+    }
+
+    /// Returns the start height of the retention window, after the given block height is recorded.
+    pub(crate) fn next_start_height(&self, block_height: u32) -> u32 {
+        // The history starts at the first recorded block height.
+        let start_height = self.start_height().unwrap_or(block_height);
+        match self.retention {
+            Some(retention) => block_height.saturating_sub(retention).max(start_height),
+            None => start_height,
+        }
+    }
+}
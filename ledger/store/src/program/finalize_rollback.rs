@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of latest blocks whose finalize rollbacks are kept, and can be rolled back.
+pub const MAX_ROLLBACK_DEPTH: u32 = 100;
+
+/// The previous mapping names of a program, where `None` denotes that the program did not exist.
+pub type FinalizeRollbackProgram<N> = (ProgramID<N>, Option<IndexSet<Identifier<N>>>);
+
+/// The previous value of a key, as (`program ID`, `mapping name`, `key`, `value`),
+/// where a `value` of `None` denotes that the key did not exist.
+pub type FinalizeRollbackEntry<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>);
+
+/// The reverse of the finalize state changes of a block, which is applied to roll back the block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FinalizeRollback<N: Network> {
+    /// The previous mapping names of the programs that were changed in the block.
+    programs: Vec<FinalizeRollbackProgram<N>>,
+    /// The previous values of the keys that were changed in the block.
+    entries: Vec<FinalizeRollbackEntry<N>>,
+}
+
+impl<N: Network> FinalizeRollback<N> {
+    /// Initializes a new finalize rollback.
+    pub const fn new(programs: Vec<FinalizeRollbackProgram<N>>, entries: Vec<FinalizeRollbackEntry<N>>) -> Self {
+        Self { programs, entries }
+    }
+
+    /// Returns the previous mapping names of the programs that were changed in the block.
+    pub fn programs(&self) -> &[FinalizeRollbackProgram<N>] {
+        &self.programs
+    }
+
+    /// Returns the previous values of the keys that were changed in the block.
+    pub fn entries(&self) -> &[FinalizeRollbackEntry<N>] {
+        &self.entries
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    cow_to_cloned,
+    cow_to_copied,
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+    program::{CommitteeStorage, FinalizeStorage, ROUND_KEY},
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+use ledger_committee::Committee;
+
+use indexmap::IndexSet;
+
+/// The version of the finalize snapshot format.
+const SNAPSHOT_VERSION: u8 = 3;
+
+/// The header of a finalize snapshot.
+///
+/// A finalize snapshot is the finalize state at a given block, written as the header,
+/// followed by the committee store (the current round, the round-to-height entries, and the committee per height),
+/// then each program ID with its mapping names, and each mapping with its key-value entries.
+///
+/// Note: The blocks do not commit to the finalize state, so the header must be obtained from a trusted source.
+/// A snapshot is only imported if its header matches the trusted header, and its contents match the checksums.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeSnapshotHeader<N: Network> {
+    /// The height of the block at which the snapshot was taken.
+    block_height: u32,
+    /// The hash of the block at which the snapshot was taken.
+    block_hash: N::BlockHash,
+    /// The checksum of the finalize state.
+    checksum: Field<N>,
+    /// The checksum of the committee store.
+    committee_checksum: Field<N>,
+}
+
+impl<N: Network> FinalizeSnapshotHeader<N> {
+    /// Initializes a new finalize snapshot header.
+    pub const fn new(
+        block_height: u32,
+        block_hash: N::BlockHash,
+        checksum: Field<N>,
+        committee_checksum: Field<N>,
+    ) -> Self {
+        Self { block_height, block_hash, checksum, committee_checksum }
+    }
+
+    /// Returns the height of the block at which the snapshot was taken.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the hash of the block at which the snapshot was taken.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the checksum of the finalize state.
+    pub const fn checksum(&self) -> Field<N> {
+        self.checksum
+    }
+
+    /// Returns the checksum of the committee store.
+    pub const fn committee_checksum(&self) -> Field<N> {
+        self.committee_checksum
+    }
+}
+
+impl<N: Network> FromBytes for FinalizeSnapshotHeader<N> {
+    /// Reads the finalize snapshot header from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != SNAPSHOT_VERSION {
+            return Err(error("Invalid finalize snapshot version"));
+        }
+        // Read the block height, block hash, and checksums.
+        let block_height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let checksum = Field::read_le(&mut reader)?;
+        let committee_checksum = Field::read_le(&mut reader)?;
+        // Return the finalize snapshot header.
+        Ok(Self::new(block_height, block_hash, checksum, committee_checksum))
+    }
+}
+
+impl<N: Network> ToBytes for FinalizeSnapshotHeader<N> {
+    /// Writes the finalize snapshot header to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        SNAPSHOT_VERSION.write_le(&mut writer)?;
+        // Write the block height, block hash, and checksums.
+        self.block_height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.checksum.write_le(&mut writer)?;
+        self.committee_checksum.write_le(&mut writer)
+    }
+}
+
+/// Writes a snapshot of the confirmed finalize state at the given block to the writer,
+/// and returns the snapshot header.
+pub(crate) fn export_snapshot<N: Network, P: FinalizeStorage<N>, W: Write>(
+    storage: &P,
+    block_height: u32,
+    block_hash: N::BlockHash,
+    mut writer: W,
+) -> Result<FinalizeSnapshotHeader<N>> {
+    // Ensure an atomic batch is not in progress, as the snapshot must not contain partial changes.
+    ensure!(!storage.is_atomic_in_progress(), "Cannot export a finalize snapshot while an atomic batch is in progress");
+
+    // Retrieve the committee store.
+    let committee = CommitteeSnapshot::load(storage.committee_store().storage())?;
+
+    // Write the header.
+    let header = FinalizeSnapshotHeader::new(
+        block_height,
+        block_hash,
+        storage.get_checksum_confirmed()?,
+        committee.to_checksum()?,
+    );
+    header.write_le(&mut writer)?;
+
+    // Write the committee store.
+    committee.write_le(&mut writer)?;
+
+    // Retrieve the program IDs and their mapping names.
+    let programs: Vec<_> = storage
+        .program_id_map()
+        .iter_confirmed()
+        .map(|(program_id, mapping_names)| (cow_to_copied!(program_id), cow_to_cloned!(mapping_names)))
+        .collect();
+    // Write the programs.
+    u32::try_from(programs.len())?.write_le(&mut writer)?;
+    for (program_id, mapping_names) in programs {
+        program_id.write_le(&mut writer)?;
+        u32::try_from(mapping_names.len())?.write_le(&mut writer)?;
+        for mapping_name in mapping_names {
+            mapping_name.write_le(&mut writer)?;
+            // Write the key-value entries of the mapping.
+            let entries = storage.key_value_map().get_map_confirmed(&(program_id, mapping_name))?;
+            (entries.len() as u64).write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+    }
+    Ok(header)
+}
+
+/// Reads a snapshot of the finalize state from the reader into the (empty) storage, and returns the snapshot header.
+/// The snapshot header must match the given trusted header, and its checksums must match the imported state.
+pub(crate) fn import_snapshot<N: Network, P: FinalizeStorage<N>, R: Read>(
+    storage: &P,
+    trusted_header: &FinalizeSnapshotHeader<N>,
+    mut reader: R,
+) -> Result<FinalizeSnapshotHeader<N>> {
+    // Ensure an atomic batch is not in progress.
+    ensure!(!storage.is_atomic_in_progress(), "Cannot import a finalize snapshot while an atomic batch is in progress");
+    // Ensure the storage is empty.
+    ensure!(
+        storage.program_id_map().iter_confirmed().next().is_none()
+            && storage.committee_store().storage().current_round_map().iter_confirmed().next().is_none(),
+        "Cannot import a finalize snapshot into a non-empty store"
+    );
+
+    // Read the header.
+    let header = FinalizeSnapshotHeader::<N>::read_le(&mut reader)?;
+    // Ensure the snapshot was taken at the trusted block.
+    if header.block_height() != trusted_header.block_height() || header.block_hash() != trusted_header.block_hash() {
+        bail!(
+            "The finalize snapshot is for block {} ('{}'), expected block {} ('{}')",
+            header.block_height(),
+            header.block_hash(),
+            trusted_header.block_height(),
+            trusted_header.block_hash()
+        )
+    }
+    // Ensure the snapshot checksums are the trusted checksums.
+    ensure!(header == *trusted_header, "The finalize snapshot checksums do not match the trusted checksums");
+
+    // Import the snapshot in a single atomic batch.
+    storage.start_atomic();
+    match import_snapshot_entries(storage, &header, reader) {
+        Ok(()) => storage.finish_atomic()?,
+        Err(error) => {
+            storage.abort_atomic();
+            return Err(error);
+        }
+    }
+    Ok(header)
+}
+
+/// Reads the committee store, programs, and mappings of a finalize snapshot from the reader into the storage,
+/// and ensures the resulting checksums match the snapshot header.
+fn import_snapshot_entries<N: Network, P: FinalizeStorage<N>, R: Read>(
+    storage: &P,
+    header: &FinalizeSnapshotHeader<N>,
+    mut reader: R,
+) -> Result<()> {
+    // Read the committee store.
+    let committee = CommitteeSnapshot::read_le(&mut reader)?;
+    // Ensure the checksum of the committee store matches the snapshot.
+    ensure!(
+        committee.to_checksum()? == header.committee_checksum(),
+        "The finalize snapshot committee checksum does not match the imported committee store"
+    );
+    committee.store(storage.committee_store().storage(), header.block_height())?;
+
+    // Read the programs.
+    let num_programs = u32::read_le(&mut reader)?;
+    for _ in 0..num_programs {
+        let program_id = ProgramID::read_le(&mut reader)?;
+        ensure!(
+            !storage.program_id_map().contains_key_speculative(&program_id)?,
+            "Duplicate program '{program_id}' in the snapshot"
+        );
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mut mapping_names = IndexSet::new();
+        for _ in 0..num_mappings {
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            ensure!(
+                mapping_names.insert(mapping_name),
+                "Duplicate mapping '{program_id}/{mapping_name}' in the snapshot"
+            );
+            // Read the key-value entries of the mapping.
+            // Note: The entries are inserted directly, as the checksum is verified over the entire snapshot.
+            let num_entries = u64::read_le(&mut reader)?;
+            for _ in 0..num_entries {
+                let key = Plaintext::read_le(&mut reader)?;
+                let value = Value::read_le(&mut reader)?;
+                storage.key_value_map().insert((program_id, mapping_name), key, value)?;
+            }
+        }
+        storage.program_id_map().insert(program_id, mapping_names)?;
+    }
+    // Ensure the checksum of the imported finalize state matches the snapshot.
+    let checksum = storage.get_checksum_pending()?;
+    ensure!(checksum == header.checksum(), "The finalize snapshot checksum does not match the imported finalize state");
+    // Store the finalize checksum of the snapshot block.
+    storage.checksum_map().insert(header.block_height(), checksum)?;
+    Ok(())
+}
+
+/// The committee store of a finalize snapshot.
+struct CommitteeSnapshot<N: Network> {
+    /// The current round, if one exists.
+    current_round: Option<u64>,
+    /// The round-to-height entries.
+    rounds: Vec<(u64, u32)>,
+    /// The committee per height.
+    committees: Vec<(u32, Committee<N>)>,
+}
+
+impl<N: Network> CommitteeSnapshot<N> {
+    /// Loads the confirmed committee store from the storage.
+    fn load<C: CommitteeStorage<N>>(storage: &C) -> Result<Self> {
+        let current_round = storage.current_round_map().get_confirmed(&ROUND_KEY)?.map(|round| cow_to_copied!(round));
+        let rounds = storage
+            .round_to_height_map()
+            .iter_confirmed()
+            .map(|(round, height)| (cow_to_copied!(round), cow_to_copied!(height)))
+            .collect();
+        let committees = storage
+            .committee_map()
+            .iter_confirmed()
+            .map(|(height, committee)| (cow_to_copied!(height), cow_to_cloned!(committee)))
+            .collect();
+        Ok(Self { current_round, rounds, committees })
+    }
+
+    /// Returns the checksum of the committee store, over the current round, the round-to-height entries,
+    /// and the committee ID per height.
+    fn to_checksum(&self) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(3 + 2 * self.rounds.len() + 2 * self.committees.len());
+        preimage.push(Field::from_u64(self.current_round.map_or(0, |round| round.saturating_add(1))));
+        preimage.push(Field::from_u64(self.rounds.len() as u64));
+        for (round, height) in &self.rounds {
+            preimage.push(Field::from_u64(*round));
+            preimage.push(Field::from_u32(*height));
+        }
+        preimage.push(Field::from_u64(self.committees.len() as u64));
+        for (height, committee) in &self.committees {
+            preimage.push(Field::from_u32(*height));
+            preimage.push(committee.id());
+        }
+        N::hash_psd8(&preimage)
+    }
+
+    /// Writes the committee store into the storage.
+    /// Note: The committee store must end at the snapshot height.
+    fn store<C: CommitteeStorage<N>>(self, storage: &C, block_height: u32) -> Result<()> {
+        // Write the current round, if one exists.
+        if let Some(current_round) = self.current_round {
+            storage.current_round_map().insert(ROUND_KEY, current_round)?;
+        }
+        // Write the round-to-height entries.
+        for (round, height) in self.rounds {
+            ensure!(height <= block_height, "The committee for round {round} is past the snapshot height");
+            storage.round_to_height_map().insert(round, height)?;
+        }
+        // Write the committees.
+        let latest_height = self.committees.iter().map(|(height, _)| *height).max();
+        for (height, committee) in self.committees {
+            ensure!(height <= block_height, "The committee at height {height} is past the snapshot height");
+            storage.committee_map().insert(height, committee)?;
+        }
+        // Ensure the committee store is at the snapshot height.
+        ensure!(
+            latest_height == Some(block_height),
+            "The finalize snapshot does not contain the committee at height {block_height}"
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CommitteeSnapshot<N> {
+    /// Reads the committee store from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the current round, if one exists.
+        let current_round = match bool::read_le(&mut reader)? {
+            true => Some(u64::read_le(&mut reader)?),
+            false => None,
+        };
+        // Read the round-to-height entries.
+        let num_rounds = u64::read_le(&mut reader)?;
+        let mut rounds = Vec::new();
+        for _ in 0..num_rounds {
+            rounds.push((u64::read_le(&mut reader)?, u32::read_le(&mut reader)?));
+        }
+        // Read the committees.
+        let num_committees = u32::read_le(&mut reader)?;
+        let mut committees = Vec::new();
+        for _ in 0..num_committees {
+            committees.push((u32::read_le(&mut reader)?, Committee::read_le(&mut reader)?));
+        }
+        Ok(Self { current_round, rounds, committees })
+    }
+}
+
+impl<N: Network> ToBytes for CommitteeSnapshot<N> {
+    /// Writes the committee store to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the current round, if one exists.
+        self.current_round.is_some().write_le(&mut writer)?;
+        if let Some(current_round) = self.current_round {
+            current_round.write_le(&mut writer)?;
+        }
+        // Write the round-to-height entries.
+        (self.rounds.len() as u64).write_le(&mut writer)?;
+        for (round, height) in &self.rounds {
+            round.write_le(&mut writer)?;
+            height.write_le(&mut writer)?;
+        }
+        // Write the committees.
+        u32::try_from(self.committees.len()).map_err(error)?.write_le(&mut writer)?;
+        for (height, committee) in &self.committees {
+            height.write_le(&mut writer)?;
+            committee.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{cow_to_cloned, helpers::Map};
+use console::{network::prelude::*, types::Field};
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The ID of a node in the finalize tree, as (`depth`, `prefix`),
+/// where `prefix` is the field element of the first `depth` bits of the key ID.
+pub type FinalizeTreeNodeID<N> = (u8, Field<N>);
+
+/// A node in the finalize tree.
+///
+/// The finalize tree is a compact sparse Merkle tree, keyed by the bits of the key ID.
+/// A subtree with a single leaf is collapsed into that leaf, so every leaf is stored at the
+/// shallowest depth at which its key ID prefix is unique, and every internal node has at least two leaves.
+/// An empty subtree has a hash of zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum FinalizeTreeNode<N: Network> {
+    /// An internal node, as (`hash`).
+    Internal(Field<N>),
+    /// A leaf node, as (`key ID`, `value ID`).
+    Leaf(Field<N>, Field<N>),
+}
+
+impl<N: Network> FinalizeTreeNode<N> {
+    /// Returns the hash of the node.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        match self {
+            Self::Internal(hash) => Ok(*hash),
+            Self::Leaf(key_id, value_id) => hash_leaf(key_id, value_id),
+        }
+    }
+}
+
+/// Returns the hash of the leaf for the given `key ID` and `value ID`.
+/// Note: Leaves are hashed with a different sponge than internal nodes, for domain separation.
+fn hash_leaf<N: Network>(key_id: &Field<N>, value_id: &Field<N>) -> Result<Field<N>> {
+    N::hash_psd4(&[*key_id, *value_id])
+}
+
+/// Returns the hash of the internal node with the given children.
+fn hash_internal<N: Network>(left: &Field<N>, right: &Field<N>) -> Result<Field<N>> {
+    N::hash_psd2(&[*left, *right])
+}
+
+/// A proof that the finalize tree contains (or does not contain) a key ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeProof<N: Network> {
+    /// The sibling hashes, from the root down to the terminal node.
+    siblings: Vec<Field<N>>,
+    /// The terminal node, as (`key ID`, `value ID`), or `None` if the terminal subtree is empty.
+    leaf: Option<(Field<N>, Field<N>)>,
+}
+
+impl<N: Network> FinalizeProof<N> {
+    /// Returns the sibling hashes, from the root down to the terminal node.
+    pub const fn siblings(&self) -> &Vec<Field<N>> {
+        &self.siblings
+    }
+
+    /// Returns the terminal leaf, as (`key ID`, `value ID`), if one exists.
+    pub const fn leaf(&self) -> Option<&(Field<N>, Field<N>)> {
+        self.leaf.as_ref()
+    }
+
+    /// Returns `true` if the proof is valid for the given root, `key ID`, and `value ID`.
+    /// If `value ID` is `None`, the proof must show that the `key ID` is not in the tree.
+    pub fn verify(&self, root: &Field<N>, key_id: &Field<N>, value_id: Option<&Field<N>>) -> bool {
+        let key_bits = key_id.to_bits_le();
+        // Ensure the number of siblings is within the depth of the tree.
+        if self.siblings.len() >= key_bits.len() {
+            return false;
+        }
+        // Ensure the terminal node is consistent with the claimed value.
+        let is_consistent = match (value_id, &self.leaf) {
+            // The leaf must be for the given key ID and value ID.
+            (Some(value_id), Some((leaf_key_id, leaf_value_id))) => leaf_key_id == key_id && leaf_value_id == value_id,
+            (Some(_), None) => false,
+            // The terminal subtree must be empty, or contain a different key ID with the same prefix.
+            (None, Some((leaf_key_id, _))) => {
+                leaf_key_id != key_id
+                    && leaf_key_id.to_bits_le()[..self.siblings.len()] == key_bits[..self.siblings.len()]
+            }
+            (None, None) => true,
+        };
+        if !is_consistent {
+            return false;
+        }
+        // Compute the terminal hash.
+        let terminal = match &self.leaf {
+            Some((leaf_key_id, leaf_value_id)) => match hash_leaf(leaf_key_id, leaf_value_id) {
+                Ok(hash) => hash,
+                Err(_) => return false,
+            },
+            None => Field::zero(),
+        };
+        // Compute the root, from the terminal node up.
+        let candidate_root =
+            self.siblings.iter().enumerate().rev().try_fold(terminal, |hash, (depth, sibling)| match key_bits[depth] {
+                true => hash_internal(sibling, &hash),
+                false => hash_internal(&hash, sibling),
+            });
+        matches!(candidate_root, Ok(candidate_root) if candidate_root == *root)
+    }
+}
+
+/// The finalize tree, over the given tree map.
+///
+/// Changes to the tree are staged in memory, and written to the tree map with `commit`.
+/// Note: Only the confirmed nodes of the tree map are read, as the tree is updated once per atomic batch.
+pub(crate) struct FinalizeTree<'a, N: Network, M: for<'b> Map<'b, FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>> {
+    /// The tree map.
+    map: &'a M,
+    /// The staged nodes, where `None` denotes a removed node.
+    staged: HashMap<FinalizeTreeNodeID<N>, Option<FinalizeTreeNode<N>>>,
+}
+
+impl<'a, N: Network, M: for<'b> Map<'b, FinalizeTreeNodeID<N>, FinalizeTreeNode<N>>> FinalizeTree<'a, N, M> {
+    /// Initializes the finalize tree over the given tree map.
+    pub(crate) fn new(map: &'a M) -> Self {
+        Self { map, staged: HashMap::new() }
+    }
+
+    /// Returns the root of the finalize tree.
+    pub(crate) fn root(&self) -> Result<Field<N>> {
+        self.hash(&Self::node_id(&[], 0)?)
+    }
+
+    /// Returns the proof for the given `key ID`.
+    pub(crate) fn prove(&self, key_id: &Field<N>) -> Result<FinalizeProof<N>> {
+        let bits = key_id.to_bits_le();
+        let mut siblings = Vec::new();
+        for depth in 0..bits.len() {
+            match self.get(&Self::node_id(&bits, depth)?)? {
+                Some(FinalizeTreeNode::Internal(_)) => {
+                    siblings.push(self.hash(&Self::child_id(&bits, depth, !bits[depth])?)?)
+                }
+                Some(FinalizeTreeNode::Leaf(key_id, value_id)) => {
+                    return Ok(FinalizeProof { siblings, leaf: Some((key_id, value_id)) });
+                }
+                None => return Ok(FinalizeProof { siblings, leaf: None }),
+            }
+        }
+        bail!("Malformed finalize tree - reached the maximum depth for key ID '{key_id}'")
+    }
+
+    /// Inserts, updates, or removes (if `value ID` is `None`) the leaf for each given `key ID`, in order.
+    pub(crate) fn update(&mut self, leaves: Vec<(Field<N>, Option<Field<N>>)>) -> Result<()> {
+        for (key_id, value_id) in leaves {
+            match value_id {
+                Some(value_id) => self.insert(key_id, value_id)?,
+                None => self.remove(&key_id)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the staged nodes to the tree map.
+    pub(crate) fn commit(self) -> Result<()> {
+        for (node_id, node) in self.staged {
+            match node {
+                Some(node) => self.map.insert(node_id, node)?,
+                None => self.map.remove(&node_id)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts or updates the leaf for the given `key ID` with the given `value ID`.
+    fn insert(&mut self, key_id: Field<N>, value_id: Field<N>) -> Result<()> {
+        let bits = key_id.to_bits_le();
+        // Find the depth at which the leaf belongs.
+        let mut depth = 0;
+        loop {
+            ensure!(depth < bits.len(), "Malformed finalize tree - reached the maximum depth for key ID '{key_id}'");
+            let node_id = Self::node_id(&bits, depth)?;
+            match self.get(&node_id)? {
+                // Insert the leaf into the empty subtree.
+                None => {
+                    self.staged.insert(node_id, Some(FinalizeTreeNode::Leaf(key_id, value_id)));
+                    break;
+                }
+                // Update the existing leaf.
+                Some(FinalizeTreeNode::Leaf(leaf_key_id, _)) if leaf_key_id == key_id => {
+                    self.staged.insert(node_id, Some(FinalizeTreeNode::Leaf(key_id, value_id)));
+                    break;
+                }
+                // Push the existing leaf down, until the two key IDs diverge.
+                Some(FinalizeTreeNode::Leaf(leaf_key_id, leaf_value_id)) => {
+                    let leaf_bits = leaf_key_id.to_bits_le();
+                    let Some(split) = (depth..bits.len()).find(|i| bits[*i] != leaf_bits[*i]) else {
+                        bail!("Malformed finalize tree - key ID '{key_id}' collides with an existing leaf")
+                    };
+                    // Note: The nodes from `depth` to `split` are overwritten as internal nodes below.
+                    depth = split + 1;
+                    let leaf = FinalizeTreeNode::Leaf(leaf_key_id, leaf_value_id);
+                    self.staged.insert(Self::node_id(&leaf_bits, depth)?, Some(leaf));
+                    self.staged.insert(Self::node_id(&bits, depth)?, Some(FinalizeTreeNode::Leaf(key_id, value_id)));
+                    break;
+                }
+                // Descend into the internal node.
+                Some(FinalizeTreeNode::Internal(_)) => depth += 1,
+            }
+        }
+        // Rehash the internal nodes above the leaf.
+        self.rehash(&bits, depth)
+    }
+
+    /// Removes the leaf for the given `key ID`, if it exists.
+    fn remove(&mut self, key_id: &Field<N>) -> Result<()> {
+        let bits = key_id.to_bits_le();
+        // Find the leaf.
+        let mut depth = 0;
+        loop {
+            ensure!(depth < bits.len(), "Malformed finalize tree - reached the maximum depth for key ID '{key_id}'");
+            let node_id = Self::node_id(&bits, depth)?;
+            match self.get(&node_id)? {
+                Some(FinalizeTreeNode::Leaf(leaf_key_id, _)) if leaf_key_id == *key_id => {
+                    self.staged.insert(node_id, None);
+                    break;
+                }
+                Some(FinalizeTreeNode::Internal(_)) => depth += 1,
+                // The key ID is not in the tree.
+                _ => return Ok(()),
+            }
+        }
+        // Collapse the subtrees that are left with a single leaf.
+        while depth > 0 {
+            let parent_depth = depth - 1;
+            let node_id = Self::child_id(&bits, parent_depth, bits[parent_depth])?;
+            let sibling_id = Self::child_id(&bits, parent_depth, !bits[parent_depth])?;
+            let (leaf_id, leaf) = match (self.get(&node_id)?, self.get(&sibling_id)?) {
+                (None, Some(leaf @ FinalizeTreeNode::Leaf(..))) => (sibling_id, leaf),
+                (Some(leaf @ FinalizeTreeNode::Leaf(..)), None) => (node_id, leaf),
+                _ => break,
+            };
+            // Move the remaining leaf up into the parent.
+            self.staged.insert(leaf_id, None);
+            self.staged.insert(Self::node_id(&bits, parent_depth)?, Some(leaf));
+            depth = parent_depth;
+        }
+        // Rehash the internal nodes above the collapsed subtree.
+        self.rehash(&bits, depth)
+    }
+
+    /// Recomputes the internal nodes on the path of the given key ID bits, from the given depth up to the root.
+    fn rehash(&mut self, bits: &[bool], depth: usize) -> Result<()> {
+        for depth in (0..depth).rev() {
+            let left = self.hash(&Self::child_id(bits, depth, false)?)?;
+            let right = self.hash(&Self::child_id(bits, depth, true)?)?;
+            let node = FinalizeTreeNode::Internal(hash_internal(&left, &right)?);
+            self.staged.insert(Self::node_id(bits, depth)?, Some(node));
+        }
+        Ok(())
+    }
+
+    /// Returns the node for the given node ID.
+    fn get(&self, node_id: &FinalizeTreeNodeID<N>) -> Result<Option<FinalizeTreeNode<N>>> {
+        match self.staged.get(node_id) {
+            Some(node) => Ok(*node),
+            None => Ok(self.map.get_confirmed(node_id)?.map(|node| cow_to_cloned!(node))),
+        }
+    }
+
+    /// Returns the hash of the subtree at the given node ID.
+    fn hash(&self, node_id: &FinalizeTreeNodeID<N>) -> Result<Field<N>> {
+        match self.get(node_id)? {
+            Some(node) => node.to_hash(),
+            None => Ok(Field::zero()),
+        }
+    }
+
+    /// Returns the node ID at the given depth on the path of the given key ID bits.
+    fn node_id(bits: &[bool], depth: usize) -> Result<FinalizeTreeNodeID<N>> {
+        Ok((u8::try_from(depth)?, Field::from_bits_le(&bits[..depth])?))
+    }
+
+    /// Returns the node ID of the given child of the node at the given depth on the path of the given key ID bits.
+    fn child_id(bits: &[bool], depth: usize, is_right: bool) -> Result<FinalizeTreeNodeID<N>> {
+        let mut prefix = bits[..depth].to_vec();
+        prefix.push(is_right);
+        Ok((u8::try_from(depth + 1)?, Field::from_bits_le(&prefix)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{memory::MemoryMap, MapRead};
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the given number of random leaves.
+    fn sample_leaves(
+        num_leaves: usize,
+        rng: &mut TestRng,
+    ) -> Vec<(Field<CurrentNetwork>, Option<Field<CurrentNetwork>>)> {
+        (0..num_leaves).map(|_| (Field::rand(rng), Some(Field::rand(rng)))).collect()
+    }
+
+    #[test]
+    fn test_update_is_canonical() {
+        let rng = &mut TestRng::default();
+
+        let leaves = sample_leaves(64, rng);
+
+        // Insert the leaves in order.
+        let map = MemoryMap::default();
+        assert_eq!(FinalizeTree::new(&map).root().unwrap(), Field::zero());
+        let mut tree = FinalizeTree::new(&map);
+        tree.update(leaves.clone()).unwrap();
+        tree.commit().unwrap();
+        let root = FinalizeTree::new(&map).root().unwrap();
+        assert_ne!(root, Field::zero());
+
+        // Insert the leaves in reverse order, along with leaves that are later removed, over several commits.
+        let other_map = MemoryMap::default();
+        let removed = sample_leaves(16, rng);
+        for chunk in leaves.iter().rev().chain(&removed).collect::<Vec<_>>().chunks(10) {
+            let mut tree = FinalizeTree::new(&other_map);
+            for (key_id, value_id) in chunk {
+                tree.update(vec![(*key_id, Some(Field::rand(rng))), (*key_id, *value_id)]).unwrap();
+            }
+            tree.commit().unwrap();
+        }
+        assert_ne!(FinalizeTree::new(&other_map).root().unwrap(), root);
+        let mut tree = FinalizeTree::new(&other_map);
+        tree.update(removed.iter().map(|(key_id, _)| (*key_id, None)).collect()).unwrap();
+        tree.commit().unwrap();
+
+        // Ensure the roots and tree maps match.
+        assert_eq!(FinalizeTree::new(&other_map).root().unwrap(), root);
+        assert_eq!(map.iter_confirmed().collect::<Vec<_>>(), other_map.iter_confirmed().collect::<Vec<_>>());
+
+        // Remove all of the leaves.
+        let mut tree = FinalizeTree::new(&other_map);
+        tree.update(leaves.iter().map(|(key_id, _)| (*key_id, None)).collect()).unwrap();
+        tree.commit().unwrap();
+        assert_eq!(FinalizeTree::new(&other_map).root().unwrap(), Field::zero());
+        assert_eq!(other_map.iter_confirmed().count(), 0);
+    }
+
+    #[test]
+    fn test_prove_verify() {
+        let rng = &mut TestRng::default();
+
+        let map = MemoryMap::default();
+
+        // Ensure non-membership can be proven in an empty tree.
+        let key_id = Field::rand(rng);
+        let proof = FinalizeTree::new(&map).prove(&key_id).unwrap();
+        assert!(proof.verify(&Field::zero(), &key_id, None));
+
+        // Insert the leaves.
+        let leaves = sample_leaves(64, rng);
+        let mut tree = FinalizeTree::new(&map);
+        tree.update(leaves.clone()).unwrap();
+        tree.commit().unwrap();
+
+        let tree = FinalizeTree::new(&map);
+        let root = tree.root().unwrap();
+        for (key_id, value_id) in &leaves {
+            let value_id = value_id.as_ref();
+            // Ensure membership can be proven.
+            let proof = tree.prove(key_id).unwrap();
+            assert!(proof.verify(&root, key_id, value_id));
+            // Ensure the proof fails for a different value, root, or non-membership.
+            assert!(!proof.verify(&root, key_id, Some(&Field::rand(rng))));
+            assert!(!proof.verify(&Field::rand(rng), key_id, value_id));
+            assert!(!proof.verify(&root, key_id, None));
+        }
+
+        // Ensure non-membership can be proven.
+        for _ in 0..64 {
+            let key_id = Field::rand(rng);
+            let proof = tree.prove(&key_id).unwrap();
+            assert!(proof.verify(&root, &key_id, None));
+            assert!(!proof.verify(&root, &key_id, Some(&Field::rand(rng))));
+        }
+    }
+}
@@ -17,3 +17,15 @@ pub use committee::*;
 
 mod finalize;
 pub use finalize::*;
+
+mod finalize_history;
+pub use finalize_history::*;
+
+mod finalize_rollback;
+pub use finalize_rollback::*;
+
+mod finalize_snapshot;
+pub use finalize_snapshot::*;
+
+mod finalize_tree;
+pub use finalize_tree::*;
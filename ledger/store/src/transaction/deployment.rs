@@ -19,6 +19,7 @@ use crate::{
     helpers::{Map, MapRead},
     FeeStorage,
     FeeStore,
+    IntegrityReport,
 };
 use console::{
     network::prelude::*,
@@ -256,6 +257,100 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the fee proof and fee transition payload for the given `transaction ID`.
+    ///
+    /// Note: The program, verifying keys, and certificates are kept, as they are needed to load the program.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.fee_store().prune(transaction_id)
+    }
+
+    /// Checks the reverse program IDs against the deployments, and ensures each deployment is complete.
+    /// If `repair` is `true`, the inconsistent reverse program IDs are rewritten from the deployments,
+    /// and the dangling owners, programs, verifying keys, and certificates are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Retrieve the `(program ID, edition)` of each deployment.
+        let mut expected = Vec::new();
+        for (transaction_id, program_id) in self.id_map().iter_confirmed() {
+            let Some(edition) = self.edition_map().get_confirmed(&program_id)? else {
+                let message = format!("Missing edition of program '{}'", *program_id);
+                report.add_issue("Deployment(Edition)", message, false);
+                continue;
+            };
+            let edition = cow_to_copied!(edition);
+            // Ensure the program owner exists.
+            let key = (*program_id, edition);
+            if !self.owner_map().contains_key_confirmed(&key)? {
+                let message = format!("Missing owner of program '{}'", *program_id);
+                report.add_issue("Deployment(Owner)", message, false);
+            }
+            // Ensure the deployed program exists, along with a verifying key and certificate for each function.
+            match self.program_map().get_confirmed(&key)? {
+                Some(program) => {
+                    for function_name in program.functions().keys() {
+                        let function_key = (*program_id, *function_name, edition);
+                        if !self.verifying_key_map().contains_key_confirmed(&function_key)? {
+                            let message = format!("Missing verifying key of '{}/{function_name}'", *program_id);
+                            report.add_issue("Deployment(VerifyingKey)", message, false);
+                        }
+                        if !self.certificate_map().contains_key_confirmed(&function_key)? {
+                            let message = format!("Missing certificate of '{}/{function_name}'", *program_id);
+                            report.add_issue("Deployment(Certificate)", message, false);
+                        }
+                    }
+                }
+                None => {
+                    let message = format!("Missing program '{}' for transaction '{}'", *program_id, *transaction_id);
+                    report.add_issue("Deployment(Program)", message, false);
+                }
+            }
+            expected.push((key, *transaction_id));
+        }
+        report.add_checked("Deployment(ID)", self.id_map().len_confirmed());
+
+        // Check the reverse program IDs.
+        report.check_index(
+            "Deployment(ReverseID)",
+            self.reverse_id_map(),
+            expected,
+            |(program_id, edition), transaction_id| {
+                Ok(self.id_map().get_confirmed(transaction_id)?.is_some_and(|id| *id == *program_id)
+                    && self.edition_map().get_confirmed(program_id)?.is_some_and(|e| *e == *edition))
+            },
+            repair,
+        )?;
+
+        // Ensure each owner, program, verifying key, and certificate belongs to a deployment.
+        let is_deployed = |program_id, edition| self.reverse_id_map().contains_key_confirmed(&(program_id, edition));
+        report.check_index(
+            "Deployment(Owner)",
+            self.owner_map(),
+            [],
+            |(id, edition), _| is_deployed(*id, *edition),
+            repair,
+        )?;
+        report.check_index(
+            "Deployment(Program)",
+            self.program_map(),
+            [],
+            |(id, edition), _| is_deployed(*id, *edition),
+            repair,
+        )?;
+        report.check_index(
+            "Deployment(VerifyingKey)",
+            self.verifying_key_map(),
+            [],
+            |(id, _, edition), _| is_deployed(*id, *edition),
+            repair,
+        )?;
+        report.check_index(
+            "Deployment(Certificate)",
+            self.certificate_map(),
+            [],
+            |(id, _, edition), _| is_deployed(*id, *edition),
+            repair,
+        )
+    }
+
     /// Returns the transaction ID that contains the given `program ID`.
     fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -513,6 +608,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the fee proof and fee transition payload for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -552,6 +652,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the deployment indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
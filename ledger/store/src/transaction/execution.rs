@@ -19,6 +19,7 @@ use crate::{
     helpers::{Map, MapRead},
     FeeStorage,
     FeeStore,
+    IntegrityReport,
     TransitionStore,
 };
 use console::network::prelude::*;
@@ -194,6 +195,83 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the execution proof and transition payloads for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee boolean.
+        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+        // Retrieve the global state root.
+        let global_state_root = match self.inclusion_map().get_confirmed(transaction_id)? {
+            Some(Cow::Borrowed((global_state_root, _))) => *global_state_root,
+            Some(Cow::Owned((global_state_root, _))) => global_state_root,
+            None => bail!("Failed to get the global state root for the transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transitions.
+            for transition_id in transition_ids {
+                self.transition_store().prune(&transition_id)?;
+            }
+
+            // Remove the proof.
+            self.inclusion_map().insert(*transaction_id, (global_state_root, None))?;
+
+            // Prune the fee.
+            if has_fee {
+                self.fee_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Checks the reverse transition IDs against the executions, and ensures each execution is complete.
+    /// If `repair` is `true`, the inconsistent reverse transition IDs are rewritten from the executions,
+    /// and the dangling inclusions are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Ensure each execution transition and inclusion exists.
+        for (transaction_id, entry) in self.id_map().iter_confirmed() {
+            for transition_id in &entry.0 {
+                if !self.transition_store().contains_transition_id(transition_id)? {
+                    let message = format!("Missing transition '{transition_id}' for transaction '{}'", *transaction_id);
+                    report.add_issue("Execution(ID)", message, false);
+                }
+            }
+            if !self.inclusion_map().contains_key_confirmed(&transaction_id)? {
+                let message = format!("Missing inclusion of transaction '{}'", *transaction_id);
+                report.add_issue("Execution(Inclusion)", message, false);
+            }
+        }
+        report.add_checked("Execution(ID)", self.id_map().len_confirmed());
+
+        // Ensure each inclusion belongs to an execution.
+        report.check_index(
+            "Execution(Inclusion)",
+            self.inclusion_map(),
+            [],
+            |transaction_id, _| self.id_map().contains_key_confirmed(transaction_id),
+            repair,
+        )?;
+
+        // Check the reverse transition IDs.
+        let expected: Vec<_> = self
+            .id_map()
+            .iter_confirmed()
+            .flat_map(|(transaction_id, entry)| entry.0.iter().map(|id| (*id, *transaction_id)).collect::<Vec<_>>())
+            .collect();
+        report.check_index(
+            "Execution(ReverseID)",
+            self.reverse_id_map(),
+            expected,
+            |transition_id, transaction_id| {
+                Ok(self.id_map().get_confirmed(transaction_id)?.is_some_and(|entry| entry.0.contains(transition_id)))
+            },
+            repair,
+        )
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -319,6 +397,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the execution proof and transition payloads for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -358,6 +441,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the execution indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
@@ -380,6 +468,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     ) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id_from_transition_id(transition_id)
     }
+
+    /// Returns `true` if the given transaction ID is an execution.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.id_map().contains_key_confirmed(transaction_id)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
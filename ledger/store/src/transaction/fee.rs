@@ -17,6 +17,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead},
+    IntegrityReport,
     TransitionStorage,
     TransitionStore,
 };
@@ -137,6 +138,49 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the fee proof and fee transition payload for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the fee transition ID and global state root.
+        let (transition_id, global_state_root, _) = match self.fee_map().get_confirmed(transaction_id)? {
+            Some(fee_id) => cow_to_cloned!(fee_id),
+            None => bail!("Failed to locate the fee transition ID for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the fee proof.
+            self.fee_map().insert(*transaction_id, (transition_id, global_state_root, None))?;
+
+            // Prune the fee transition.
+            self.transition_store().prune(&transition_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Checks the reverse fee entries against the fees, and ensures each fee transition exists.
+    /// If `repair` is `true`, the inconsistent reverse fee entries are rewritten from the fees.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Ensure each fee transition exists.
+        for (transaction_id, fee) in self.fee_map().iter_confirmed() {
+            if !self.transition_store().contains_transition_id(&fee.0)? {
+                let message = format!("Missing fee transition '{}' for transaction '{}'", fee.0, *transaction_id);
+                report.add_issue("Fee(Fee)", message, false);
+            }
+        }
+
+        // Check the reverse fee entries.
+        let expected: Vec<_> = self.fee_map().iter_confirmed().map(|(id, fee)| (fee.0, *id)).collect();
+        report.check_index(
+            "Fee(ReverseFee)",
+            self.reverse_fee_map(),
+            expected,
+            |transition_id, transaction_id| {
+                Ok(self.fee_map().get_confirmed(transaction_id)?.is_some_and(|fee| fee.0 == *transition_id))
+            },
+            repair,
+        )
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -196,6 +240,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the fee proof and fee transition payload for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, F::TransitionStorage> {
         self.storage.transition_store()
@@ -240,6 +289,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the fee indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
@@ -257,6 +311,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     ) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id_from_transition_id(transition_id)
     }
+
+    /// Returns `true` if the given transaction ID has a fee.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.fee_map().contains_key_confirmed(transaction_id)
+    }
 }
 
 #[cfg(test)]
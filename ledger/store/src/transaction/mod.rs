@@ -25,6 +25,7 @@ use crate::{
     atomic_batch_scope,
     cow_to_copied,
     helpers::{Map, MapRead},
+    IntegrityReport,
     TransitionStorage,
     TransitionStore,
 };
@@ -194,6 +195,49 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proofs and transition payloads for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+        // Prune the transaction.
+        match transaction_type {
+            // Prune the deployment transaction.
+            TransactionType::Deploy => self.deployment_store().prune(transaction_id),
+            // Prune the execution transaction.
+            TransactionType::Execute => self.execution_store().prune(transaction_id),
+            // Prune the fee transaction.
+            TransactionType::Fee => self.fee_store().prune(transaction_id),
+        }
+    }
+
+    /// Checks that each transaction is in the storage of its type, and checks the indexes of each storage.
+    /// If `repair` is `true`, the inconsistent index entries are rewritten from their source maps.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Ensure each transaction is in the storage of its type.
+        let mut num_transactions = 0;
+        for (transaction_id, transaction_type) in self.id_map().iter_confirmed() {
+            num_transactions += 1;
+            let exists = match *transaction_type {
+                TransactionType::Deploy => self.deployment_store().get_program_id(&transaction_id)?.is_some(),
+                TransactionType::Execute => self.execution_store().contains_transaction_id(&transaction_id)?,
+                TransactionType::Fee => self.fee_store().contains_transaction_id(&transaction_id)?,
+            };
+            if !exists {
+                let message = format!("Missing contents of transaction '{}'", *transaction_id);
+                report.add_issue("Transaction(ID)", message, false);
+            }
+        }
+        report.add_checked("Transaction(ID)", num_transactions);
+
+        // Check the indexes of each storage.
+        self.deployment_store().check_integrity(report, repair)?;
+        self.execution_store().check_integrity(report, repair)?;
+        self.fee_store().check_integrity(report, repair)
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -262,6 +306,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and transition payloads for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -311,6 +360,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the transaction indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
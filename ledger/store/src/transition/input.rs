@@ -15,6 +15,7 @@
 use crate::{
     atomic_batch_scope,
     helpers::{Map, MapRead},
+    IntegrityReport,
 };
 use console::{
     network::prelude::*,
@@ -217,6 +218,80 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the input values for the given `transition ID`.
+    /// The input IDs, serial numbers, and tags are kept, as they are needed for consensus.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids = self.get_ids(transition_id)?;
+
+        atomic_batch_scope!(self, {
+            // Remove the input values.
+            for input_id in input_ids {
+                self.constant_map().remove(&input_id)?;
+                self.public_map().remove(&input_id)?;
+                self.private_map().remove(&input_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Checks the reverse input IDs and record tags against the input IDs and records,
+    /// and ensures each input value belongs to an input ID.
+    /// If `repair` is `true`, the inconsistent entries are rewritten from their source maps,
+    /// and the dangling input values are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Check the reverse input IDs.
+        let expected: Vec<_> = self
+            .id_map()
+            .iter_confirmed()
+            .flat_map(|(transition_id, input_ids)| {
+                input_ids.iter().map(|input_id| (*input_id, *transition_id)).collect::<Vec<_>>()
+            })
+            .collect();
+        report.check_index(
+            "TransitionInput(ReverseID)",
+            self.reverse_id_map(),
+            expected,
+            |input_id, transition_id| {
+                Ok(self.id_map().get_confirmed(transition_id)?.is_some_and(|ids| ids.contains(input_id)))
+            },
+            repair,
+        )?;
+
+        // Check the record tags.
+        let expected: Vec<_> = self.record_map().iter_confirmed().map(|(serial, tag)| (*tag, *serial)).collect();
+        report.check_index(
+            "TransitionInput(RecordTag)",
+            self.record_tag_map(),
+            expected,
+            |tag, serial_number| Ok(self.record_map().get_confirmed(serial_number)?.is_some_and(|t| *t == *tag)),
+            repair,
+        )?;
+
+        // Ensure each input value belongs to an input ID.
+        // Note: Missing values are not reported, as pruning removes the input values.
+        macro_rules! check_values {
+            ($name:literal, $map:expr) => {
+                report.check_index(
+                    $name,
+                    $map,
+                    [],
+                    |input_id, _| self.reverse_id_map().contains_key_confirmed(input_id),
+                    repair,
+                )?
+            };
+        }
+        check_values!("TransitionInput(Constant)", self.constant_map());
+        check_values!("TransitionInput(Public)", self.public_map());
+        check_values!("TransitionInput(Private)", self.private_map());
+        check_values!("TransitionInput(Record)", self.record_map());
+        check_values!("TransitionInput(ExternalRecord)", self.external_record_map());
+        report.add_checked("TransitionInput(ID)", self.id_map().len_confirmed());
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -344,6 +419,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -383,6 +463,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the input indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
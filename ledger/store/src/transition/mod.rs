@@ -18,11 +18,15 @@ pub use input::*;
 mod output;
 pub use output::*;
 
+mod position;
+pub use position::*;
+
 use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+    IntegrityReport,
 };
 use console::{
     network::prelude::*,
@@ -53,6 +57,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
     /// The signer commitments.
     type SCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
+    /// The mapping of `(program ID, function name)` to `transition position` to `transition ID`.
+    type FunctionIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -73,6 +79,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
     /// Returns the signer commitments map.
     fn scm_map(&self) -> &Self::SCMMap;
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -90,6 +98,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
         self.scm_map().start_atomic();
+        self.function_index_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -102,6 +111,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
             || self.scm_map().is_atomic_in_progress()
+            || self.function_index_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +124,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
         self.scm_map().atomic_checkpoint();
+        self.function_index_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -126,6 +137,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
         self.scm_map().clear_latest_checkpoint();
+        self.function_index_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -138,6 +150,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
         self.scm_map().atomic_rewind();
+        self.function_index_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -150,6 +163,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
         self.scm_map().abort_atomic();
+        self.function_index_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -161,7 +175,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
         self.reverse_tcm_map().finish_atomic()?;
-        self.scm_map().finish_atomic()
+        self.scm_map().finish_atomic()?;
+        self.function_index_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -225,6 +240,122 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the input and output payloads for the given `transition ID`.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Checks the transition indexes against the transitions, and the inputs and outputs indexes.
+    /// If `repair` is `true`, the inconsistent index entries are rewritten from their source maps.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Ensure each transition has its `tpk`, `tcm`, and `scm`.
+        let mut num_transitions = 0;
+        for transition_id in self.locator_map().keys_confirmed() {
+            num_transitions += 1;
+            if !self.tpk_map().contains_key_confirmed(&transition_id)? {
+                report.add_issue("Transition(TPK)", format!("Missing 'tpk' for transition '{transition_id}'"), false);
+            }
+            if !self.tcm_map().contains_key_confirmed(&transition_id)? {
+                report.add_issue("Transition(TCM)", format!("Missing 'tcm' for transition '{transition_id}'"), false);
+            }
+            if !self.scm_map().contains_key_confirmed(&transition_id)? {
+                report.add_issue("Transition(SCM)", format!("Missing 'scm' for transition '{transition_id}'"), false);
+            }
+        }
+        report.add_checked("Transition(Locator)", num_transitions);
+
+        // Check the reverse `tpk` entries.
+        let expected: Vec<_> = self.tpk_map().iter_confirmed().map(|(id, tpk)| (*tpk, *id)).collect();
+        report.check_index(
+            "Transition(ReverseTPK)",
+            self.reverse_tpk_map(),
+            expected,
+            |tpk, transition_id| Ok(self.tpk_map().get_confirmed(transition_id)?.is_some_and(|t| *t == *tpk)),
+            repair,
+        )?;
+
+        // Check the reverse `tcm` entries.
+        let expected: Vec<_> = self.tcm_map().iter_confirmed().map(|(id, tcm)| (*tcm, *id)).collect();
+        report.check_index(
+            "Transition(ReverseTCM)",
+            self.reverse_tcm_map(),
+            expected,
+            |tcm, transition_id| Ok(self.tcm_map().get_confirmed(transition_id)?.is_some_and(|t| *t == *tcm)),
+            repair,
+        )?;
+
+        // Check the inputs and outputs.
+        self.input_store().check_integrity(report, repair)?;
+        self.output_store().check_integrity(report, repair)
+    }
+
+    /// Checks the function index against the given entries, which are derived from the blocks.
+    /// The transitions of the pruned blocks, up to the given height, cannot be retrieved, so their entries
+    /// are checked against the transition program IDs and function names instead.
+    /// If `repair` is `true`, the inconsistent entries are rewritten.
+    #[allow(clippy::type_complexity)]
+    fn check_function_index(
+        &self,
+        report: &mut IntegrityReport,
+        expected: Vec<((ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID)>,
+        pruned_height: Option<u32>,
+        repair: bool,
+    ) -> Result<()> {
+        report.check_nested_index(
+            "Transition(FunctionIndex)",
+            self.function_index_map(),
+            expected,
+            |locator, position, transition_id| {
+                let is_pruned = pruned_height.is_some_and(|height| position.block_height() <= height);
+                Ok(is_pruned && self.locator_map().get_confirmed(transition_id)?.is_some_and(|l| *l == *locator))
+            },
+            repair,
+        )
+    }
+
+    /// Stores the function index entries for the given `transitions`, in the block at the given height.
+    /// The transitions are indexed by their program ID and function name, in the given order.
+    fn insert_function_index<'a>(
+        &self,
+        block_height: u32,
+        transitions: impl Iterator<Item = &'a Transition<N>>,
+    ) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (index, transition) in transitions.enumerate() {
+                self.function_index_map().insert(
+                    (*transition.program_id(), *transition.function_name()),
+                    TransitionPosition::new(block_height, u32::try_from(index)?),
+                    *transition.id(),
+                )?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the function index entries for the given `transitions`, in the block at the given height.
+    fn remove_function_index<'a>(
+        &self,
+        block_height: u32,
+        transitions: impl Iterator<Item = &'a Transition<N>>,
+    ) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (index, transition) in transitions.enumerate() {
+                self.function_index_map().remove_key(
+                    &(*transition.program_id(), *transition.function_name()),
+                    &TransitionPosition::new(block_height, u32::try_from(index)?),
+                )?;
+            }
+            Ok(())
+        })
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -285,6 +416,8 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     reverse_tcm: T::ReverseTCMMap,
     /// The map of signer commitments.
     scm: T::SCMMap,
+    /// The function index map.
+    function_index: T::FunctionIndexMap,
     /// The transition storage.
     storage: T,
 }
@@ -304,6 +437,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            function_index: storage.function_index_map().clone(),
             storage,
         })
     }
@@ -319,6 +453,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            function_index: storage.function_index_map().clone(),
             storage,
         }
     }
@@ -333,6 +468,29 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input and output payloads for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
+    /// Stores the function index entries for the given `transitions`, in the block at the given height.
+    pub fn insert_function_index<'a>(
+        &self,
+        block_height: u32,
+        transitions: impl Iterator<Item = &'a Transition<N>>,
+    ) -> Result<()> {
+        self.storage.insert_function_index(block_height, transitions)
+    }
+
+    /// Removes the function index entries for the given `transitions`, in the block at the given height.
+    pub fn remove_function_index<'a>(
+        &self,
+        block_height: u32,
+        transitions: impl Iterator<Item = &'a Transition<N>>,
+    ) -> Result<()> {
+        self.storage.remove_function_index(block_height, transitions)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -372,6 +530,23 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the transition indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
+
+    /// Checks the function index against the given entries, repairing it if `repair` is `true`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn check_function_index(
+        &self,
+        report: &mut IntegrityReport,
+        expected: Vec<((ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID)>,
+        pruned_height: Option<u32>,
+        repair: bool,
+    ) -> Result<()> {
+        self.storage.check_function_index(report, expected, pruned_height, repair)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -412,6 +587,23 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         }))
     }
 
+    /// Returns the number of indexed transitions for the given program ID and function name.
+    pub fn num_function_transitions(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<usize> {
+        self.function_index.len_map_confirmed(&(*program_id, *function_name))
+    }
+
+    /// Returns at most `limit` of the `(position, transition ID)` pairs for the given program ID and function name,
+    /// starting from the given position, in the order they were included in the ledger.
+    pub fn get_function_transitions(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        start: TransitionPosition,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.function_index.get_map_range_confirmed(&(*program_id, *function_name), &start, limit)
+    }
+
     /// Returns the input IDs for the given `transition ID`.
     pub fn get_input_ids(&self, transition_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
         self.inputs.get_input_ids(transition_id)
@@ -15,6 +15,7 @@
 use crate::{
     atomic_batch_scope,
     helpers::{Map, MapRead},
+    IntegrityReport,
 };
 use console::{
     network::prelude::*,
@@ -234,6 +235,99 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the output values and record ciphertexts for the given `transition ID`.
+    /// The output IDs, commitments, checksums, and record nonces are kept, as they are needed for consensus.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids = self.get_ids(transition_id)?;
+
+        atomic_batch_scope!(self, {
+            // Remove the output values.
+            for output_id in output_ids {
+                self.constant_map().remove(&output_id)?;
+                self.public_map().remove(&output_id)?;
+                self.private_map().remove(&output_id)?;
+                self.future_map().remove(&output_id)?;
+
+                // If the output is a record, remove the record ciphertext.
+                if let Some(record) = self.record_map().get_confirmed(&output_id)? {
+                    let checksum = match record {
+                        Cow::Borrowed((checksum, _)) => *checksum,
+                        Cow::Owned((checksum, _)) => checksum,
+                    };
+                    self.record_map().insert(output_id, (checksum, None))?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Checks the reverse output IDs and record nonces against the output IDs and records,
+    /// and ensures each output value belongs to an output ID.
+    /// If `repair` is `true`, the inconsistent entries are rewritten from their source maps,
+    /// and the dangling output values are removed.
+    fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        // Check the reverse output IDs.
+        let expected: Vec<_> = self
+            .id_map()
+            .iter_confirmed()
+            .flat_map(|(transition_id, output_ids)| {
+                output_ids.iter().map(|output_id| (*output_id, *transition_id)).collect::<Vec<_>>()
+            })
+            .collect();
+        report.check_index(
+            "TransitionOutput(ReverseID)",
+            self.reverse_id_map(),
+            expected,
+            |output_id, transition_id| {
+                Ok(self.id_map().get_confirmed(transition_id)?.is_some_and(|ids| ids.contains(output_id)))
+            },
+            repair,
+        )?;
+
+        // Check the record nonces.
+        let expected: Vec<_> = self
+            .record_map()
+            .iter_confirmed()
+            .filter_map(|(commitment, entry)| entry.1.as_ref().map(|record| (*record.nonce(), *commitment)))
+            .collect();
+        report.check_index(
+            "TransitionOutput(RecordNonce)",
+            self.record_nonce_map(),
+            expected,
+            // Note: The nonce of a pruned record is kept, so it is only checked that its commitment exists.
+            |nonce, commitment| match self.record_map().get_confirmed(commitment)? {
+                Some(entry) => Ok(entry.1.as_ref().map_or(true, |record| record.nonce() == nonce)),
+                None => Ok(false),
+            },
+            repair,
+        )?;
+
+        // Ensure each output value belongs to an output ID.
+        // Note: Missing values are not reported, as pruning removes the output values.
+        macro_rules! check_values {
+            ($name:literal, $map:expr) => {
+                report.check_index(
+                    $name,
+                    $map,
+                    [],
+                    |output_id, _| self.reverse_id_map().contains_key_confirmed(output_id),
+                    repair,
+                )?
+            };
+        }
+        check_values!("TransitionOutput(Constant)", self.constant_map());
+        check_values!("TransitionOutput(Public)", self.public_map());
+        check_values!("TransitionOutput(Private)", self.private_map());
+        check_values!("TransitionOutput(Record)", self.record_map());
+        check_values!("TransitionOutput(ExternalRecord)", self.external_record_map());
+        check_values!("TransitionOutput(Future)", self.future_map());
+        report.add_checked("TransitionOutput(ID)", self.id_map().len_confirmed());
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -367,6 +461,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output values and record ciphertexts for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -406,6 +505,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
     }
+
+    /// Checks the output indexes, repairing them if `repair` is `true`.
+    pub(crate) fn check_integrity(&self, report: &mut IntegrityReport, repair: bool) -> Result<()> {
+        self.storage.check_integrity(report, repair)
+    }
 }
 
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
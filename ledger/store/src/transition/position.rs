@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The position of a transition in the ledger, i.e. its block height and its position in the block.
+///
+/// The position is serialized in big-endian byte order, so that the positions of the stored transitions
+/// are ordered by their serialized bytes, and a range of them can be read from a given position.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TransitionPosition {
+    /// The height of the block that contains the transition.
+    block_height: u32,
+    /// The position of the transition in the block.
+    index: u32,
+}
+
+impl TransitionPosition {
+    /// Initializes a new transition position.
+    pub const fn new(block_height: u32, index: u32) -> Self {
+        Self { block_height, index }
+    }
+
+    /// Returns the height of the block that contains the transition.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the position of the transition in the block.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the position that immediately follows this one.
+    pub const fn next(&self) -> Self {
+        match self.index.checked_add(1) {
+            Some(index) => Self::new(self.block_height, index),
+            None => Self::new(self.block_height.saturating_add(1), 0),
+        }
+    }
+}
+
+impl Serialize for TransitionPosition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.block_height.to_be_bytes(), self.index.to_be_bytes()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransitionPosition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (block_height, index) = <([u8; 4], [u8; 4])>::deserialize(deserializer)?;
+        Ok(Self::new(u32::from_be_bytes(block_height), u32::from_be_bytes(index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialized_order() {
+        let positions = [
+            TransitionPosition::new(0, 0),
+            TransitionPosition::new(0, 1),
+            TransitionPosition::new(0, 256),
+            TransitionPosition::new(1, 0),
+            TransitionPosition::new(256, 0),
+            TransitionPosition::new(u32::MAX, u32::MAX),
+        ];
+        // Ensure the positions round-trip, and their serialized bytes are in the same order as the positions.
+        let serialized: Vec<_> = positions.iter().map(|position| bincode::serialize(position).unwrap()).collect();
+        for (position, bytes) in positions.iter().zip(&serialized) {
+            assert_eq!(*position, bincode::deserialize::<TransitionPosition>(bytes).unwrap());
+        }
+        assert!(serialized.windows(2).all(|pair| pair[0] < pair[1]));
+        // Ensure the next position follows the position.
+        assert_eq!(TransitionPosition::new(1, 2).next(), TransitionPosition::new(1, 3));
+        assert_eq!(TransitionPosition::new(1, u32::MAX).next(), TransitionPosition::new(2, 0));
+    }
+}
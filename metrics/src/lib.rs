@@ -14,17 +14,41 @@
 
 #![forbid(unsafe_code)]
 
-const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+use ::metrics::SharedString;
+
+const COUNTER_NAMES: [&str; 3] = [store::WRITTEN_BYTES, store::BLOCK_CACHE_HITS, store::BLOCK_CACHE_MISSES];
+const GAUGE_NAMES: [&str; 4] = [committee::TOTAL_STAKE, store::MAP_KEYS, store::MAP_SIZE, store::BLOCK_CACHE_HIT_RATE];
+const HISTOGRAM_NAMES: [&str; 4] =
+    [store::ATOMIC_BATCH_OPERATIONS, store::ATOMIC_BATCH_SIZE, store::ATOMIC_BATCH_LATENCY, store::MAP_BATCH_OPERATIONS];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod store {
+    pub const MAP_KEYS: &str = "snarkvm_ledger_store_map_keys";
+    pub const MAP_SIZE: &str = "snarkvm_ledger_store_map_size_bytes";
+    pub const MAP_BATCH_OPERATIONS: &str = "snarkvm_ledger_store_map_batch_operations";
+    pub const ATOMIC_BATCH_OPERATIONS: &str = "snarkvm_ledger_store_atomic_batch_operations";
+    pub const ATOMIC_BATCH_SIZE: &str = "snarkvm_ledger_store_atomic_batch_size_bytes";
+    pub const ATOMIC_BATCH_LATENCY: &str = "snarkvm_ledger_store_atomic_batch_latency_secs";
+    pub const WRITTEN_BYTES: &str = "snarkvm_ledger_store_written_bytes";
+    pub const BLOCK_CACHE_HITS: &str = "snarkvm_ledger_store_block_cache_hits";
+    pub const BLOCK_CACHE_MISSES: &str = "snarkvm_ledger_store_block_cache_misses";
+    pub const BLOCK_CACHE_HIT_RATE: &str = "snarkvm_ledger_store_block_cache_hit_rate";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -52,6 +76,15 @@ pub fn increment_counter(name: &'static str) {
     counter.increment(1);
 }
 
+/// Increments a counter with the given name by the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn increment_counter_by<V: Into<u64>>(name: &'static str, value: V) {
+    let counter = ::metrics::counter!(name);
+    counter.increment(value.into());
+}
+
 /******** Gauge ********/
 
 /// Registers a gauge with the given name.
@@ -68,6 +101,19 @@ pub fn gauge<V: Into<f64>>(name: &'static str, value: V) {
     gauge.set(value.into());
 }
 
+/// Updates a gauge with the given name and label to the given value.
+///
+/// Gauges represent a single value that can go up or down over time,
+/// and always starts out with an initial value of zero.
+pub fn gauge_label<L: Into<SharedString>, V: Into<f64>>(
+    name: &'static str,
+    label_key: &'static str,
+    label_value: L,
+    value: V,
+) {
+    ::metrics::gauge!(name, label_key => label_value).set(value.into());
+}
+
 /// Increments a gauge with the given name by the given value.
 ///
 /// Gauges represent a single value that can go up or down over time,
@@ -99,6 +145,126 @@ pub fn histogram<V: Into<f64>>(name: &'static str, value: V) {
     histogram.record(value.into());
 }
 
-pub fn histogram_label<V: Into<f64>>(name: &'static str, label_key: &'static str, label_value: String, value: V) {
+pub fn histogram_label<L: Into<SharedString>, V: Into<f64>>(
+    name: &'static str,
+    label_key: &'static str,
+    label_value: L,
+    value: V,
+) {
     ::metrics::histogram!(name, label_key => label_value).record(value.into());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::metrics::{Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, Unit};
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    /// A recorder that keeps the latest value of each metric, keyed by its name and labels.
+    #[derive(Clone, Default)]
+    struct TestRecorder(Arc<Mutex<BTreeMap<String, f64>>>);
+
+    /// A handle to a metric of the test recorder.
+    struct TestHandle(Arc<Mutex<BTreeMap<String, f64>>>, String);
+
+    impl TestRecorder {
+        /// Returns the latest value of the metric with the given name and labels.
+        fn value(&self, key: &str) -> Option<f64> {
+            self.0.lock().unwrap().get(key).copied()
+        }
+
+        /// Returns a handle to the metric with the given key.
+        fn handle(&self, key: &Key) -> Arc<TestHandle> {
+            let labels = key.labels().map(|label| format!("{}={}", label.key(), label.value())).collect::<Vec<_>>();
+            let name = match labels.is_empty() {
+                true => key.name().to_string(),
+                false => format!("{}{{{}}}", key.name(), labels.join(",")),
+            };
+            Arc::new(TestHandle(self.0.clone(), name))
+        }
+    }
+
+    impl TestHandle {
+        fn update(&self, f: impl FnOnce(f64) -> f64) {
+            let mut values = self.0.lock().unwrap();
+            let value = values.entry(self.1.clone()).or_default();
+            *value = f(*value);
+        }
+    }
+
+    impl CounterFn for TestHandle {
+        fn increment(&self, value: u64) {
+            self.update(|current| current + value as f64)
+        }
+
+        fn absolute(&self, value: u64) {
+            self.update(|current| current.max(value as f64))
+        }
+    }
+
+    impl GaugeFn for TestHandle {
+        fn increment(&self, value: f64) {
+            self.update(|current| current + value)
+        }
+
+        fn decrement(&self, value: f64) {
+            self.update(|current| current - value)
+        }
+
+        fn set(&self, value: f64) {
+            self.update(|_| value)
+        }
+    }
+
+    impl HistogramFn for TestHandle {
+        fn record(&self, value: f64) {
+            self.update(|_| value)
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+
+        fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.handle(key))
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    #[test]
+    fn test_recorder() {
+        let recorder = TestRecorder::default();
+
+        ::metrics::with_local_recorder(&recorder, || {
+            increment_counter_by(store::WRITTEN_BYTES, 10u64);
+            increment_counter_by(store::WRITTEN_BYTES, 5u64);
+            counter(store::BLOCK_CACHE_HITS, 3u64);
+            gauge(store::BLOCK_CACHE_HIT_RATE, 0.75);
+            gauge_label(store::MAP_KEYS, "map", "BlockIDMap", 2.0);
+            gauge_label(store::MAP_KEYS, "map", "BlockHeaderMap".to_string(), 4.0);
+            histogram_label(store::MAP_BATCH_OPERATIONS, "map", "BlockIDMap", 7.0);
+        });
+
+        assert_eq!(recorder.value(store::WRITTEN_BYTES), Some(15.0));
+        assert_eq!(recorder.value(store::BLOCK_CACHE_HITS), Some(3.0));
+        assert_eq!(recorder.value(store::BLOCK_CACHE_HIT_RATE), Some(0.75));
+        assert_eq!(recorder.value(&format!("{}{{map=BlockIDMap}}", store::MAP_KEYS)), Some(2.0));
+        assert_eq!(recorder.value(&format!("{}{{map=BlockHeaderMap}}", store::MAP_KEYS)), Some(4.0));
+        assert_eq!(recorder.value(&format!("{}{{map=BlockIDMap}}", store::MAP_BATCH_OPERATIONS)), Some(7.0));
+        assert_eq!(recorder.value(store::MAP_KEYS), None);
+    }
+}
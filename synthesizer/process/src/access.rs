@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{Argument, Future, Register};
+use synthesizer_program::{CallOperator, Operand};
+
+use indexmap::IndexSet;
+
+/// A mapping entry, as (`program ID`, `mapping name`, `key`).
+pub type MappingEntry<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>);
+
+/// The mapping entries that the finalize logic of a transaction may read from and write to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessSet<N: Network> {
+    /// The mapping entries that may be read.
+    reads: Vec<MappingEntry<N>>,
+    /// The mapping entries that may be written to.
+    writes: Vec<MappingEntry<N>>,
+}
+
+impl<N: Network> AccessSet<N> {
+    /// Initializes a new, empty access set.
+    pub fn new() -> Self {
+        Self { reads: Vec::new(), writes: Vec::new() }
+    }
+
+    /// Returns the mapping entries that may be read.
+    pub fn reads(&self) -> &[MappingEntry<N>] {
+        &self.reads
+    }
+
+    /// Returns the mapping entries that may be written to.
+    pub fn writes(&self) -> &[MappingEntry<N>] {
+        &self.writes
+    }
+
+    /// Returns all mapping entries that may be accessed.
+    pub fn entries(&self) -> impl Iterator<Item = &MappingEntry<N>> {
+        self.reads.iter().chain(self.writes.iter().filter(|entry| !self.reads.contains(*entry)))
+    }
+
+    /// Returns the distinct mappings, as (`program ID`, `mapping name`), that may be accessed.
+    pub fn mappings(&self) -> IndexSet<(ProgramID<N>, Identifier<N>)> {
+        self.entries().map(|(program_id, mapping_name, _)| (*program_id, *mapping_name)).collect()
+    }
+
+    /// Adds the given mapping entry to the read set.
+    pub fn insert_read(&mut self, entry: MappingEntry<N>) {
+        if !self.reads.contains(&entry) {
+            self.reads.push(entry);
+        }
+    }
+
+    /// Adds the given mapping entry to the write set.
+    pub fn insert_write(&mut self, entry: MappingEntry<N>) {
+        if !self.writes.contains(&entry) {
+            self.writes.push(entry);
+        }
+    }
+
+    /// Returns `true` if the finalize logic of the two access sets may not be reordered,
+    /// i.e. if either one writes to a mapping entry that the other reads from or writes to.
+    pub fn is_conflicting(&self, other: &Self) -> bool {
+        self.writes.iter().any(|entry| other.reads.contains(entry) || other.writes.contains(entry))
+            || other.writes.iter().any(|entry| self.reads.contains(entry))
+    }
+}
+
+impl<N: Network> Default for AccessSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the mapping entries that the finalize logic of the given execution and fee may access.
+    ///
+    /// The access set is derived from the `Future` arguments of the execution and fee, and is an over-approximation,
+    /// as every `get`, `get.or_use`, `contains`, `set`, and `remove` command is included regardless of branching.
+    /// Returns `None` if a mapping key cannot be determined statically, i.e. it is not a literal or a finalize input.
+    pub fn finalize_access_set(&self, execution: &Execution<N>, fee: Option<&Fee<N>>) -> Result<Option<AccessSet<N>>> {
+        // Initialize the access set.
+        let mut access_set = AccessSet::new();
+        // Collect the top-level futures of the execution and fee.
+        let execution_future = execution.peek()?.outputs().last().and_then(|output| output.future());
+        let fee_future = fee.and_then(|fee| fee.outputs().last().and_then(|output| output.future()));
+        // Initialize a stack of futures to analyze.
+        let mut futures = execution_future.into_iter().chain(fee_future).collect::<Vec<_>>();
+        // Analyze each future, including the futures passed to it as arguments.
+        while let Some(future) = futures.pop() {
+            if !self.add_future_accesses(future, &mut access_set)? {
+                return Ok(None);
+            }
+            futures.extend(future.arguments().iter().filter_map(|argument| match argument {
+                Argument::Future(future) => Some(future),
+                Argument::Plaintext(_) => None,
+            }));
+        }
+        Ok(Some(access_set))
+    }
+
+    /// Adds the mapping entries that the finalize logic of the given future may access to the access set.
+    /// Returns `false` if a mapping key cannot be determined statically.
+    fn add_future_accesses(&self, future: &Future<N>, access_set: &mut AccessSet<N>) -> Result<bool> {
+        // Retrieve the stack.
+        let stack = self.get_stack(future.program_id())?;
+        // Retrieve the finalize logic.
+        let Some(finalize) = stack.get_function_ref(future.function_name())?.finalize_logic() else {
+            bail!(
+                "The function '{}/{}' does not have an associated finalize block",
+                future.program_id(),
+                future.function_name()
+            )
+        };
+        // Map the finalize input registers to their arguments.
+        let inputs = finalize
+            .inputs()
+            .iter()
+            .map(|input| input.register().locator())
+            .zip_eq(future.arguments())
+            .collect::<HashMap<_, _>>();
+
+        // Resolves the given key operand to a plaintext value, if possible.
+        let resolve_key = |operand: &Operand<N>| -> Result<Option<Plaintext<N>>> {
+            match operand {
+                Operand::Literal(literal) => Ok(Some(Plaintext::from(literal))),
+                Operand::ProgramID(program_id) => Ok(Some(Plaintext::from(Literal::Address(program_id.to_address()?)))),
+                Operand::Register(register) => match (inputs.get(&register.locator()), register) {
+                    (Some(Argument::Plaintext(plaintext)), Register::Locator(_)) => Ok(Some(plaintext.clone())),
+                    (Some(Argument::Plaintext(plaintext)), Register::Access(_, path)) => Ok(plaintext.find(path).ok()),
+                    _ => Ok(None),
+                },
+                _ => Ok(None),
+            }
+        };
+        // Resolves the given mapping operator to a (program ID, mapping name) pair.
+        let resolve_mapping = |operator: &CallOperator<N>| match operator {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), *mapping_name),
+        };
+
+        for command in finalize.commands() {
+            let ((program_id, mapping_name), key, is_write) = match command {
+                Command::Contains(contains) => {
+                    (resolve_mapping(contains.mapping()), resolve_key(contains.key())?, false)
+                }
+                Command::Get(get) => (resolve_mapping(get.mapping()), resolve_key(get.key())?, false),
+                Command::GetOrUse(get_or_use) => {
+                    (resolve_mapping(get_or_use.mapping()), resolve_key(get_or_use.key())?, false)
+                }
+                Command::Set(set) => ((*stack.program_id(), *set.mapping_name()), resolve_key(set.key())?, true),
+                Command::Remove(remove) => {
+                    ((*stack.program_id(), *remove.mapping_name()), resolve_key(remove.key())?, true)
+                }
+                _ => continue,
+            };
+            // If the key cannot be determined statically, the access set is unknown.
+            let Some(key) = key else {
+                return Ok(false);
+            };
+            match is_write {
+                true => access_set.insert_write((program_id, mapping_name, key)),
+                false => access_set.insert_read((program_id, mapping_name, key)),
+            }
+        }
+        Ok(true)
+    }
+}
@@ -29,7 +29,8 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
         // Evaluate the function.
-        let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization)?, None);
+        let call_stack = CallStack::evaluate(authorization)?;
+        let response = self.observe(|| stack.evaluate_function::<A>(call_stack, None));
         lap!(timer, "Evaluate the function");
 
         finish!(timer);
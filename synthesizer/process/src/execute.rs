@@ -37,7 +37,9 @@ impl<N: Network> Process<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let mut trace = Trace::new();
+        trace.set_dev_mode(self.dev_mode);
+        let trace = Arc::new(RwLock::new(trace));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
@@ -45,7 +47,7 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
         // Execute the circuit.
-        let response = stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
+        let response = self.observe(|| stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng))?;
         lap!(timer, "Execute the function");
 
         // Extract the trace.
@@ -13,8 +13,9 @@
 // limitations under the License.
 
 use super::*;
+use crate::{current_observer, ExecutionPhase, ExecutionStep};
 use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use synthesizer_program::{Await, CommandTrait, FinalizeRegistersState, Operand};
 use utilities::handle_halting;
 
 use std::collections::HashSet;
@@ -109,7 +110,8 @@ impl<N: Network> Process<N> {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let mut finalize_operations =
+                self.observe(|| finalize_transition(state, store, stack, transition, call_graph))?;
 
             /* Finalize the fee. */
 
@@ -117,7 +119,7 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(self.observe(|| finalize_fee_transition(state, store, fee_stack, fee))?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
@@ -143,7 +145,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = self.observe(|| finalize_fee_transition(state, store, stack, fee));
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -172,13 +174,37 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     }
 }
 
-/// Finalizes the given transition.
+/// Finalizes the given transition, and notifies the execution observer, if one is set, of a failed command.
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    // Retrieve the execution observer, if one is set.
+    let observer = current_observer::<N>();
+    // Initialize a tracker for the command in progress, which is reported if the finalize fails.
+    let mut pending_step = None;
+    // Finalize the transition.
+    let result =
+        finalize_transition_commands(state, store, stack, transition, call_graph, &observer, &mut pending_step);
+    // If a command failed, notify the observer.
+    if let (Some(observer), Some(step), Err(error)) = (&observer, &pending_step, &result) {
+        observer.on_error(step, error);
+    }
+    result
+}
+
+/// Finalizes the given transition, tracking the command in progress for the execution observer.
+fn finalize_transition_commands<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    transition: &Transition<N>,
+    call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    observer: &Option<Arc<dyn ExecutionObserver<N>>>,
+    pending_step: &mut Option<ExecutionStep<N>>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
@@ -224,6 +250,21 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // If an observer is set, notify it of the step.
+            *pending_step = observer.as_ref().map(|observer| {
+                let step = ExecutionStep::new(
+                    ExecutionPhase::Finalize,
+                    *stack.program_id(),
+                    *finalize.name(),
+                    counter,
+                    command.opcode(),
+                    command.operands(),
+                    command.destinations(),
+                    |operand| registers.load(stack, operand),
+                );
+                observer.before_step(&step);
+                step
+            });
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -288,6 +329,11 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     };
 
+                    // If an observer is set, notify it of the outcome, before the callee is finalized.
+                    if let (Some(observer), Some(step)) = (observer, pending_step.take()) {
+                        observer.after_step(&step, &[]);
+                    }
+
                     // Increment the call counter.
                     call_counter += 1;
                     // Increment the counter.
@@ -321,6 +367,11 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
                 }
             };
+
+            // If an observer is set, notify it of the outcome.
+            if let (Some(observer), Some(step)) = (observer, pending_step.take()) {
+                observer.after_step(&step, &step.outputs(|operand| registers.load(stack, operand)));
+            }
         }
         // Check that all future registers have been awaited.
         let mut unawaited = Vec::new();
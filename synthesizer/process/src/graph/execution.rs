@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Returns the call graph of the given execution.
+    ///
+    /// The graph contains a node for every transition in the execution, and an edge from every
+    /// transition to the transitions it calls. Each node is annotated with its finalize cost and its
+    /// number of constraints, which are taken from the given call metrics if provided, and from the
+    /// verifying key of the function otherwise.
+    pub fn execution_graph(
+        &self,
+        execution: &Execution<N>,
+        call_metrics: Option<&[CallMetrics<N>]>,
+    ) -> Result<DependencyGraph> {
+        // Ensure the call metrics correspond to the transitions, if they are provided.
+        if let Some(call_metrics) = call_metrics {
+            ensure!(
+                call_metrics.len() == execution.len(),
+                "Expected {} call metrics for the execution, found {}",
+                execution.len(),
+                call_metrics.len()
+            );
+        }
+
+        // Reconstruct the call graph.
+        let call_graph = self.construct_call_graph(execution)?;
+
+        let mut graph = DependencyGraph::new(GraphKind::Execution);
+        for (index, transition) in execution.transitions().enumerate() {
+            let stack = self.get_stack(transition.program_id())?;
+            // Retrieve the number of constraints.
+            let num_constraints = match call_metrics {
+                Some(call_metrics) => {
+                    let metrics = &call_metrics[index];
+                    ensure!(
+                        metrics.program_id == *transition.program_id()
+                            && metrics.function_name == *transition.function_name(),
+                        "Call metrics for '{}/{}' do not match transition '{}/{}'",
+                        metrics.program_id,
+                        metrics.function_name,
+                        transition.program_id(),
+                        transition.function_name()
+                    );
+                    Some(metrics.num_function_constraints)
+                }
+                None => num_constraints(stack, transition.function_name()),
+            };
+            // Add the transition node.
+            graph.nodes.push(GraphNode {
+                id: transition.id().to_string(),
+                label: format!("{}/{}", transition.program_id(), transition.function_name()),
+                program_id: Some(transition.program_id().to_string()),
+                num_constraints,
+                finalize_cost: finalize_cost(stack, transition.function_name())?,
+            });
+            // Add the call edges, in the order the calls are made.
+            let Some(children) = call_graph.get(transition.id()) else {
+                bail!("Transition '{}' is missing from the call graph", transition.id())
+            };
+            for child in children {
+                graph.edges.push(GraphEdge {
+                    source: transition.id().to_string(),
+                    target: child.to_string(),
+                    kind: EdgeKind::Call,
+                });
+            }
+        }
+        Ok(graph)
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod execution;
+mod program;
+
+use crate::{CallMetrics, Process, Stack};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use ledger_block::Execution;
+use synthesizer_program::StackProgram;
+
+use serde_json::json;
+
+/// The kind of a dependency graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GraphKind {
+    /// The import graph of a program.
+    Program,
+    /// The call graph of an execution.
+    Execution,
+}
+
+/// The kind of an edge in a dependency graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// The source program imports the target program.
+    Import,
+    /// The source function calls the target function.
+    Call,
+}
+
+/// A node in a dependency graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphNode {
+    /// The unique ID of the node.
+    pub id: String,
+    /// The label of the node, i.e. the program ID or locator.
+    pub label: String,
+    /// The program ID the node belongs to, if the node is a function.
+    pub program_id: Option<String>,
+    /// The number of constraints of the function, if known.
+    pub num_constraints: Option<u64>,
+    /// The finalize cost of the function in microcredits, if applicable.
+    pub finalize_cost: Option<u64>,
+}
+
+/// An edge in a dependency graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphEdge {
+    /// The ID of the source node.
+    pub source: String,
+    /// The ID of the target node.
+    pub target: String,
+    /// The kind of the edge.
+    pub kind: EdgeKind,
+}
+
+/// A dependency graph of programs, functions, or transitions,
+/// which may be rendered as Graphviz DOT or as JSON.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// The kind of the graph.
+    kind: GraphKind,
+    /// The nodes of the graph, in insertion order.
+    nodes: Vec<GraphNode>,
+    /// The edges of the graph, in insertion order.
+    edges: Vec<GraphEdge>,
+}
+
+impl DependencyGraph {
+    /// Initializes a new, empty dependency graph.
+    fn new(kind: GraphKind) -> Self {
+        Self { kind, nodes: Vec::new(), edges: Vec::new() }
+    }
+
+    /// Returns the kind of the graph.
+    pub const fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    /// Returns the nodes of the graph.
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the graph.
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    /// Returns the node with the given ID, if it exists.
+    pub fn get_node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the graph in the Graphviz DOT format.
+    ///
+    /// Functions are grouped into a cluster per program, imports are drawn as dashed edges
+    /// between programs, and calls are drawn as solid edges between functions.
+    pub fn to_dot(&self) -> String {
+        let name = match self.kind {
+            GraphKind::Program => "program",
+            GraphKind::Execution => "execution",
+        };
+        let mut dot = format!("digraph {name} {{\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        // Write the nodes that do not belong to a program.
+        for node in self.nodes.iter().filter(|node| node.program_id.is_none()) {
+            dot.push_str(&format!("    {};\n", node.to_dot()));
+        }
+        // Write the nodes that belong to a program, grouped by program.
+        let mut program_ids = Vec::new();
+        for program_id in self.nodes.iter().filter_map(|node| node.program_id.as_deref()) {
+            if !program_ids.contains(&program_id) {
+                program_ids.push(program_id);
+            }
+        }
+        for (index, program_id) in program_ids.into_iter().enumerate() {
+            dot.push_str(&format!("    subgraph cluster_{index} {{\n"));
+            dot.push_str(&format!("        label={};\n", quote(program_id)));
+            for node in self.nodes.iter().filter(|node| node.program_id.as_deref() == Some(program_id)) {
+                dot.push_str(&format!("        {};\n", node.to_dot()));
+            }
+            dot.push_str("    }\n");
+        }
+        // Write the edges.
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Import => " [style=dashed]",
+                EdgeKind::Call => "",
+            };
+            dot.push_str(&format!("    {} -> {}{style};\n", quote(&edge.source), quote(&edge.target)));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns the graph as JSON.
+    pub fn to_json(&self) -> serde_json::Value {
+        let kind = match self.kind {
+            GraphKind::Program => "program",
+            GraphKind::Execution => "execution",
+        };
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                json!({
+                    "id": node.id,
+                    "label": node.label,
+                    "program_id": node.program_id,
+                    "num_constraints": node.num_constraints,
+                    "finalize_cost": node.finalize_cost,
+                })
+            })
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let kind = match edge.kind {
+                    EdgeKind::Import => "import",
+                    EdgeKind::Call => "call",
+                };
+                json!({ "source": edge.source, "target": edge.target, "kind": kind })
+            })
+            .collect::<Vec<_>>();
+        json!({ "kind": kind, "nodes": nodes, "edges": edges })
+    }
+}
+
+impl GraphNode {
+    /// Returns the node as a Graphviz DOT statement.
+    fn to_dot(&self) -> String {
+        let mut label = self.label.clone();
+        if let Some(num_constraints) = self.num_constraints {
+            label.push_str(&format!("\\n{num_constraints} constraints"));
+        }
+        if let Some(finalize_cost) = self.finalize_cost {
+            label.push_str(&format!("\\nfinalize: {finalize_cost} microcredits"));
+        }
+        // Programs are drawn with a distinct shape from functions and transitions.
+        let shape = match self.program_id {
+            Some(_) => "",
+            None => ", shape=folder",
+        };
+        format!("{} [label={}{shape}]", quote(&self.id), quote(&label))
+    }
+}
+
+/// Returns the given string as a quoted DOT identifier.
+fn quote(string: &str) -> String {
+    // Note: Backslashes are intentionally kept as-is, so that `\n` line breaks are preserved in labels.
+    format!("\"{}\"", string.replace('"', "\\\""))
+}
+
+/// Returns the number of constraints of the given function, if its verifying key is available.
+fn num_constraints<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Option<u64> {
+    match stack.contains_verifying_key(function_name) {
+        true => stack.get_verifying_key(function_name).ok().map(|key| key.circuit_info.num_constraints as u64),
+        false => None,
+    }
+}
+
+/// Returns the finalize cost of the given function, if it has a finalize scope.
+fn finalize_cost<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<Option<u64>> {
+    match stack.get_function_ref(function_name)?.finalize_logic() {
+        Some(_) => Ok(Some(stack.get_finalize_cost(function_name)?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::{CallOperator, Instruction};
+
+impl<N: Network> Process<N> {
+    /// Returns the import graph of the given program.
+    ///
+    /// The graph contains a node for every program reachable through the imports of the given program,
+    /// and a node for every function in those programs, annotated with its number of constraints
+    /// (if its verifying key is available) and its finalize cost (if it has a finalize scope).
+    pub fn program_graph(&self, program_id: &ProgramID<N>) -> Result<DependencyGraph> {
+        let mut graph = DependencyGraph::new(GraphKind::Program);
+
+        // Traverse the imports in breadth-first order, starting from the given program.
+        let mut queue = vec![self.get_stack(program_id)?.clone()];
+        let mut visited = vec![*program_id];
+        while !queue.is_empty() {
+            let stack = queue.remove(0);
+            let program = stack.program();
+
+            // Add the program node.
+            graph.nodes.push(GraphNode {
+                id: program.id().to_string(),
+                label: program.id().to_string(),
+                program_id: None,
+                num_constraints: None,
+                finalize_cost: None,
+            });
+
+            // Add the import edges, and enqueue the imports that have not been visited.
+            for import_id in program.imports().keys() {
+                graph.edges.push(GraphEdge {
+                    source: program.id().to_string(),
+                    target: import_id.to_string(),
+                    kind: EdgeKind::Import,
+                });
+                if !visited.contains(import_id) {
+                    visited.push(*import_id);
+                    queue.push(stack.get_external_stack(import_id)?.clone());
+                }
+            }
+
+            // Add the function nodes and the call edges.
+            for (function_name, function) in program.functions() {
+                let id = format!("{}/{function_name}", program.id());
+                graph.nodes.push(GraphNode {
+                    id: id.clone(),
+                    label: function_name.to_string(),
+                    program_id: Some(program.id().to_string()),
+                    num_constraints: num_constraints(&stack, function_name),
+                    finalize_cost: finalize_cost(&stack, function_name)?,
+                });
+                for instruction in function.instructions() {
+                    // Note: Calls to closures are inlined into the function, and are therefore not drawn.
+                    if let Instruction::Call(call) = instruction {
+                        if let CallOperator::Locator(locator) = call.operator() {
+                            let external_stack = stack.get_external_stack(locator.program_id())?;
+                            if external_stack.program().contains_function(locator.resource()) {
+                                graph.edges.push(GraphEdge {
+                                    source: id.clone(),
+                                    target: locator.to_string(),
+                                    kind: EdgeKind::Call,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(graph)
+    }
+}
@@ -18,9 +18,18 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod access;
+pub use access::*;
+
 mod cost;
 pub use cost::*;
 
+mod graph;
+pub use graph::*;
+
+mod observer;
+pub use observer::*;
+
 mod stack;
 pub use stack::*;
 
@@ -63,7 +72,7 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{BatchVerifier, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -79,6 +88,10 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The execution observer, if one is set.
+    observer: Option<Arc<dyn ExecutionObserver<N>>>,
+    /// A flag indicating whether the process is in dev mode, in which executions carry mock proofs.
+    dev_mode: bool,
 }
 
 impl<N: Network> Process<N> {
@@ -88,7 +101,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            observer: None,
+            dev_mode: false,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -134,6 +152,14 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), Arc::new(stack));
     }
+
+    /// Removes the stack for the given program ID from the process, and returns it, if it exists.
+    /// Note: This is intended for rolling back a deployment, and must not be used on 'credits.aleo'.
+    #[inline]
+    pub fn remove_stack(&mut self, program_id: &ProgramID<N>) -> Option<Arc<Stack<N>>> {
+        // Remove the stack from the process.
+        self.stacks.shift_remove(program_id)
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -143,7 +169,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            observer: None,
+            dev_mode: false,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -176,7 +207,12 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            observer: None,
+            dev_mode: false,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -197,6 +233,48 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Sets the execution observer, which is notified before and after every instruction and finalize command
+    /// of the evaluations, executions, and finalizations of this process.
+    /// Note: The observer is not shared with the clones of this process, nor with the processes cloned before.
+    #[inline]
+    pub fn set_execution_observer(&mut self, observer: Arc<dyn ExecutionObserver<N>>) {
+        self.observer = Some(observer);
+    }
+
+    /// Removes the execution observer, if one is set.
+    #[inline]
+    pub fn clear_execution_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Invokes the given closure with the execution observer of this process, if one is set,
+    /// as the observer of the execution in progress.
+    #[inline]
+    pub(crate) fn observe<T>(&self, f: impl FnOnce() -> T) -> T {
+        with_observer(self.observer.clone(), f)
+    }
+
+    /// Enables dev mode, in which executions and fees are constraint-checked, but carry a mock proof
+    /// in place of a proof. A process in dev mode accepts mock proofs, in addition to proofs.
+    ///
+    /// Note: Dev mode is intended for testing only, and is unavailable without the `dev-mode` feature.
+    /// Dev mode is only available on a network that allows it (see `Network::ALLOWS_DEV_MODE`),
+    /// and a process without dev mode enabled always rejects mock proofs.
+    #[cfg(feature = "dev-mode")]
+    #[inline]
+    pub fn enable_dev_mode(&mut self) -> Result<()> {
+        // Ensure the network allows dev mode.
+        ensure!(N::ALLOWS_DEV_MODE, "Dev mode is unavailable on '{}'", N::NAME);
+        self.dev_mode = true;
+        Ok(())
+    }
+
+    /// Returns `true` if the process is in dev mode.
+    #[inline]
+    pub const fn is_dev_mode(&self) -> bool {
+        self.dev_mode
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod tracer;
+pub use tracer::*;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+use synthesizer_program::{Opcode, Operand};
+
+use std::{any::Any, cell::RefCell, sync::Arc};
+
+thread_local! {
+    /// The observer of the execution in progress on this thread, if one is set.
+    /// Note: The observer is type-erased, as a thread local cannot be generic over the network.
+    static OBSERVER: RefCell<Option<Box<dyn Any>>> = RefCell::new(None);
+}
+
+/// Invokes the given closure with the given observer as the observer of the execution on this thread.
+/// The previous observer of the thread is restored once the closure returns, or panics.
+pub(crate) fn with_observer<N: Network, T>(
+    observer: Option<Arc<dyn ExecutionObserver<N>>>,
+    f: impl FnOnce() -> T,
+) -> T {
+    /// A guard that restores the previous observer when dropped.
+    struct Restore(Option<Box<dyn Any>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OBSERVER.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    // Set the observer, and restore the previous observer on exit.
+    let previous =
+        OBSERVER.with(|current| current.replace(observer.map(|observer| Box::new(observer) as Box<dyn Any>)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Returns the observer of the execution in progress on this thread, if one is set.
+pub(crate) fn current_observer<N: Network>() -> Option<Arc<dyn ExecutionObserver<N>>> {
+    OBSERVER.with(|current| {
+        current.borrow().as_ref().and_then(|observer| observer.downcast_ref::<Arc<dyn ExecutionObserver<N>>>()).cloned()
+    })
+}
+
+/// The phase in which an execution step is performed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionPhase {
+    /// The step is evaluated on console values, in `Stack::evaluate_function`.
+    Evaluate,
+    /// The step is executed in the circuit, in `Stack::execute_function`.
+    Execute,
+    /// The step is a finalize command.
+    Finalize,
+}
+
+impl Display for ExecutionPhase {
+    /// Prints the phase as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Evaluate => write!(f, "evaluate"),
+            Self::Execute => write!(f, "execute"),
+            Self::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// An instruction or finalize command, as seen by an `ExecutionObserver`.
+#[derive(Clone, Debug)]
+pub struct ExecutionStep<N: Network> {
+    /// The phase in which the step is performed.
+    pub phase: ExecutionPhase,
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The name of the function (or finalize scope) containing the step.
+    pub resource: Identifier<N>,
+    /// The index of the instruction or command in its scope.
+    pub index: usize,
+    /// The opcode.
+    pub opcode: Opcode,
+    /// The operands.
+    pub operands: Vec<Operand<N>>,
+    /// The resolved values of the operands, or `None` if an operand could not be loaded.
+    pub values: Vec<Option<Value<N>>>,
+    /// The destination registers.
+    pub destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> ExecutionStep<N> {
+    /// Initializes a new step, resolving the operands with the given loader.
+    pub(crate) fn new(
+        phase: ExecutionPhase,
+        program_id: ProgramID<N>,
+        resource: Identifier<N>,
+        index: usize,
+        opcode: Opcode,
+        operands: Vec<Operand<N>>,
+        destinations: Vec<Register<N>>,
+        load: impl Fn(&Operand<N>) -> Result<Value<N>>,
+    ) -> Self {
+        // Resolve the operands.
+        let values = operands.iter().map(|operand| load(operand).ok()).collect();
+        Self { phase, program_id, resource, index, opcode, operands, values, destinations }
+    }
+
+    /// Returns the values of the destination registers, resolved with the given loader.
+    pub(crate) fn outputs(&self, load: impl Fn(&Operand<N>) -> Result<Value<N>>) -> Vec<Option<Value<N>>> {
+        self.destinations.iter().map(|register| load(&Operand::Register(register.clone())).ok()).collect()
+    }
+}
+
+/// An observer that is notified before and after every instruction and finalize command.
+///
+/// All methods have empty default implementations, so an observer only implements the hooks it needs.
+/// Observers are purely diagnostic, and cannot alter the outcome of a step.
+pub trait ExecutionObserver<N: Network>: Send + Sync {
+    /// Invoked before the step is performed.
+    fn before_step(&self, _step: &ExecutionStep<N>) {}
+
+    /// Invoked after the step succeeds, with the values of its destination registers.
+    fn after_step(&self, _step: &ExecutionStep<N>, _outputs: &[Option<Value<N>>]) {}
+
+    /// Invoked after the step fails, with the error.
+    fn on_error(&self, _step: &ExecutionStep<N>, _error: &Error) {}
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use std::io::Write;
+
+/// An execution observer that writes a readable, step-by-step log to the given writer.
+///
+/// Each step is logged as a line of the form:
+/// ```text
+/// [evaluate] example.aleo/foo #0: add r0 r1 into r2 (r0 = 2field, r1 = 3field)
+/// [evaluate] example.aleo/foo #0:   r2 = 5field
+/// ```
+pub struct ExecutionTracer<W: Write + Send> {
+    /// The writer for the step log.
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> ExecutionTracer<W> {
+    /// Initializes a new tracer that writes to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self { writer: Mutex::new(writer) }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+
+    /// Writes a line for the given step.
+    /// Note: Errors from the writer are ignored, as tracing must not affect the execution.
+    fn write_line(&self, step: &ExecutionStep<impl Network>, line: impl Display) {
+        let mut writer = self.writer.lock();
+        let _ = writeln!(writer, "[{}] {}/{} #{}: {line}", step.phase, step.program_id, step.resource, step.index);
+    }
+}
+
+impl ExecutionTracer<std::io::Stdout> {
+    /// Initializes a new tracer that writes to standard output.
+    pub fn stdout() -> Self {
+        Self::new(std::io::stdout())
+    }
+}
+
+impl<N: Network, W: Write + Send> ExecutionObserver<N> for ExecutionTracer<W> {
+    /// Logs the opcode, operands, and destinations of the step, along with the operand values.
+    fn before_step(&self, step: &ExecutionStep<N>) {
+        // Format the instruction.
+        let mut instruction = step.opcode.to_string();
+        step.operands.iter().for_each(|operand| instruction.push_str(&format!(" {operand}")));
+        if !step.destinations.is_empty() {
+            instruction.push_str(" into");
+            step.destinations.iter().for_each(|destination| instruction.push_str(&format!(" {destination}")));
+        }
+        // Format the values of the register operands.
+        let values = step
+            .operands
+            .iter()
+            .zip_eq(&step.values)
+            .filter(|(operand, _)| matches!(operand, Operand::Register(_)))
+            .map(|(operand, value)| format_assignment(operand, value.as_ref()))
+            .collect::<Vec<_>>();
+        match values.is_empty() {
+            true => self.write_line(step, instruction),
+            false => self.write_line(step, format!("{instruction} ({})", values.join(", "))),
+        }
+    }
+
+    /// Logs the values stored in the destination registers.
+    fn after_step(&self, step: &ExecutionStep<N>, outputs: &[Option<Value<N>>]) {
+        for (destination, value) in step.destinations.iter().zip_eq(outputs) {
+            self.write_line(step, format!("  {}", format_assignment(destination, value.as_ref())));
+        }
+    }
+
+    /// Logs the error.
+    fn on_error(&self, step: &ExecutionStep<N>, error: &Error) {
+        self.write_line(step, format!("  failed: {error}"));
+    }
+}
+
+/// Formats the given register (or operand) and its value, as `r0 = 5field`.
+fn format_assignment<N: Network>(register: &impl Display, value: Option<&Value<N>>) -> String {
+    match value {
+        Some(value) => format!("{register} = {}", value.to_string().split_whitespace().collect::<Vec<_>>().join(" ")),
+        None => format!("{register} = ?"),
+    }
+}
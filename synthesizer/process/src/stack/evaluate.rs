@@ -173,9 +173,28 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the execution observer, if one is set.
+        let observer = current_observer::<N>();
+
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If an observer is set, notify it of the step.
+            let step = observer.as_ref().map(|observer| {
+                let step = ExecutionStep::new(
+                    ExecutionPhase::Evaluate,
+                    *self.program_id(),
+                    *function.name(),
+                    index,
+                    instruction.opcode(),
+                    instruction.operands().to_vec(),
+                    instruction.destinations(),
+                    |operand| registers.load(self, operand),
+                );
+                observer.before_step(&step);
+                step
+            });
+
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
@@ -183,6 +202,15 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
+
+            // If an observer is set, notify it of the outcome.
+            if let (Some(observer), Some(step)) = (&observer, &step) {
+                match &result {
+                    Ok(()) => observer.after_step(step, &step.outputs(|operand| registers.load(self, operand))),
+                    Err(error) => observer.on_error(step, error),
+                }
+            }
+
             // If the evaluation fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
@@ -273,9 +273,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // If the constraint profiler is recording, initialize a list for the metrics of each instruction.
+        let mut instruction_metrics =
+            ConstraintProfile::<N>::is_recording().then(|| Vec::with_capacity(function.instructions().len()));
+
+        // Retrieve the execution observer, if one is set.
+        let observer = current_observer::<N>();
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
@@ -291,25 +297,97 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
+            // If an observer is set, notify it of the step.
+            let step = observer.as_ref().map(|observer| {
+                let step = ExecutionStep::new(
+                    ExecutionPhase::Execute,
+                    *self.program_id(),
+                    *function.name(),
+                    index,
+                    instruction.opcode(),
+                    instruction.operands().to_vec(),
+                    instruction.destinations(),
+                    |operand| registers.load_circuit(self, operand).map(|value| value.eject_value()),
+                );
+                observer.before_step(&step);
+                step
+            });
+
+            // If the constraint profiler is recording, count the circuit before executing the instruction.
+            let count = instruction_metrics.is_some().then(A::count);
+
             // Execute the instruction.
-            let result = match instruction {
+            let (result, count_in_scope) = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                Instruction::Call(call) => (CallTrait::execute(call, self, &mut registers, rng), None),
+                // If the constraint profiler is recording, execute the instruction in its own scope.
+                _ if instruction_metrics.is_some() => A::scope(format!("instruction_{index}"), || {
+                    let result = instruction.execute(self, &mut registers);
+                    (result, Some(A::count_in_scope()))
+                }),
                 // Otherwise, execute the instruction normally.
-                _ => instruction.execute(self, &mut registers),
+                _ => (instruction.execute(self, &mut registers), None),
             };
+
+            // If an observer is set, notify it of the outcome.
+            if let (Some(observer), Some(step)) = (&observer, &step) {
+                match &result {
+                    Ok(()) => observer.after_step(
+                        step,
+                        &step.outputs(|operand| registers.load_circuit(self, operand).map(|value| value.eject_value())),
+                    ),
+                    Err(error) => observer.on_error(step, error),
+                }
+            }
+
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
 
-            // If the instruction was a function call, then set the tracker to `true`.
+            // If the instruction was a function call, then set the tracker to `true`, and record the callee.
+            let mut callee = None;
             if let Instruction::Call(call) = instruction {
                 // Check if the call is a function call.
                 if call.is_function_call(self)? {
                     contains_function_call = true;
+                    callee = Some(match call.operator() {
+                        CallOperator::Locator(locator) => *locator,
+                        CallOperator::Resource(resource) => Locator::new(*self.program_id(), *resource),
+                    });
                 }
             }
+
+            // If the constraint profiler is recording, record the metrics of the instruction.
+            if let (Some(instruction_metrics), Some(count)) = (&mut instruction_metrics, count) {
+                // Retrieve the number of constants, variables, constraints, and nonzeros introduced by the instruction.
+                // Note: A `call` instruction synthesizes the callee in its own circuit, and then restores the circuit
+                // of the caller, which discards any scope. So the circuit is counted before and after the call instead.
+                let (num_constants, num_public, num_private, num_constraints, num_nonzeros) = match count_in_scope {
+                    Some(count_in_scope) => count_in_scope,
+                    None => {
+                        let (constants, public, private, constraints, (nonzeros_a, nonzeros_b, nonzeros_c)) = count;
+                        let (a, b, c) = A::num_nonzeros();
+                        (
+                            A::num_constants() - constants,
+                            A::num_public() - public,
+                            A::num_private() - private,
+                            A::num_constraints() - constraints,
+                            (a - nonzeros_a, b - nonzeros_b, c - nonzeros_c),
+                        )
+                    }
+                };
+                instruction_metrics.push(InstructionMetrics {
+                    index,
+                    opcode: instruction.opcode(),
+                    callee,
+                    num_constants,
+                    num_public,
+                    num_private,
+                    num_constraints,
+                    num_nonzeros,
+                });
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -428,6 +506,22 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 lap!(timer, "Synthesize the {} circuit key", function.name());
             }
         }
+        // If the constraint profiler is recording, record the call, unless it is authorized or synthesized.
+        if let Some(instruction_metrics) = instruction_metrics {
+            if !matches!(registers.call_stack(), CallStack::Authorize(..) | CallStack::Synthesize(..)) {
+                // Construct the call metrics.
+                let metrics = CallMetrics {
+                    program_id: *self.program_id(),
+                    function_name: *function.name(),
+                    num_instructions: function.instructions().len(),
+                    num_request_constraints,
+                    num_function_constraints,
+                    num_response_constraints,
+                };
+                ConstraintProfile::record_call(metrics, instruction_metrics);
+            }
+        }
+
         // If the circuit is in `Authorize` mode, then save the transition.
         if let CallStack::Authorize(_, _, authorization) = registers.call_stack() {
             // Construct the transition.
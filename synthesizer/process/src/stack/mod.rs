@@ -36,7 +36,18 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{
+    cost_in_microcredits,
+    current_observer,
+    traits::*,
+    CallMetrics,
+    ConstraintProfile,
+    ExecutionPhase,
+    ExecutionStep,
+    InstructionMetrics,
+    Process,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
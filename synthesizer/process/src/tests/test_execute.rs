@@ -15,6 +15,9 @@
 use crate::{
     traits::{StackEvaluate, StackExecute},
     CallStack,
+    ConstraintProfile,
+    EdgeKind,
+    ExecutionTracer,
     Process,
     Stack,
     Trace,
@@ -26,7 +29,7 @@ use console::{
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
     types::{Field, U64},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Execution, Fee, Transaction};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -155,6 +158,179 @@ function foo:
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_and_execute_with_tracer() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 2field into r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("foo").unwrap();
+    // Declare the function inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+    // Clone the process, before the observer is set.
+    let untraced_process = process.clone();
+
+    // Set the tracer as the execution observer.
+    let tracer = Arc::new(ExecutionTracer::new(Vec::<u8>::new()));
+    process.set_execution_observer(tracer.clone());
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Evaluate the function with the clone of the process, which must not notify the observer.
+    untraced_process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+
+    // Evaluate and execute the function.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("10field").unwrap());
+    let (response, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("10field").unwrap());
+
+    // Remove the observer, and retrieve the log.
+    process.clear_execution_observer();
+    let log = String::from_utf8(Arc::try_unwrap(tracer).ok().unwrap().into_inner()).unwrap();
+
+    // Ensure every step was logged once in both phases.
+    for phase in ["evaluate", "execute"] {
+        assert_eq!(log.matches(&format!("[{phase}] example.aleo/foo #0: add")).count(), 1);
+        assert!(log.contains(&format!(
+            "[{phase}] example.aleo/foo #0: add r0 r1 into r2 (r0 = 2field, r1 = 3field)\n[{phase}] example.aleo/foo #0:   r2 = 5field\n"
+        )));
+        assert!(log.contains(&format!(
+            "[{phase}] example.aleo/foo #1: mul r2 2field into r3 (r2 = 5field)\n[{phase}] example.aleo/foo #1:   r3 = 10field\n"
+        )));
+    }
+}
+
+#[cfg(feature = "dev-mode")]
+#[test]
+fn test_process_dev_mode() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+    assert!(!process.is_dev_mode());
+
+    // Authorize and execute the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap(), Value::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "foo", inputs.iter(), rng).unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Construct an execution with a mock proof.
+    trace.set_dev_mode(true);
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    assert!(execution.proof().is_none());
+
+    // Ensure a process without dev mode rejects the mock proof.
+    assert!(process.verify_execution(&execution).is_err());
+
+    // Ensure a process in dev mode accepts the mock proof.
+    process.enable_dev_mode().unwrap();
+    assert!(process.is_dev_mode());
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_constraint_profile() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    hash.bhp256 r3 into r4 as field;
+    output r4 as field.private;
+",
+    )
+    .unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap(), Value::from_str("3field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "foo", inputs.iter(), rng).unwrap();
+
+    // Execute the function, and record its constraint profile.
+    let ((_, trace), profile) =
+        ConstraintProfile::<CurrentNetwork>::record(|| process.execute::<CurrentAleo, _>(authorization, rng)).unwrap();
+
+    // Ensure the metrics of every instruction were recorded.
+    let metrics = &trace.call_metrics()[0];
+    let json = profile.to_json();
+    let instructions = json["calls"][0]["instructions"].as_array().unwrap();
+    let opcodes = instructions.iter().map(|instruction| instruction["opcode"].as_str().unwrap()).collect_vec();
+    assert_eq!(opcodes, ["add", "mul", "hash.bhp256"]);
+    // Ensure the field addition is free, and the hash dominates the function.
+    let constraints =
+        instructions.iter().map(|instruction| instruction["num_constraints"].as_u64().unwrap()).collect_vec();
+    assert_eq!(constraints[0], 0);
+    assert_eq!(constraints[1], 1);
+    assert!(constraints[2] > 100);
+    assert!(constraints.iter().sum::<u64>() <= metrics.num_function_constraints);
+
+    // Ensure the profile attributes all of the constraints.
+    let num_constraints =
+        metrics.num_request_constraints + metrics.num_function_constraints + metrics.num_response_constraints;
+    assert_eq!(profile.num_constraints(), num_constraints);
+    let folded = profile.to_folded_stacks();
+    assert!(folded.contains("example.aleo/foo;1:mul 1\n"));
+    assert!(folded.contains(&format!("example.aleo/foo;2:hash.bhp256 {}\n", constraints[2])));
+    assert_eq!(
+        folded.lines().map(|line| line.rsplit_once(' ').unwrap().1.parse::<u64>().unwrap()).sum::<u64>(),
+        num_constraints
+    );
+}
+
 #[test]
 fn test_program_evaluate_struct_and_function() {
     // Initialize a new program.
@@ -1951,6 +2127,91 @@ function a:
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_dependency_graphs() {
+    // Initialize the programs.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program zero.aleo;
+
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import zero.aleo;
+
+program one.aleo;
+
+function b:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call zero.aleo/c r0 r1 into r2;
+    call zero.aleo/c r2 r1 into r3;
+    output r3 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Check the import graph.
+    let graph = process.program_graph(program1.id()).unwrap();
+    let ids = graph.nodes().iter().map(|node| node.id.as_str()).collect_vec();
+    assert_eq!(ids, ["one.aleo", "one.aleo/b", "zero.aleo", "zero.aleo/c"]);
+    let edges = graph.edges().iter().map(|edge| (edge.source.as_str(), edge.target.as_str(), edge.kind)).collect_vec();
+    assert_eq!(edges, [
+        ("one.aleo", "zero.aleo", EdgeKind::Import),
+        ("one.aleo/b", "zero.aleo/c", EdgeKind::Call),
+        ("one.aleo/b", "zero.aleo/c", EdgeKind::Call)
+    ]);
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph program {"));
+    assert!(dot.contains("\"one.aleo\" -> \"zero.aleo\" [style=dashed];"));
+    assert!(dot.contains("\"one.aleo/b\" -> \"zero.aleo/c\";"));
+    assert_eq!(graph.to_json()["nodes"].as_array().unwrap().len(), 4);
+
+    // Check the finalize costs are annotated.
+    let graph = process.program_graph(&ProgramID::from_str("credits.aleo").unwrap()).unwrap();
+    let stack = process.get_stack("credits.aleo").unwrap();
+    let node = graph.get_node("credits.aleo/transfer_public").unwrap();
+    assert_eq!(
+        node.finalize_cost,
+        Some(stack.get_finalize_cost(&Identifier::from_str("transfer_public").unwrap()).unwrap())
+    );
+    assert_eq!(graph.get_node("credits.aleo/transfer_private").unwrap().finalize_cost, None);
+
+    // Authorize the function, which produces the transitions of the execution.
+    let rng = &mut TestRng::default();
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap(), Value::from_str("2u8").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "b", inputs.iter(), rng).unwrap();
+    let execution = Execution::from(authorization.transitions().into_values(), Default::default(), None).unwrap();
+
+    // Check the call graph.
+    let graph = process.execution_graph(&execution, None).unwrap();
+    let labels = graph.nodes().iter().map(|node| node.label.as_str()).collect_vec();
+    assert_eq!(labels, ["zero.aleo/c", "zero.aleo/c", "one.aleo/b"]);
+    let transition_ids = execution.transitions().map(|transition| transition.id().to_string()).collect_vec();
+    let edges = graph.edges().iter().map(|edge| (edge.source.as_str(), edge.target.as_str())).collect_vec();
+    assert_eq!(edges, [
+        (transition_ids[2].as_str(), transition_ids[0].as_str()),
+        (transition_ids[2].as_str(), transition_ids[1].as_str())
+    ]);
+    assert!(graph.nodes().iter().all(|node| node.finalize_cost.is_none()));
+    assert!(graph.to_dot().contains(&format!("\"{}\" -> \"{}\";", transition_ids[2], transition_ids[0])));
+    assert_eq!(graph.to_json()["edges"].as_array().unwrap().len(), 2);
+
+    // Ensure call metrics that do not match the transitions are rejected.
+    assert!(process.execution_graph(&execution, Some(&[])).is_err());
+}
+
 #[test]
 fn test_complex_execution_order() {
     // This test checks that the execution order is correct.
@@ -2363,8 +2624,12 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        observer: None,
+        dev_mode: false,
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
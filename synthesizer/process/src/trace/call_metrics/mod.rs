@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod profile;
+pub use profile::*;
+
 use console::{
     network::Network,
-    program::{Identifier, ProgramID},
+    program::{Identifier, Locator, ProgramID},
 };
+use synthesizer_program::Opcode;
 
 #[derive(Copy, Clone, Debug)]
 pub struct CallMetrics<N: Network> {
@@ -26,3 +30,19 @@ pub struct CallMetrics<N: Network> {
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
 }
+
+#[derive(Copy, Clone, Debug)]
+pub struct InstructionMetrics<N: Network> {
+    /// The index of the instruction in the function.
+    pub index: usize,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The function invoked by the instruction, if it is a function call.
+    /// Note: The callee is synthesized in its own circuit, so its constraints are not counted here.
+    pub callee: Option<Locator<N>>,
+    pub num_constants: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    pub num_constraints: u64,
+    pub num_nonzeros: (u64, u64, u64),
+}
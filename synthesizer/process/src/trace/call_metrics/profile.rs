@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::prelude::{bail, ensure, Result};
+
+use indexmap::IndexMap;
+use serde_json::json;
+use std::{any::Any, cell::RefCell};
+
+thread_local! {
+    /// The calls recorded by the constraint profiler on this thread, or `None` if it is not recording.
+    /// Note: The calls are type-erased, as a thread local cannot be generic over the network.
+    static RECORDED_CALLS: RefCell<Option<Vec<Box<dyn Any>>>> = RefCell::new(None);
+}
+
+/// A constraint profile of an execution, attributing constraints to every call and instruction.
+///
+/// The profile is built from the calls of an execution, which are recorded in the order the
+/// calls complete, i.e. every callee precedes its caller.
+///
+/// Note: The profile does not attribute proving time. The transitions of an execution are proven
+/// together, in a single batch proof, so the proving time of a call (let alone an instruction) cannot
+/// be measured on its own. The proving time of a circuit grows with its constraints and nonzeros,
+/// which the profile attributes instead.
+#[derive(Clone, Debug)]
+pub struct ConstraintProfile<N: Network> {
+    /// The profiles of the top-level calls.
+    roots: Vec<CallProfile<N>>,
+}
+
+/// The constraint profile of a single call.
+#[derive(Clone, Debug)]
+struct CallProfile<N: Network> {
+    /// The metrics of the call.
+    metrics: CallMetrics<N>,
+    /// The metrics of the instructions of the call.
+    instructions: Vec<InstructionMetrics<N>>,
+    /// The profiles of the function calls made by this call, in the order they are made.
+    callees: Vec<CallProfile<N>>,
+}
+
+impl<N: Network> ConstraintProfile<N> {
+    /// Initializes a new constraint profile from the given calls, with the metrics of their instructions.
+    pub fn new(calls: &[(CallMetrics<N>, Vec<InstructionMetrics<N>>)]) -> Result<Self> {
+        // Reconstruct the call tree, starting from the last call to complete.
+        let mut remaining = calls.to_vec();
+        let mut roots = Vec::new();
+        while !remaining.is_empty() {
+            roots.push(CallProfile::pop(&mut remaining)?);
+        }
+        // Restore the roots to the order in which they were called.
+        roots.reverse();
+        Ok(Self { roots })
+    }
+
+    /// Invokes the given closure, and returns its output along with the constraint profile of the calls
+    /// it executes on this thread, i.e. in the `Execute`, `PackageRun`, or `CheckDeployment` mode.
+    pub fn record<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Self)> {
+        /// A guard that restores the previously recorded calls when dropped.
+        struct Restore(Option<Vec<Box<dyn Any>>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                RECORDED_CALLS.with(|calls| *calls.borrow_mut() = self.0.take());
+            }
+        }
+
+        // Start recording, and restore the previously recorded calls on exit.
+        let restore = Restore(RECORDED_CALLS.with(|calls| calls.replace(Some(Vec::new()))));
+        let output = f();
+        // Retrieve the recorded calls.
+        let calls = RECORDED_CALLS.with(|calls| calls.borrow_mut().take()).unwrap_or_default();
+        drop(restore);
+
+        // Construct the profile.
+        let calls = calls
+            .into_iter()
+            .map(|call| match call.downcast::<(CallMetrics<N>, Vec<InstructionMetrics<N>>)>() {
+                Ok(call) => Ok(*call),
+                Err(_) => bail!("Recorded a call of a different network"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((output?, Self::new(&calls)?))
+    }
+
+    /// Returns `true` if the constraint profiler is recording on this thread.
+    pub(crate) fn is_recording() -> bool {
+        RECORDED_CALLS.with(|calls| calls.borrow().is_some())
+    }
+
+    /// Records the given call, with the metrics of its instructions, if the constraint profiler is recording.
+    pub(crate) fn record_call(metrics: CallMetrics<N>, instructions: Vec<InstructionMetrics<N>>) {
+        RECORDED_CALLS.with(|calls| {
+            if let Some(calls) = calls.borrow_mut().as_mut() {
+                calls.push(Box::new((metrics, instructions)));
+            }
+        });
+    }
+
+    /// Returns the total number of constraints in the profile.
+    pub fn num_constraints(&self) -> u64 {
+        self.roots.iter().map(|root| root.num_constraints()).sum()
+    }
+
+    /// Returns the profile in the folded-stack format, as consumed by flame graph tools.
+    ///
+    /// Each line is a `;`-separated stack of frames, followed by the number of constraints in the last frame.
+    /// A function call is nested under the `call` instruction that invokes it.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut lines = Vec::new();
+        for root in &self.roots {
+            root.fold(None, &mut lines);
+        }
+        lines.into_iter().map(|line| format!("{line}\n")).collect()
+    }
+
+    /// Returns the profile as JSON, with the call tree and the constraints per opcode.
+    pub fn to_json(&self) -> serde_json::Value {
+        // Aggregate the instructions by opcode.
+        let mut opcodes = IndexMap::<String, (u64, u64)>::new();
+        for root in &self.roots {
+            root.aggregate_opcodes(&mut opcodes);
+        }
+        // Sort the opcodes by their number of constraints, in descending order.
+        opcodes.sort_by(|_, (_, a), _, (_, b)| b.cmp(a));
+
+        json!({
+            "num_constraints": self.num_constraints(),
+            "calls": self.roots.iter().map(|root| root.to_json()).collect::<Vec<_>>(),
+            "opcodes": opcodes
+                .into_iter()
+                .map(|(opcode, (count, num_constraints))| {
+                    json!({ "opcode": opcode, "count": count, "num_constraints": num_constraints })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl<N: Network> CallProfile<N> {
+    /// Pops the last call (and its callees) from the given call metrics.
+    fn pop(remaining: &mut Vec<(CallMetrics<N>, Vec<InstructionMetrics<N>>)>) -> Result<Self> {
+        // Retrieve the call.
+        let (metrics, instructions) = match remaining.pop() {
+            Some(call) => call,
+            None => bail!("Missing call metrics for a function call"),
+        };
+        // Retrieve the callees, which completed in reverse order.
+        let mut callees = Vec::new();
+        for callee in instructions.iter().rev().filter_map(|instruction| instruction.callee) {
+            let profile = Self::pop(remaining)?;
+            // Ensure the callee matches the call.
+            ensure!(
+                profile.metrics.program_id == *callee.program_id()
+                    && profile.metrics.function_name == *callee.resource(),
+                "Expected call metrics for '{callee}', found '{}/{}'",
+                profile.metrics.program_id,
+                profile.metrics.function_name
+            );
+            callees.push(profile);
+        }
+        // Restore the callees to the order in which they were called.
+        callees.reverse();
+        Ok(Self { metrics, instructions, callees })
+    }
+
+    /// Returns the total number of constraints in the call, including its callees.
+    fn num_constraints(&self) -> u64 {
+        self.metrics.num_request_constraints
+            + self.metrics.num_function_constraints
+            + self.metrics.num_response_constraints
+            + self.callees.iter().map(|callee| callee.num_constraints()).sum::<u64>()
+    }
+
+    /// Returns the number of function constraints that are not attributed to an instruction.
+    fn num_output_constraints(&self) -> u64 {
+        let num_instruction_constraints =
+            self.instructions.iter().map(|instruction| instruction.num_constraints).sum::<u64>();
+        self.metrics.num_function_constraints.saturating_sub(num_instruction_constraints)
+    }
+
+    /// Appends the folded stacks of the call, under the given parent frame.
+    fn fold(&self, parent: Option<&str>, lines: &mut Vec<String>) {
+        // Construct the frame for the call.
+        let locator = format!("{}/{}", self.metrics.program_id, self.metrics.function_name);
+        let frame = match parent {
+            Some(parent) => format!("{parent};{locator}"),
+            None => locator,
+        };
+        // A helper to append a line, skipping frames without constraints.
+        let mut push = |frame: &str, num_constraints: u64| {
+            if num_constraints > 0 {
+                lines.push(format!("{frame} {num_constraints}"));
+            }
+        };
+
+        push(&format!("{frame};request"), self.metrics.num_request_constraints);
+        let mut callees = self.callees.iter();
+        let mut nested = Vec::new();
+        for instruction in &self.instructions {
+            let instruction_frame = format!("{frame};{}:{}", instruction.index, instruction.opcode);
+            push(&instruction_frame, instruction.num_constraints);
+            // If the instruction is a function call, nest the callee under the instruction.
+            if instruction.callee.is_some() {
+                if let Some(callee) = callees.next() {
+                    nested.push((instruction_frame, callee));
+                }
+            }
+        }
+        push(&format!("{frame};outputs"), self.num_output_constraints());
+        push(&format!("{frame};response"), self.metrics.num_response_constraints);
+
+        // Append the callees.
+        for (instruction_frame, callee) in nested {
+            callee.fold(Some(&instruction_frame), lines);
+        }
+    }
+
+    /// Adds the count and number of constraints of each opcode in the call (and its callees) to the given map.
+    fn aggregate_opcodes(&self, opcodes: &mut IndexMap<String, (u64, u64)>) {
+        for instruction in &self.instructions {
+            let entry = opcodes.entry(instruction.opcode.to_string()).or_default();
+            entry.0 += 1;
+            entry.1 += instruction.num_constraints;
+        }
+        for callee in &self.callees {
+            callee.aggregate_opcodes(opcodes);
+        }
+    }
+
+    /// Returns the call as JSON.
+    fn to_json(&self) -> serde_json::Value {
+        let mut callees = self.callees.iter();
+        let instructions = self
+            .instructions
+            .iter()
+            .map(|instruction| {
+                let mut value = json!({
+                    "index": instruction.index,
+                    "opcode": instruction.opcode.to_string(),
+                    "num_constants": instruction.num_constants,
+                    "num_public": instruction.num_public,
+                    "num_private": instruction.num_private,
+                    "num_constraints": instruction.num_constraints,
+                    "num_nonzeros": [instruction.num_nonzeros.0, instruction.num_nonzeros.1, instruction.num_nonzeros.2],
+                });
+                // If the instruction is a function call, nest the callee under the instruction.
+                if instruction.callee.is_some() {
+                    if let Some(callee) = callees.next() {
+                        value["callee"] = callee.to_json();
+                    }
+                }
+                value
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "program_id": self.metrics.program_id.to_string(),
+            "function_name": self.metrics.function_name.to_string(),
+            "num_constraints": self.num_constraints(),
+            "num_request_constraints": self.metrics.num_request_constraints,
+            "num_function_constraints": self.metrics.num_function_constraints,
+            "num_response_constraints": self.metrics.num_response_constraints,
+            "num_output_constraints": self.num_output_constraints(),
+            "instructions": instructions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, prelude::FromStr};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples the metrics of an instruction.
+    fn sample_instruction(index: usize, opcode: Opcode, num_constraints: u64) -> InstructionMetrics<CurrentNetwork> {
+        InstructionMetrics {
+            index,
+            opcode,
+            callee: None,
+            num_constants: 0,
+            num_public: 0,
+            num_private: num_constraints,
+            num_constraints,
+            num_nonzeros: (num_constraints, num_constraints, num_constraints),
+        }
+    }
+
+    /// Samples the metrics of a call, with the metrics of its instructions.
+    fn sample_call(
+        locator: &str,
+        instruction_metrics: Vec<InstructionMetrics<CurrentNetwork>>,
+        num_function_constraints: u64,
+    ) -> (CallMetrics<CurrentNetwork>, Vec<InstructionMetrics<CurrentNetwork>>) {
+        let locator = Locator::<CurrentNetwork>::from_str(locator).unwrap();
+        let metrics = CallMetrics {
+            program_id: *locator.program_id(),
+            function_name: *locator.resource(),
+            num_instructions: instruction_metrics.len(),
+            num_request_constraints: 100,
+            num_function_constraints,
+            num_response_constraints: 10,
+        };
+        (metrics, instruction_metrics)
+    }
+
+    /// Samples the calls of an execution where `foo.aleo/main` calls `bar.aleo/transfer`.
+    fn sample_call_metrics() -> Vec<(CallMetrics<CurrentNetwork>, Vec<InstructionMetrics<CurrentNetwork>>)> {
+        // Construct the callee.
+        let callee = sample_call("bar.aleo/transfer", vec![sample_instruction(0, Opcode::Literal("add"), 5)], 5);
+        // Construct the caller.
+        let mut call = sample_instruction(1, Opcode::Call, 3);
+        call.callee = Some(Locator::from_str("bar.aleo/transfer").unwrap());
+        let caller = sample_call(
+            "foo.aleo/main",
+            vec![
+                sample_instruction(0, Opcode::Literal("add"), 7),
+                call,
+                sample_instruction(2, Opcode::Hash("hash.bhp256"), 0),
+            ],
+            12,
+        );
+        // Note: The callee completes before the caller.
+        vec![callee, caller]
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        let profile = ConstraintProfile::new(&sample_call_metrics()).unwrap();
+        assert_eq!(profile.num_constraints(), 110 + 5 + 110 + 12);
+
+        let expected = "foo.aleo/main;request 100
+foo.aleo/main;0:add 7
+foo.aleo/main;1:call 3
+foo.aleo/main;outputs 2
+foo.aleo/main;response 10
+foo.aleo/main;1:call;bar.aleo/transfer;request 100
+foo.aleo/main;1:call;bar.aleo/transfer;0:add 5
+foo.aleo/main;1:call;bar.aleo/transfer;response 10
+";
+        assert_eq!(profile.to_folded_stacks(), expected);
+    }
+
+    #[test]
+    fn test_json() {
+        let profile = ConstraintProfile::new(&sample_call_metrics()).unwrap();
+        let json = profile.to_json();
+
+        assert_eq!(json["num_constraints"], 237);
+        assert_eq!(json["calls"].as_array().unwrap().len(), 1);
+        assert_eq!(json["calls"][0]["function_name"], "main");
+        assert_eq!(json["calls"][0]["instructions"][1]["callee"]["program_id"], "bar.aleo");
+        assert_eq!(json["calls"][0]["instructions"][1]["callee"]["num_constraints"], 115);
+        assert_eq!(json["opcodes"][0], json!({ "opcode": "add", "count": 2, "num_constraints": 12 }));
+    }
+
+    #[test]
+    fn test_record() {
+        // Ensure a call is not recorded outside of a recording.
+        assert!(!ConstraintProfile::<CurrentNetwork>::is_recording());
+        let (metrics, instructions) = sample_call("foo.aleo/main", vec![], 0);
+        ConstraintProfile::record_call(metrics, instructions);
+
+        // Record the calls.
+        let (output, profile) = ConstraintProfile::<CurrentNetwork>::record(|| {
+            assert!(ConstraintProfile::<CurrentNetwork>::is_recording());
+            for (metrics, instructions) in sample_call_metrics() {
+                ConstraintProfile::record_call(metrics, instructions);
+            }
+            Ok(1u8)
+        })
+        .unwrap();
+        assert_eq!(output, 1);
+        assert_eq!(profile.num_constraints(), 237);
+        assert!(!ConstraintProfile::<CurrentNetwork>::is_recording());
+
+        // Ensure an error of the closure is returned.
+        assert!(ConstraintProfile::<CurrentNetwork>::record(|| -> Result<()> { bail!("Failed") }).is_err());
+        assert!(!ConstraintProfile::<CurrentNetwork>::is_recording());
+    }
+
+    #[test]
+    fn test_missing_callee() {
+        // Remove the callee from the call metrics.
+        let call_metrics = sample_call_metrics().into_iter().skip(1).collect::<Vec<_>>();
+        assert!(ConstraintProfile::new(&call_metrics).is_err());
+    }
+}
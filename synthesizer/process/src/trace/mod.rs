@@ -25,7 +25,7 @@ use console::{
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use synthesizer_snark::{BatchVerifier, Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
@@ -45,6 +45,8 @@ pub struct Trace<N: Network> {
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
+    /// A flag indicating whether the trace produces mock proofs, as it was executed in dev mode.
+    is_dev_mode: bool,
 }
 
 impl<N: Network> Trace<N> {
@@ -57,9 +59,20 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            is_dev_mode: false,
         }
     }
 
+    /// Returns `true` if the trace produces mock proofs, as it was executed in dev mode.
+    pub const fn is_dev_mode(&self) -> bool {
+        self.is_dev_mode
+    }
+
+    /// Sets whether the trace produces mock proofs.
+    pub(crate) fn set_dev_mode(&mut self, is_dev_mode: bool) {
+        self.is_dev_mode = is_dev_mode;
+    }
+
     /// Returns the list of transitions.
     pub fn transitions(&self) -> &[Transition<N>] {
         &self.transitions
@@ -169,9 +182,9 @@ impl<N: Network> Trace<N> {
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
         let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+            self.prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
         // Return the execution.
-        Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
+        Execution::from(self.transitions.iter().cloned(), global_state_root, proof)
     }
 
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
@@ -196,7 +209,7 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+        let (global_state_root, proof) = self.prove_batch::<A, R>(
             "credits.aleo/fee (private or public)",
             proving_tasks,
             inclusion_assignments,
@@ -204,7 +217,7 @@ impl<N: Network> Trace<N> {
             rng,
         )?;
         // Return the fee.
-        Ok(Fee::from_unchecked(fee_transition.clone(), global_state_root, Some(proof)))
+        Ok(Fee::from_unchecked(fee_transition.clone(), global_state_root, proof))
     }
 
     /// Checks the proof for the execution.
@@ -213,6 +226,48 @@ impl<N: Network> Trace<N> {
         locator: &str,
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
+    ) -> Result<()> {
+        Self::check_execution_proof(locator, verifier_inputs, execution, None)
+    }
+
+    /// Checks the proof for the execution, up to (but excluding) the final pairing check,
+    /// which is deferred to the given batch verifier.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_execution_proof_deferred(
+        locator: &str,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+        batch_verifier: &mut BatchVerifier<N>,
+    ) -> Result<()> {
+        Self::check_execution_proof(locator, verifier_inputs, execution, Some(batch_verifier))
+    }
+
+    /// Checks the proof for the fee.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+        Self::check_fee_proof(verifier_inputs, fee, None)
+    }
+
+    /// Checks the proof for the fee, up to (but excluding) the final pairing check,
+    /// which is deferred to the given batch verifier.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_fee_proof_deferred(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+        batch_verifier: &mut BatchVerifier<N>,
+    ) -> Result<()> {
+        Self::check_fee_proof(verifier_inputs, fee, Some(batch_verifier))
+    }
+}
+
+impl<N: Network> Trace<N> {
+    /// Checks the proof for the execution.
+    /// If a batch verifier is given, the final pairing check is deferred to the batch verifier.
+    fn check_execution_proof(
+        locator: &str,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
     ) -> Result<()> {
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
@@ -223,15 +278,26 @@ impl<N: Network> Trace<N> {
         // Retrieve the proof.
         let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
         // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
+        match Self::verify_batch(
+            locator,
+            verifier_inputs,
+            global_state_root,
+            execution.transitions(),
+            proof,
+            batch_verifier,
+        ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
     }
 
     /// Checks the proof for the fee.
-    /// Note: This does *not* check that the global state root exists in the ledger.
-    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+    /// If a batch verifier is given, the final pairing check is deferred to the batch verifier.
+    fn check_fee_proof(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         // Retrieve the global state root.
         let global_state_root = fee.global_state_root();
         // Ensure the global state root is not zero.
@@ -247,6 +313,7 @@ impl<N: Network> Trace<N> {
             global_state_root,
             [fee.transition()].into_iter(),
             proof,
+            batch_verifier,
         ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Fee is invalid - {e}"),
@@ -256,13 +323,15 @@ impl<N: Network> Trace<N> {
 
 impl<N: Network> Trace<N> {
     /// Returns the global state root and proof for the given assignments.
+    /// In dev mode, the assignments are checked to be satisfied, and no proof is returned (a mock proof).
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
         global_state_root: N::StateRoot,
         rng: &mut R,
-    ) -> Result<(N::StateRoot, Proof<N>)> {
+    ) -> Result<(N::StateRoot, Option<Proof<N>>)> {
         // Ensure the global state root is not zero.
         // Note: To protect user privacy, even when there are *no* inclusion assignments,
         // the user must provide a real global state root (which is checked in consensus).
@@ -289,13 +358,39 @@ impl<N: Network> Trace<N> {
             proving_tasks.push((proving_key, batch_inclusions));
         }
 
+        // If the trace is in dev mode, check the assignments are satisfied, and return a mock proof.
+        #[cfg(feature = "dev-mode")]
+        if self.is_dev_mode {
+            Self::check_assignments(locator, &proving_tasks)?;
+            return Ok((global_state_root, None));
+        }
+
         // Compute the proof.
         let proof = ProvingKey::prove_batch(locator, &proving_tasks, rng)?;
         // Return the global state root and proof.
-        Ok((global_state_root, proof))
+        Ok((global_state_root, Some(proof)))
+    }
+
+    /// Ensures every assignment in the given proving tasks is satisfied.
+    #[cfg(feature = "dev-mode")]
+    fn check_assignments(locator: &str, proving_tasks: &[(ProvingKey<N>, Vec<Assignment<N::Field>>)]) -> Result<()> {
+        use algorithms::r1cs::{ConstraintSynthesizer, TestConstraintChecker};
+
+        for assignment in proving_tasks.iter().flat_map(|(_, assignments)| assignments) {
+            // Synthesize the assignment into a constraint checker.
+            let mut checker = TestConstraintChecker::<N::Field>::new();
+            assignment.generate_constraints(&mut checker)?;
+            // Ensure the assignment is satisfied.
+            if !checker.is_satisfied() {
+                let constraint = checker.which_is_unsatisfied().unwrap_or_default();
+                bail!("'{locator}' is not satisfied (first unsatisfied constraint: '{constraint}')")
+            }
+        }
+        Ok(())
     }
 
     /// Checks the proof for the given inputs.
+    /// If a batch verifier is given, the final pairing check is deferred to the batch verifier.
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
@@ -303,6 +398,7 @@ impl<N: Network> Trace<N> {
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
     ) -> Result<()> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
@@ -313,7 +409,11 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        // Verify the proof, or defer its final pairing check to the batch verifier.
+        match batch_verifier {
+            Some(batch_verifier) => batch_verifier.add(locator, verifier_inputs, proof),
+            None => VerifyingKey::verify_batch(locator, verifier_inputs, proof),
+        }
+        .map_err(|e| anyhow!("Failed to verify proof - {e}"))
     }
 }
@@ -19,6 +19,28 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_internal(execution, None)
+    }
+
+    /// Verifies the given execution is valid, up to (but excluding) the final pairing check of its proof,
+    /// which is deferred to the given batch verifier.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_deferred(
+        &self,
+        execution: &Execution<N>,
+        batch_verifier: &mut BatchVerifier<N>,
+    ) -> Result<()> {
+        self.verify_execution_internal(execution, Some(batch_verifier))
+    }
+
+    /// Verifies the given execution is valid.
+    /// If a batch verifier is given, the final pairing check of the proof is deferred to the batch verifier.
+    fn verify_execution_internal(
+        &self,
+        execution: &Execution<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -144,8 +166,15 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        // Verify the execution proof, unless the process is in dev mode and the execution carries a mock proof.
+        if !(self.dev_mode && execution.proof().is_none()) {
+            match batch_verifier {
+                Some(batch_verifier) => {
+                    Trace::verify_execution_proof_deferred(&locator, verifier_inputs, execution, batch_verifier)?
+                }
+                None => Trace::verify_execution_proof(&locator, verifier_inputs, execution)?,
+            }
+        }
 
         lap!(timer, "Verify the proof");
 
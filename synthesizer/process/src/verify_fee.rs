@@ -19,6 +19,30 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+        self.verify_fee_internal(fee, deployment_or_execution_id, None)
+    }
+
+    /// Verifies the given fee is valid, up to (but excluding) the final pairing check of its proof,
+    /// which is deferred to the given batch verifier.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_fee_deferred(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch_verifier: &mut BatchVerifier<N>,
+    ) -> Result<()> {
+        self.verify_fee_internal(fee, deployment_or_execution_id, Some(batch_verifier))
+    }
+
+    /// Verifies the given fee is valid.
+    /// If a batch verifier is given, the final pairing check of the proof is deferred to the batch verifier.
+    fn verify_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
         #[cfg(debug_assertions)]
@@ -63,8 +87,8 @@ impl<N: Network> Process<N> {
 
         // Verify the fee transition is well-formed.
         match is_fee_private {
-            true => self.verify_fee_private(&fee)?,
-            false => self.verify_fee_public(&fee)?,
+            true => self.verify_fee_private(&fee, batch_verifier)?,
+            false => self.verify_fee_public(&fee, batch_verifier)?,
         }
         finish!(timer, "Verify the fee transition");
         Ok(())
@@ -73,7 +97,7 @@ impl<N: Network> Process<N> {
 
 impl<N: Network> Process<N> {
     /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    fn verify_fee_private(&self, fee: &&Fee<N>, batch_verifier: Option<&mut BatchVerifier<N>>) -> Result<()> {
         let timer = timer!("Process::verify_fee_private");
 
         // Retrieve the network ID.
@@ -134,15 +158,22 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
+        // Ensure the fee proof is valid, unless the process is in dev mode and the fee carries a mock proof.
+        if !(self.dev_mode && fee.proof().is_none()) {
+            match batch_verifier {
+                Some(batch_verifier) => {
+                    Trace::verify_fee_proof_deferred((verifying_key, vec![inputs]), fee, batch_verifier)?
+                }
+                None => Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?,
+            }
+        }
         finish!(timer, "Verify the fee proof");
         Ok(())
     }
 
     /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn verify_fee_public(&self, fee: &&Fee<N>, batch_verifier: Option<&mut BatchVerifier<N>>) -> Result<()> {
         let timer = timer!("Process::verify_fee_public");
 
         // Retrieve the network ID.
@@ -203,8 +234,15 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
+        // Ensure the fee proof is valid, unless the process is in dev mode and the fee carries a mock proof.
+        if !(self.dev_mode && fee.proof().is_none()) {
+            match batch_verifier {
+                Some(batch_verifier) => {
+                    Trace::verify_fee_proof_deferred((verifying_key, vec![inputs]), fee, batch_verifier)?
+                }
+                None => Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?,
+            }
+        }
         finish!(timer, "Verify the fee proof");
         Ok(())
     }
@@ -248,8 +286,8 @@ mod tests {
                     process.verify_fee(&fee.unwrap(), execution_id).unwrap();
                 }
                 Transaction::Fee(_, fee) => match fee.is_fee_private() {
-                    true => process.verify_fee_private(&&fee).unwrap(),
-                    false => process.verify_fee_public(&&fee).unwrap(),
+                    true => process.verify_fee_private(&&fee, None).unwrap(),
+                    false => process.verify_fee_public(&&fee, None).unwrap(),
                 },
             }
         }
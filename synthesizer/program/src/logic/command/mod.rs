@@ -53,6 +53,8 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -145,6 +147,42 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+        }
+    }
+
+    /// Returns the operands of the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+            Command::Contains(contains) => contains.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => vec![branch_eq.first().clone(), branch_eq.second().clone()],
+            Command::BranchNeq(branch_neq) => vec![branch_neq.first().clone(), branch_neq.second().clone()],
+            Command::Position(_) => vec![],
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::polycommit::sonic_pc::PairingCheck;
+
+use std::collections::BTreeMap;
+
+/// A verifier that defers the final pairing check of each batch proof, so that many
+/// batch proofs (e.g. of all transactions in a block) are checked at once with a single pairing check.
+#[derive(Clone)]
+pub struct BatchVerifier<N: Network> {
+    /// The locators and pairing checks of the deferred proofs.
+    pairing_checks: Vec<(String, PairingCheck<N::PairingCurve>)>,
+}
+
+impl<N: Network> BatchVerifier<N> {
+    /// Initializes a new batch verifier.
+    pub const fn new() -> Self {
+        Self { pairing_checks: Vec::new() }
+    }
+
+    /// Returns the number of deferred proofs.
+    pub fn len(&self) -> usize {
+        self.pairing_checks.len()
+    }
+
+    /// Returns `true` if there are no deferred proofs.
+    pub fn is_empty(&self) -> bool {
+        self.pairing_checks.is_empty()
+    }
+
+    /// Returns the locators of the deferred proofs.
+    pub fn locators(&self) -> impl '_ + ExactSizeIterator<Item = &str> {
+        self.pairing_checks.iter().map(|(locator, _)| locator.as_str())
+    }
+
+    /// Checks the batch proof for the given public inputs, up to (but excluding) the final pairing check,
+    /// which is deferred to `BatchVerifier::verify`.
+    #[allow(clippy::type_complexity)]
+    pub fn add(
+        &mut self,
+        locator: &str,
+        inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> Result<()> {
+        // Convert the instances.
+        let num_expected_keys = inputs.len();
+        let keys_to_inputs: BTreeMap<_, _> =
+            inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+        ensure!(keys_to_inputs.len() == num_expected_keys, "Incorrect number of verifying keys for batch proof");
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Prepare the pairing check of the batch proof.
+        match Varuna::<N>::prepare_verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof)? {
+            Some(pairing_check) => self.pairing_checks.push((locator.to_string(), pairing_check)),
+            None => bail!("'verify_batch' failed for '{locator}' - the proof has an incorrect hiding mode"),
+        }
+        Ok(())
+    }
+
+    /// Moves all of the deferred proofs of `other` into this batch verifier.
+    pub fn append(&mut self, other: &mut Self) {
+        self.pairing_checks.append(&mut other.pairing_checks);
+    }
+
+    /// Returns `Ok(())` if all of the deferred proofs are valid, using a single pairing check.
+    pub fn verify<R: Rng + CryptoRng>(self, rng: &mut R) -> Result<()> {
+        // If there are no deferred proofs, return early.
+        if self.pairing_checks.is_empty() {
+            return Ok(());
+        }
+
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+
+        // Verify the deferred proofs.
+        let num_proofs = self.pairing_checks.len();
+        let pairing_checks = self.pairing_checks.into_iter().map(|(_, pairing_check)| pairing_check);
+        let is_valid = Varuna::<N>::verify_pairing_checks(universal_verifier, pairing_checks, rng)?;
+
+        #[cfg(feature = "aleo-cli")]
+        println!(
+            "{}",
+            format!(" • Verified {num_proofs} batch proofs: {is_valid} (in {} ms)", timer.elapsed().as_millis())
+                .dimmed()
+        );
+
+        match is_valid {
+            true => Ok(()),
+            false => bail!("Failed to verify the batch of {num_proofs} proofs"),
+        }
+    }
+}
+
+impl<N: Network> Default for BatchVerifier<N> {
+    /// Initializes a new batch verifier.
+    fn default() -> Self {
+        Self::new()
+    }
+}
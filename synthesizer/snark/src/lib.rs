@@ -28,6 +28,9 @@ use colored::Colorize;
 
 type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, varuna::VarunaHidingMode>;
 
+mod batch_verifier;
+pub use batch_verifier::BatchVerifier;
+
 mod certificate;
 pub use certificate::Certificate;
 
@@ -209,4 +212,34 @@ mod test {
 
         println!("Called verifier");
     }
+
+    #[test]
+    fn test_varuna_batch_verifier() {
+        let rng = &mut TestRng::default();
+
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Construct two independent proofs.
+        let proof_0 = proving_key.prove("test", &assignment, rng).unwrap();
+        let proof_1 = proving_key.prove("test", &assignment, rng).unwrap();
+
+        let one = <Circuit as Environment>::BaseField::one();
+
+        // Should pass.
+        let mut batch_verifier = BatchVerifier::<CurrentNetwork>::new();
+        batch_verifier.add("test_0", vec![(verifying_key.clone(), vec![vec![one, one]])], &proof_0).unwrap();
+        batch_verifier.add("test_1", vec![(verifying_key.clone(), vec![vec![one, one]])], &proof_1).unwrap();
+        assert_eq!(batch_verifier.locators().collect::<Vec<_>>(), ["test_0", "test_1"]);
+        assert!(batch_verifier.verify(rng).is_ok());
+
+        // Should fail, as the public inputs of the second proof are incorrect.
+        let mut batch_verifier = BatchVerifier::<CurrentNetwork>::new();
+        batch_verifier.add("test_0", vec![(verifying_key.clone(), vec![vec![one, one]])], &proof_0).unwrap();
+        batch_verifier.add("test_1", vec![(verifying_key, vec![vec![one, one + one]])], &proof_1).unwrap();
+        assert!(batch_verifier.verify(rng).is_err());
+
+        // Should pass, as there are no deferred proofs.
+        assert!(BatchVerifier::<CurrentNetwork>::new().verify(rng).is_ok());
+    }
 }
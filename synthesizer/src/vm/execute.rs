@@ -126,7 +126,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Prepare the query.
         let query = match query {
             Some(query) => query,
-            None => Query::VM(self.block_store().clone()),
+            None => Query::from((self.block_store().clone(), self.finalize_store().clone())),
         };
         lap!(timer, "Prepare the query");
 
@@ -171,7 +171,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Prepare the query.
         let query = match query {
             Some(query) => query,
-            None => Query::VM(self.block_store().clone()),
+            None => Query::from((self.block_store().clone(), self.finalize_store().clone())),
         };
         lap!(timer, "Prepare the query");
 
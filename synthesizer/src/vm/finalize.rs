@@ -74,6 +74,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
             };
 
+        // Record the reasons the transactions failed verification, for their receipts.
+        self.record_rejection_reasons(verification_aborted_transactions.iter().map(|(tx, e)| (tx.id(), e.clone())));
+
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
         let (ratifications, confirmed_transactions, speculation_aborted_transactions, ratified_finalize_operations) =
             self.atomic_speculate(
@@ -131,11 +134,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // NOTE: We perform the transaction checks here prior to `atomic_speculate` because we must
         // ensure that the `Fee` transactions are valid. We can't unify the transaction checks in `atomic_speculate`
         // because we run speculation on the unconfirmed variant of the transactions.
-        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction(transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Note: The proofs of all transactions are checked at once, with a single batched pairing check.
+        let transactions_to_check = transactions
+            .iter()
+            .map(|transaction| Ok((transaction.transaction(), transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.check_transactions(&transactions_to_check, rng)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
 
         // Reconstruct the candidate ratifications to verify the speculation.
         let candidate_ratifications = ratifications.iter().cloned().collect::<Vec<_>>();
@@ -177,13 +182,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         solutions: &Solutions<N>,
         transactions: &Transactions<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_with_receipts(state, ratifications, solutions, transactions)
+            .map(|(ratified_finalize_operations, _receipts)| ratified_finalize_operations)
+    }
+
+    /// Finalizes the given transactions into the VM.
+    ///
+    /// Returns the finalize operations from pre-ratify and post-ratify,
+    /// and the receipts of the confirmed transactions.
+    #[inline]
+    pub(crate) fn finalize_with_receipts(
+        &self,
+        state: FinalizeGlobalState,
+        ratifications: &Ratifications<N>,
+        solutions: &Solutions<N>,
+        transactions: &Transactions<N>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<TransactionReceipt<N>>)> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let (ratified_finalize_operations, receipts) =
+            self.atomic_finalize(state, ratifications, solutions, transactions)?;
 
         finish!(timer, "Finished real-run of finalize");
-        Ok(ratified_finalize_operations)
+        Ok((ratified_finalize_operations, receipts))
     }
 }
 
@@ -288,6 +310,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut confirmed = Vec::with_capacity(num_transactions);
             // Initialize a list of the aborted transactions.
             let mut aborted = Vec::new();
+            // Initialize a list of the reasons the transactions were rejected.
+            let mut rejection_reasons = Vec::new();
             // Initialize a list of the successful deployments.
             let mut deployments = IndexSet::new();
             // Initialize a counter for the confirmed transaction index.
@@ -301,8 +325,44 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize the list of created transition public keys.
             let mut tpks: IndexSet<Group<N>> = IndexSet::new();
 
+            // Collect the transactions, so that independent executions can be speculated on ahead of time.
+            let transactions = transactions.collect::<Vec<_>>();
+            // Initialize a list of the executions that were speculated on in parallel.
+            let mut speculations: Vec<Option<Speculation<N>>> = (0..num_transactions).map(|_| None).collect();
+            // Initialize the index of the first transaction that is not part of a parallel speculation wave.
+            let mut next_wave_index = 0;
+
             // Finalize the transactions.
-            'outer: for transaction in transactions {
+            'outer: for (index, transaction) in transactions.iter().copied().enumerate() {
+                // If this transaction starts a new wave, speculate on the non-conflicting executions in parallel.
+                // Each execution is finalized on an isolated view of the finalize store, containing the mapping
+                // entries in its access set. The results are merged below, in order, as if speculated sequentially.
+                if index >= next_wave_index {
+                    let wave = speculation_wave(&process, store, &transactions[index..]);
+                    next_wave_index = index + wave.len().max(1);
+                    if wave.len() > 1 {
+                        let results = cfg_into_iter!(wave)
+                            .enumerate()
+                            .map(|(offset, access_set)| match transactions[index + offset] {
+                                Transaction::Execute(_, execution, fee) => speculate_execution_isolated(
+                                    &process,
+                                    state,
+                                    store,
+                                    execution,
+                                    fee.as_ref(),
+                                    &access_set,
+                                )
+                                .ok(),
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>();
+                        // Note: On failure to construct a view, the execution is speculated on sequentially.
+                        for (offset, speculation) in results.into_iter().enumerate() {
+                            speculations[index + offset] = speculation;
+                        }
+                    }
+                }
+
                 // Ensure the number of confirmed transactions does not exceed the maximum.
                 // Upon reaching the maximum number of confirmed transactions, all remaining transactions are aborted.
                 if confirmed.len() >= Self::MAXIMUM_CONFIRMED_TRANSACTIONS {
@@ -391,7 +451,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         match deployments.contains(deployment.program_id()) {
                             // If the program has already been deployed, construct the rejected deploy transaction.
                             true => match process_rejected_deployment(fee, *deployment.clone()) {
-                                Ok(result) => result,
+                                Ok(result) => {
+                                    // Store the reason the deployment was rejected.
+                                    let reason =
+                                        format!("Program '{}' is already deployed in the block", deployment.program_id());
+                                    rejection_reasons.push((transaction.id(), reason));
+                                    result
+                                }
                                 Err(error) => {
                                     // Note: On failure, skip this transaction, and continue speculation.
                                     #[cfg(debug_assertions)]
@@ -412,8 +478,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                         .map_err(|e| e.to_string())
                                 }
                                 // Construct the rejected deploy transaction.
-                                Err(_error) => match process_rejected_deployment(fee, *deployment.clone()) {
-                                    Ok(result) => result,
+                                Err(reason) => match process_rejected_deployment(fee, *deployment.clone()) {
+                                    Ok(result) => {
+                                        // Store the reason the deployment was rejected.
+                                        rejection_reasons.push((transaction.id(), reason.to_string()));
+                                        result
+                                    }
                                     Err(error) => {
                                         // Note: On failure, skip this transaction, and continue speculation.
                                         #[cfg(debug_assertions)]
@@ -430,41 +500,44 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // The finalize operation here involves calling 'update_key_value',
                     // and update the respective leaves of the finalize tree.
                     Transaction::Execute(_, execution, fee) => {
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        let outcome = match speculations[index].take() {
+                            // If the execution was speculated on in parallel, apply its changes to the finalize store.
+                            Some(speculation) => match speculation.apply(store) {
+                                Ok(()) => speculation.outcome,
+                                // Note: This will abort the entire atomic batch.
+                                Err(error) => return Err(format!("Failed to apply a speculated execution - {error}")),
+                            },
+                            // Otherwise, speculate on the execution directly.
+                            None => speculate_execution(&process, state, store, execution, fee.as_ref()),
+                        };
+                        match outcome {
                             // Construct the accepted execute transaction.
-                            Ok(finalize) => {
+                            SpeculationOutcome::Accepted(finalize) => {
                                 ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
-                                // Finalize the fee, to ensure it is valid.
-                                Some(fee) => {
-                                    match process.finalize_fee(state, store, fee).and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
-                                    }) {
-                                        Ok((fee_tx, finalize)) => {
-                                            // Construct the rejected execution.
-                                            let rejected = Rejected::new_execution(execution.clone());
-                                            // Construct the rejected execute transaction.
-                                            ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
-                                                .map_err(|e| e.to_string())
-                                        }
-                                        Err(error) => {
-                                            // Note: On failure, skip this transaction, and continue speculation.
-                                            #[cfg(debug_assertions)]
-                                            eprintln!("Failed to finalize the fee in a rejected execute - {error}");
-                                            // Store the aborted transaction.
-                                            aborted.push((transaction.clone(), error.to_string()));
-                                            // Continue to the next transaction.
-                                            continue 'outer;
-                                        }
-                                    }
-                                }
-                                // This is a foundational bug - the caller is violating protocol rules.
-                                // Note: This will abort the entire atomic batch.
-                                None => Err("Rejected execute transaction has no fee".to_string()),
-                            },
+                            SpeculationOutcome::Rejected(fee_tx, finalize, reason) => {
+                                // Store the reason the execution was rejected.
+                                rejection_reasons.push((transaction.id(), reason));
+                                // Construct the rejected execution.
+                                let rejected = Rejected::new_execution(execution.clone());
+                                // Construct the rejected execute transaction.
+                                ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
+                                    .map_err(|e| e.to_string())
+                            }
+                            SpeculationOutcome::Aborted(error) => {
+                                // Note: On failure, skip this transaction, and continue speculation.
+                                #[cfg(debug_assertions)]
+                                eprintln!("Failed to finalize the fee in a rejected execute - {error}");
+                                // Store the aborted transaction.
+                                aborted.push((transaction.clone(), error));
+                                // Continue to the next transaction.
+                                continue 'outer;
+                            }
+                            // This is a foundational bug - the caller is violating protocol rules.
+                            // Note: This will abort the entire atomic batch.
+                            SpeculationOutcome::Invalid(error) => Err(error),
                         }
                     }
                     // There are no finalize operations here.
@@ -555,6 +628,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 return Err("Failed to construct the ratifications after speculation".to_string());
             };
 
+            // Record the reasons the transactions were rejected or aborted, for their receipts.
+            self.record_rejection_reasons(
+                rejection_reasons.into_iter().chain(aborted.iter().map(|(tx, error)| (tx.id(), error.clone()))),
+            );
+
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the ratifications,
@@ -565,7 +643,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Performs atomic finalization over a list of transactions.
     ///
-    /// Returns the finalize operations from pre-ratify and post-ratify.
+    /// Returns the finalize operations from pre-ratify and post-ratify,
+    /// and the receipts of the confirmed transactions.
     #[inline]
     fn atomic_finalize(
         &self,
@@ -573,7 +652,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: &Solutions<N>,
         transactions: &Transactions<N>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<TransactionReceipt<N>>)> {
         // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
         // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
         // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
@@ -618,6 +697,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
+            // Initialize a list for the transaction receipts.
+            let mut receipts = Vec::with_capacity(transactions.len());
 
             // Finalize the transactions.
             for (index, transaction) in transactions.iter().enumerate() {
@@ -770,8 +851,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 lap!(timer, "Finalizing transaction {}", transaction.id());
 
                 match outcome {
-                    // If the transaction succeeded to finalize, continue to the next transaction.
-                    Ok(()) => (),
+                    // If the transaction succeeded to finalize, store its receipt and continue to the next transaction.
+                    Ok(()) => {
+                        // Retrieve the reason the transaction was rejected, as recorded during speculation.
+                        let reason = match transaction.is_rejected() {
+                            true => transaction
+                                .to_unconfirmed_transaction_id()
+                                .ok()
+                                .and_then(|transaction_id| self.rejection_reasons.read().peek(&transaction_id).cloned()),
+                            false => None,
+                        };
+                        match TransactionReceipt::from_confirmed(state.block_height(), transaction, reason) {
+                            Ok(receipt) => receipts.push(receipt),
+                            // Note: This will abort the entire atomic batch.
+                            Err(e) => return Err(format!("Failed to construct the receipt of a transaction - {e}")),
+                        }
+                    }
                     // If the transaction failed to finalize, abort and continue to the next transaction.
                     Err(error) => {
                         eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
@@ -790,6 +885,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            /* Record the finalize rollback and history. */
+
+            // Record the reverse of the finalize state changes of the block, so the block can be rolled back.
+            if let Err(e) = store.record_rollback(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the finalize rollback - {e}"));
+            }
+            // Record the key-value changes of the block, if the finalize history is enabled.
+            if let Err(e) = store.record_history(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the finalize history - {e}"));
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
@@ -799,10 +907,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
-            Ok(ratified_finalize_operations)
+            Ok((ratified_finalize_operations, receipts))
         })
     }
 
+    /// Records the reasons that the given transactions were rejected or aborted,
+    /// so that the receipts of the transactions include them once their block is finalized.
+    #[inline]
+    fn record_rejection_reasons(&self, reasons: impl IntoIterator<Item = (N::TransactionID, String)>) {
+        let mut rejection_reasons = self.rejection_reasons.write();
+        for (transaction_id, reason) in reasons {
+            rejection_reasons.put(transaction_id, reason);
+        }
+    }
+
     /// Performs the pre-ratifications before finalizing transactions.
     #[inline]
     fn atomic_pre_ratify<'a>(
@@ -1696,6 +1814,198 @@ finalize transfer_public:
         }
     }
 
+    #[test]
+    fn test_atomic_finalize_parallel_speculation() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+
+        // Sample the addresses for the recipients.
+        let recipients = (0..3).map(|_| Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct a mint to each recipient, and a transfer from the caller.
+        let mints = recipients
+            .iter()
+            .map(|recipient| {
+                sample_mint_public(&vm, caller_private_key, &program_id, *recipient, 10, &mut unspent_records, rng)
+            })
+            .collect::<Vec<_>>();
+        let transfer =
+            sample_transfer_public(&vm, caller_private_key, &program_id, recipients[0], 10, &mut unspent_records, rng);
+
+        // Ensure the mints are speculated on in parallel, and the transfer is not.
+        let transactions = mints.iter().chain([&transfer]).collect::<Vec<_>>();
+        let wave = speculation_wave(&vm.process().read(), vm.finalize_store(), &transactions);
+        assert_eq!(wave.len(), mints.len());
+        for (access_set, recipient) in wave.iter().zip_eq(&recipients) {
+            let key = Plaintext::from(Literal::Address(*recipient));
+            assert!(access_set.reads().iter().any(|(_, _, k)| k == &key));
+            assert!(access_set.writes().iter().any(|(_, _, k)| k == &key));
+        }
+        let transfer_access_set = match &transfer {
+            Transaction::Execute(_, execution, fee) => {
+                vm.process().read().finalize_access_set(execution, fee.as_ref()).unwrap().unwrap()
+            }
+            _ => unreachable!(),
+        };
+        assert!(wave[0].is_conflicting(&transfer_access_set));
+        assert!(!wave[1].is_conflicting(&transfer_access_set));
+
+        // Speculate on each mint individually. As the mints do not conflict, this is equivalent to sequential speculation.
+        let expected = mints
+            .iter()
+            .map(|mint| {
+                let (_, confirmed_transactions, _, _) = vm
+                    .atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), [mint].into_iter())
+                    .unwrap();
+                confirmed_transactions[0].finalize_operations().clone()
+            })
+            .collect::<Vec<_>>();
+
+        // Speculate on the mints in parallel, followed by the conflicting transfer.
+        // Caller Balance = 0
+        // Transfer_10 -> Balance = 0 - 10 = -10 (should be rejected)
+        let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+            .atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.into_iter())
+            .unwrap();
+
+        // Assert that the caller has an insufficient balance, and the transfer is rejected.
+        assert_eq!(confirmed_transactions.len(), 4);
+        assert!(aborted_transaction_ids.is_empty());
+        assert!(confirmed_transactions[3].is_rejected());
+        assert_eq!(confirmed_transactions[3], reject(3, &transfer, confirmed_transactions[3].finalize_operations()));
+
+        // Ensure the mints are accepted, with the same finalize operations as sequential speculation.
+        for (index, (mint, expected)) in mints.iter().zip_eq(expected).enumerate() {
+            assert!(confirmed_transactions[index].is_accepted());
+            assert_eq!(confirmed_transactions[index].transaction(), mint);
+            assert_eq!(confirmed_transactions[index].finalize_operations(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_atomic_finalize_parallel_speculation_conflict_on_new_key() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+
+        // Sample the addresses for the recipients, which do not have a balance yet.
+        let recipients = (0..2).map(|_| Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct two mints to the first recipient, and a mint to the second recipient.
+        let mints = [recipients[0], recipients[0], recipients[1]]
+            .into_iter()
+            .map(|recipient| {
+                sample_mint_public(&vm, caller_private_key, &program_id, recipient, 10, &mut unspent_records, rng)
+            })
+            .collect::<Vec<_>>();
+        let transactions = mints.iter().collect::<Vec<_>>();
+
+        // Ensure the balance of the first recipient is not confirmed yet.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(recipients[0]));
+        assert!(!vm.finalize_store().contains_key_confirmed(program_id, mapping_name, &key).unwrap());
+
+        // Ensure the first two mints conflict on the new key, and the last two mints are speculated on in parallel.
+        let process = vm.process().read().clone();
+        let wave = speculation_wave(&process, vm.finalize_store(), &transactions);
+        assert_eq!(wave.len(), 1);
+        assert!(wave[0].writes().contains(&(program_id, mapping_name, key)));
+        assert_eq!(speculation_wave(&process, vm.finalize_store(), &transactions[1..]).len(), 2);
+
+        // Speculate on the mints sequentially.
+        let state = sample_finalize_state(1);
+        let store = vm.finalize_store();
+        store.start_atomic();
+        let expected = mints
+            .iter()
+            .map(|mint| match mint {
+                Transaction::Execute(_, execution, fee) => {
+                    match speculate_execution(&process, state, store, execution, fee.as_ref()) {
+                        SpeculationOutcome::Accepted(finalize) => finalize,
+                        _ => panic!("The mint should be accepted"),
+                    }
+                }
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        store.abort_atomic();
+
+        // Speculate on the mints, with the non-conflicting mints in parallel.
+        let (_, confirmed_transactions, aborted_transaction_ids, _) =
+            vm.atomic_speculate(state, None, vec![], &None.into(), transactions.into_iter()).unwrap();
+
+        // Ensure the mints are accepted, with the same finalize operations as sequential speculation.
+        assert_eq!(confirmed_transactions.len(), 3);
+        assert!(aborted_transaction_ids.is_empty());
+        for (index, (mint, expected)) in mints.iter().zip_eq(expected).enumerate() {
+            assert!(confirmed_transactions[index].is_accepted());
+            assert_eq!(confirmed_transactions[index].transaction(), mint);
+            assert_eq!(confirmed_transactions[index].finalize_operations(), &expected);
+        }
+    }
+
     #[test]
     fn test_finalize_catch_halt() {
         let rng = &mut TestRng::default();
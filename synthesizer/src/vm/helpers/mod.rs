@@ -19,3 +19,6 @@ mod macros;
 
 mod rewards;
 pub use rewards::*;
+
+mod speculation;
+pub(crate) use speculation::*;
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Value};
+use ledger_block::{Execution, Fee, Transaction};
+use ledger_store::{helpers::memory::FinalizeMemory, FinalizeStorage, FinalizeStore};
+use synthesizer_process::{AccessSet, MappingEntry, Process};
+use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait};
+
+/// The outcome of finalizing an execute transaction during speculation.
+pub(crate) enum SpeculationOutcome<N: Network> {
+    /// The execution was finalized, with the given finalize operations.
+    Accepted(Vec<FinalizeOperation<N>>),
+    /// The execution was rejected for the given reason, and the fee was finalized,
+    /// with the given fee transaction and finalize operations.
+    Rejected(Transaction<N>, Vec<FinalizeOperation<N>>, String),
+    /// The execution was rejected, and the fee failed to finalize, with the given error.
+    Aborted(String),
+    /// The execution was rejected, and has no fee.
+    /// Note: This will abort the entire atomic batch.
+    Invalid(String),
+}
+
+/// An execute transaction that was speculated on an isolated view of the finalize store.
+pub(crate) struct Speculation<N: Network> {
+    /// The outcome of the speculation.
+    pub(crate) outcome: SpeculationOutcome<N>,
+    /// The mapping entries that were changed, with their new values (or `None`, if removed).
+    pub(crate) changes: Vec<(MappingEntry<N>, Option<Value<N>>)>,
+}
+
+impl<N: Network> Speculation<N> {
+    /// Applies the changed mapping entries of this speculation to the given finalize store.
+    pub(crate) fn apply<P: FinalizeStorage<N>>(&self, store: &FinalizeStore<N, P>) -> Result<()> {
+        for ((program_id, mapping_name, key), value) in &self.changes {
+            match value {
+                Some(value) => {
+                    store.update_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+                }
+                None => {
+                    store.remove_key_value(*program_id, *mapping_name, key)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finalizes the given execution and fee on the given finalize store.
+pub(crate) fn speculate_execution<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    execution: &Execution<N>,
+    fee: Option<&Fee<N>>,
+) -> SpeculationOutcome<N> {
+    match process.finalize_execution(state, store, execution, fee) {
+        // The execution is accepted.
+        Ok(finalize) => SpeculationOutcome::Accepted(finalize),
+        // The execution is rejected.
+        Err(reason) => match fee {
+            // Finalize the fee, to ensure it is valid.
+            Some(fee) => match process
+                .finalize_fee(state, store, fee)
+                .and_then(|finalize| Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize)))
+            {
+                Ok((fee_tx, finalize)) => SpeculationOutcome::Rejected(fee_tx, finalize, reason.to_string()),
+                Err(error) => SpeculationOutcome::Aborted(error.to_string()),
+            },
+            // This is a foundational bug - the caller is violating protocol rules.
+            None => SpeculationOutcome::Invalid("Rejected execute transaction has no fee".to_string()),
+        },
+    }
+}
+
+/// Finalizes the given execution and fee on isolated views of the given finalize store,
+/// which only contain the mapping entries in the given access set.
+///
+/// The execution and the fee of a rejected execution are each finalized on a fresh view,
+/// so a failed execution is discarded as a whole, instead of being rewound to a checkpoint.
+pub(crate) fn speculate_execution_isolated<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    execution: &Execution<N>,
+    fee: Option<&Fee<N>>,
+    access_set: &AccessSet<N>,
+) -> Result<Speculation<N>> {
+    // Retrieve the values of the accessed mapping entries.
+    let values = access_set
+        .entries()
+        .map(|(program_id, mapping_name, key)| store.get_value_speculative(*program_id, *mapping_name, key))
+        .collect::<Result<Vec<_>>>()?;
+    // Initializes an isolated view, containing the accessed mapping entries.
+    let isolated_view = || -> Result<FinalizeStore<N, FinalizeMemory<N>>> {
+        let view = FinalizeStore::<N, FinalizeMemory<N>>::open(None)?;
+        for (program_id, mapping_name) in access_set.mappings() {
+            view.initialize_mapping(program_id, mapping_name)?;
+        }
+        for ((program_id, mapping_name, key), value) in access_set.entries().zip_eq(&values) {
+            if let Some(value) = value {
+                view.insert_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+            }
+        }
+        Ok(view)
+    };
+
+    // Finalize the execution on a view.
+    let view = isolated_view()?;
+    let (outcome, view) = match process.finalize_execution(state, &view, execution, fee) {
+        // The execution is accepted.
+        Ok(finalize) => (SpeculationOutcome::Accepted(finalize), view),
+        // The execution is rejected.
+        Err(reason) => match fee {
+            // Finalize the fee on a fresh view, to ensure it is valid.
+            Some(fee) => {
+                let view = isolated_view()?;
+                let outcome = match process
+                    .finalize_fee(state, &view, fee)
+                    .and_then(|finalize| Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize)))
+                {
+                    Ok((fee_tx, finalize)) => SpeculationOutcome::Rejected(fee_tx, finalize, reason.to_string()),
+                    Err(error) => SpeculationOutcome::Aborted(error.to_string()),
+                };
+                (outcome, view)
+            }
+            // This is a foundational bug - the caller is violating protocol rules.
+            None => (SpeculationOutcome::Invalid("Rejected execute transaction has no fee".to_string()), view),
+        },
+    };
+
+    // Collect the declared mapping entries that were changed.
+    let mut changes = Vec::new();
+    for (entry, original_value) in access_set.entries().zip_eq(values) {
+        if !access_set.writes().contains(entry) {
+            continue;
+        }
+        let (program_id, mapping_name, key) = entry;
+        let value = view.get_value_speculative(*program_id, *mapping_name, key)?;
+        if value != original_value {
+            changes.push((entry.clone(), value));
+        }
+    }
+    Ok(Speculation { outcome, changes })
+}
+
+/// Returns the access sets of the longest prefix of the given transactions that can be speculated on in parallel,
+/// i.e. a run of execute transactions with statically-known access sets over existing mappings, that do not conflict.
+///
+/// Conflicts are detected from the mapping entries that the finalize commands declare to read and write,
+/// so two executions conflict on a key even if it does not exist yet, or was only written earlier in this block.
+pub(crate) fn speculation_wave<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    store: &FinalizeStore<N, P>,
+    transactions: &[&Transaction<N>],
+) -> Vec<AccessSet<N>> {
+    let mut wave: Vec<AccessSet<N>> = Vec::new();
+    for transaction in transactions {
+        // Only execute transactions are speculated on in parallel.
+        let Transaction::Execute(_, execution, fee) = transaction else {
+            break;
+        };
+        // Retrieve the access set of the execution.
+        let Ok(Some(access_set)) = process.finalize_access_set(execution, fee.as_ref()) else {
+            break;
+        };
+        // Ensure the accessed mappings exist, including mappings initialized earlier in this block.
+        if !access_set.mappings().iter().all(|(program_id, mapping_name)| {
+            store.contains_mapping_speculative(program_id, mapping_name).unwrap_or(false)
+        }) {
+            break;
+        }
+        // Ensure the access set does not conflict with the preceding transactions in the wave.
+        if wave.iter().any(|other| other.is_conflicting(&access_set)) {
+            break;
+        }
+        wave.push(access_set);
+    }
+    wave
+}
@@ -40,6 +40,7 @@ use ledger_block::{
     Rejected,
     Solutions,
     Transaction,
+    TransactionReceipt,
     Transactions,
 };
 use ledger_committee::Committee;
@@ -54,9 +55,11 @@ use ledger_store::{
     TransactionStorage,
     TransactionStore,
     TransitionStore,
+    MAX_ROLLBACK_DEPTH,
 };
 use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_snark::BatchVerifier;
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::{IndexMap, IndexSet};
@@ -80,6 +83,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     block_lock: Arc<Mutex<()>>,
     /// A cache containing the list of recent partially-verified transactions.
     partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, ()>>>,
+    /// A cache containing the reasons that recent transactions were rejected or aborted during speculation.
+    rejection_reasons: Arc<RwLock<LruCache<N::TransactionID, String>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -99,6 +104,33 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Load the deployments from the store.
+        let transaction_store = store.transaction_store();
+        let deployment_ids = transaction_store.deployment_transaction_ids().map(|id| *id).collect::<Vec<_>>();
+        Self::load_deployments(&mut process, transaction_store, &deployment_ids)?;
+
+        // Return the new VM.
+        Ok(Self {
+            process: Arc::new(RwLock::new(process)),
+            store,
+            atomic_lock: Arc::new(Mutex::new(())),
+            block_lock: Arc::new(Mutex::new(())),
+            partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
+                NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
+            ))),
+            rejection_reasons: Arc::new(RwLock::new(LruCache::new(
+                NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
+            ))),
+        })
+    }
+
+    /// Loads the given deployments from the store into the process, along with their imports.
+    /// The deployments of programs that already exist in the process are skipped.
+    fn load_deployments(
+        process: &mut Process<N>,
+        transaction_store: &TransactionStore<N, C::TransactionStorage>,
+        deployment_ids: &[N::TransactionID],
+    ) -> Result<()> {
         // A helper function to retrieve all the deployments.
         fn load_deployment_and_imports<N: Network, T: TransactionStorage<N>>(
             process: &Process<N>,
@@ -149,10 +181,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Ok(deployments)
         }
 
-        // Retrieve the transaction store.
-        let transaction_store = store.transaction_store();
-        // Retrieve the list of deployment transaction IDs.
-        let deployment_ids = transaction_store.deployment_transaction_ids().collect::<Vec<_>>();
         // Load the deployments from the store.
         for (i, chunk) in deployment_ids.chunks(256).enumerate() {
             debug!(
@@ -164,7 +192,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let deployments = cfg_iter!(chunk)
                 .map(|transaction_id| {
                     // Load the deployment and its imports.
-                    load_deployment_and_imports(&process, transaction_store, **transaction_id)
+                    load_deployment_and_imports(&*process, transaction_store, *transaction_id)
                 })
                 .collect::<Result<Vec<_>>>()?;
 
@@ -176,16 +204,47 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
-        // Return the new VM.
-        Ok(Self {
-            process: Arc::new(RwLock::new(process)),
-            store,
-            atomic_lock: Arc::new(Mutex::new(())),
-            block_lock: Arc::new(Mutex::new(())),
-            partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
-                NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
-            ))),
-        })
+        Ok(())
+    }
+
+    /// Catches up with the primary instance of the storage, if the storage is a secondary instance,
+    /// and loads the programs that were deployed since.
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        // Catch up with the primary instance of the storage.
+        self.store.try_catch_up_with_primary()?;
+
+        // Retrieve the transaction store.
+        let transaction_store = self.transaction_store();
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+        // Retrieve the deployment transaction IDs of the programs that are not loaded.
+        let mut deployment_ids = Vec::new();
+        for transaction_id in transaction_store.deployment_transaction_ids() {
+            match transaction_store.get_program_id(&transaction_id)? {
+                Some(program_id) if process.contains_program(&program_id) => continue,
+                _ => deployment_ids.push(*transaction_id),
+            }
+        }
+        // Load the deployments.
+        Self::load_deployments(&mut process, transaction_store, &deployment_ids)
+    }
+
+    /// Enables dev mode in the process, in which executions and fees carry a mock proof in place of a proof.
+    ///
+    /// Note: Dev mode is unavailable without the `dev-mode` feature, on a network that does not allow it,
+    /// and on the production network, i.e. if the genesis block of the VM is the genesis block of the network.
+    #[cfg(feature = "dev-mode")]
+    pub fn enable_dev_mode(&self) -> Result<()> {
+        // Retrieve the genesis block hash.
+        let Some(genesis_hash) = self.block_store().get_block_hash(0)? else {
+            bail!("Cannot enable dev mode before the genesis block is added")
+        };
+        // Ensure the VM is not on the production network.
+        if genesis_hash == Block::<N>::from_bytes_le(N::genesis_bytes())?.hash() {
+            bail!("Dev mode is unavailable on the production network of '{}'", N::NAME)
+        }
+        // Enable dev mode in the process.
+        self.process.write().enable_dev_mode()
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -371,9 +430,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // First, insert the block.
         self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
-            Ok(_ratified_finalize_operations) => {
+        // Next, finalize the transactions, and store their receipts.
+        let finalize_result =
+            self.finalize_with_receipts(state, block.ratifications(), block.solutions(), block.transactions());
+        match finalize_result.and_then(|(_ratified_finalize_operations, mut receipts)| {
+            // Construct the receipts of the aborted transactions.
+            // Note: The reason a transaction was aborted is not included in the block,
+            // so it is only known if this VM speculated on the transaction.
+            for transaction_id in block.aborted_transaction_ids() {
+                let reason = self.rejection_reasons.read().peek(transaction_id).cloned();
+                receipts.push(TransactionReceipt::from_aborted(block.height(), *transaction_id, reason)?);
+            }
+            self.block_store().insert_receipts(&receipts)
+        }) {
+            Ok(()) => {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
                 #[cfg(feature = "rocks")]
                 self.block_store().unpause_atomic_writes::<false>()?;
@@ -409,6 +479,94 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Removes the last `n` blocks from the VM in a single atomic batch, reverting their finalize state changes,
+    /// and removing the programs that were deployed in the blocks from the process.
+    /// If any of the blocks cannot be removed, the VM is left unchanged.
+    #[inline]
+    pub fn remove_last_blocks(&self, n: u32) -> Result<()> {
+        // Ensure 'n' is non-zero.
+        ensure!(n > 0, "Cannot remove zero blocks");
+        // Ensure 'n' is within the rollback depth.
+        ensure!(n <= MAX_ROLLBACK_DEPTH, "Cannot remove more than the last {MAX_ROLLBACK_DEPTH} blocks");
+
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+        // Acquire the atomic lock, which is needed to ensure the finalize state is not changed concurrently.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the heights of the blocks to remove, from the latest block.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+            bail!("Cannot remove the last {n} blocks - the block store is empty")
+        };
+        ensure!(n <= latest_height, "Cannot remove the last {n} blocks - the genesis block cannot be removed");
+        let heights = (latest_height - n + 1..=latest_height).rev();
+
+        // Retrieve the transactions of the blocks, and ensure each block can be rolled back.
+        let mut transactions = Vec::with_capacity(n as usize);
+        for height in heights.clone() {
+            ensure!(
+                self.finalize_store().contains_rollback(height)?,
+                "Cannot remove block {height} - no finalize rollback was recorded for it \
+                 (only the latest {MAX_ROLLBACK_DEPTH} blocks, finalized since rollbacks were recorded, can be rolled back)"
+            );
+            let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                bail!("Cannot remove block {height} - missing block hash")
+            };
+            let Some(block_transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Cannot remove block {height} - missing transactions")
+            };
+            transactions.push(block_transactions);
+        }
+
+        // Pause the atomic writes, so that the finalize rollbacks and the removal belong to a single batch.
+        #[cfg(feature = "rocks")]
+        self.block_store().pause_atomic_writes()?;
+        // Start an atomic batch over the finalize store and the block store, for all of the blocks.
+        self.finalize_store().start_atomic();
+        self.block_store().start_atomic();
+
+        // First, roll back the finalize state of each block, from the latest block, then remove the blocks.
+        let result = heights
+            .into_iter()
+            .try_for_each(|height| {
+                self.finalize_store()
+                    .rollback_block(height)
+                    .map_err(|error| anyhow!("Failed to roll back block {height} - {error}"))
+            })
+            .and_then(|_| self.block_store().remove_last_n(n))
+            .and_then(|_| self.finalize_store().finish_atomic())
+            .and_then(|_| self.block_store().finish_atomic());
+        match result {
+            Ok(()) => {
+                // Unpause the atomic writes, executing the ones queued from the rollbacks and removal.
+                #[cfg(feature = "rocks")]
+                self.block_store().unpause_atomic_writes::<false>()?;
+            }
+            Err(error) => {
+                // Clear all pending atomic operations.
+                self.block_store().abort_atomic();
+                self.finalize_store().abort_atomic();
+                // Disable the atomic batch override.
+                // Note: This call is guaranteed to succeed (without error), because `DISCARD_BATCH == true`.
+                #[cfg(feature = "rocks")]
+                self.block_store().unpause_atomic_writes::<true>()?;
+                // Reload the block tree, as it is updated before the atomic batch is written.
+                self.block_store().reload_tree()?;
+                return Err(error);
+            }
+        }
+
+        // Lastly, remove the stacks of the programs that were deployed in the blocks.
+        let mut process = self.process.write();
+        for transaction in transactions.iter().flat_map(|transactions| transactions.deployments()) {
+            if let Some(deployment) = transaction.transaction().deployment() {
+                process.remove_stack(deployment.program_id());
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -39,6 +41,151 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
+    ) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, None, rng)
+    }
+
+    /// Verifies the given transactions (and their rejected IDs) in the VM. On failure, returns an error.
+    ///
+    /// The execution and fee proofs of all transactions are checked at once, with a single batched pairing check.
+    /// If the batched pairing check fails, each transaction is verified individually, to pinpoint the invalid transaction.
+    pub fn check_transactions<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
+        // Verify each transaction, deferring the final pairing check of each proof.
+        // Note: Each transaction has its own batch verifier, so that no proof is deferred from a transaction that is invalid.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        let batch_verifiers = cfg_iter!(transactions)
+            .zip(rngs)
+            .map(|((transaction, rejected_id), mut rng)| {
+                let mut batch_verifier = BatchVerifier::new();
+                self.check_transaction_internal(transaction, *rejected_id, Some(&mut batch_verifier), &mut rng)
+                    .map_err(|e| anyhow!("Transaction '{}' is invalid - {e}", transaction.id()))?;
+                Ok(batch_verifier)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Verify the transactions (excluding the proofs)");
+
+        // Combine the deferred proofs of all transactions.
+        let mut batch_verifier = BatchVerifier::new();
+        batch_verifiers.into_iter().for_each(|mut verifier| batch_verifier.append(&mut verifier));
+        lap!(timer, "Combine {} deferred proofs", batch_verifier.len());
+
+        // Verify all of the deferred proofs at once.
+        if let Err(batch_error) = batch_verifier.verify(rng) {
+            // If the batched pairing check failed, verify each transaction individually, to pinpoint the invalid transaction.
+            return self.check_transactions_individually(transactions, batch_error, rng);
+        }
+        lap!(timer, "Verify the deferred proofs");
+
+        // As the proofs have been verified, add the transaction IDs to the partially-verified transactions cache.
+        for (transaction, _) in transactions {
+            if !matches!(transaction, Transaction::Fee(..)) {
+                self.partially_verified_transactions.write().push(transaction.id(), ());
+            }
+        }
+
+        finish!(timer, "Verify the transactions");
+        Ok(())
+    }
+
+    /// Verifies each of the given transactions individually, after the batched pairing check of their proofs failed
+    /// with the given error, to pinpoint the invalid transaction.
+    ///
+    /// This always returns an error. If every transaction verifies individually, the batched pairing check
+    /// disagrees with the individual checks, which is a bug, so the transactions are rejected all the same.
+    fn check_transactions_individually<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        batch_error: Error,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Verify each transaction individually.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        cfg_iter!(transactions).zip(rngs).try_for_each(|((transaction, rejected_id), mut rng)| {
+            self.check_transaction(transaction, *rejected_id, &mut rng)
+                .map_err(|e| anyhow!("Transaction '{}' is invalid - {e}", transaction.id()))
+        })?;
+        // Note: This case is only reachable if the batched pairing check and the individual checks disagree.
+        bail!("The batched proof check failed ({batch_error}), but each transaction verified individually")
+    }
+
+    /// Verifies the deployment and execution proofs of the given transactions, ahead of the stateful checks,
+    /// and adds the verified transactions to the partially-verified transactions cache.
+    ///
+    /// This check is best-effort: transactions that cannot be verified yet (e.g. as their program is not deployed yet)
+    /// are skipped, and are fully verified by `VM::check_transaction` instead. Returns the number of verified transactions.
+    pub fn preverify_transactions<R: CryptoRng + Rng>(&self, transactions: &[&Transaction<N>], rng: &mut R) -> usize {
+        let timer = timer!("VM::preverify_transactions");
+
+        // Select the transactions that have not been partially-verified.
+        let transactions = {
+            let cache = self.partially_verified_transactions.read();
+            transactions
+                .iter()
+                .filter(|transaction| !matches!(transaction, Transaction::Fee(..)))
+                .filter(|transaction| cache.peek(&transaction.id()).is_none())
+                .collect::<Vec<_>>()
+        };
+
+        // Verify the deployments, and defer the final pairing check of each execution proof.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        let verified = cfg_iter!(transactions)
+            .zip(rngs)
+            .filter_map(|(transaction, mut rng)| {
+                let mut batch_verifier = BatchVerifier::new();
+                let result = match transaction {
+                    Transaction::Deploy(_, _, deployment, _) => self.check_deployment_internal(deployment, &mut rng),
+                    Transaction::Execute(_, execution, _) => {
+                        self.process.read().verify_execution_deferred(execution, &mut batch_verifier)
+                    }
+                    Transaction::Fee(..) => return None,
+                };
+                result.ok().map(|()| (transaction.id(), batch_verifier))
+            })
+            .collect::<Vec<_>>();
+        lap!(timer, "Verify the deployments and executions (excluding the proofs)");
+
+        // Combine the deferred proofs of all transactions.
+        let mut batch_verifier = BatchVerifier::new();
+        let transaction_ids = verified
+            .into_iter()
+            .map(|(transaction_id, mut verifier)| {
+                batch_verifier.append(&mut verifier);
+                transaction_id
+            })
+            .collect::<Vec<_>>();
+
+        // Verify all of the deferred proofs at once.
+        // Note: If the batched pairing check fails, no transaction is cached, and each transaction is verified in full later.
+        let num_verified = match batch_verifier.verify(rng) {
+            Ok(()) => {
+                let mut cache = self.partially_verified_transactions.write();
+                transaction_ids.iter().for_each(|transaction_id| {
+                    cache.push(*transaction_id, ());
+                });
+                transaction_ids.len()
+            }
+            Err(_) => 0,
+        };
+
+        finish!(timer, "Pre-verified {num_verified} transactions");
+        num_verified
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If a batch verifier is given, the final pairing check of each proof is deferred to the batch verifier,
+    /// and the transaction is *not* added to the partially-verified transactions cache.
+    fn check_transaction_internal<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        mut batch_verifier: Option<&mut BatchVerifier<N>>,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
@@ -93,7 +240,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Check for duplicate elements");
 
         // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        self.check_transaction_fee(transaction, rejected_id, batch_verifier.as_deref_mut())?;
 
         // Check if the transaction exists in the partially-verified cache.
         let is_partially_verified = self.partially_verified_transactions.read().peek(&transaction.id()).is_some();
@@ -134,14 +281,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
                 // Verify the execution.
-                self.check_execution_internal(execution, is_partially_verified)?;
+                self.check_execution_internal(execution, is_partially_verified, batch_verifier.as_deref_mut())?;
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
 
         // If the above checks have passed and this is not a fee transaction,
         // then add the transaction ID to the partially-verified transactions cache.
-        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
+        // Note: If the proofs are deferred, the transaction ID is added once the proofs are verified.
+        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified && batch_verifier.is_none() {
             self.partially_verified_transactions.write().push(transaction.id(), ());
         }
 
@@ -152,6 +300,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transaction_fee(transaction, rejected_id, None)
+    }
+
+    /// Verifies the `fee` in the given transaction. On failure, returns an error.
+    /// If a batch verifier is given, the final pairing check of the fee proof is deferred to the batch verifier.
+    fn check_transaction_fee(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -167,7 +326,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, batch_verifier)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -195,7 +354,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    self.check_fee_internal(fee, execution_id, batch_verifier)?;
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
@@ -207,7 +366,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, batch_verifier)?,
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
@@ -243,14 +402,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
+    ///
+    /// If a batch verifier is given, the final pairing check of the proof is deferred to the batch verifier.
     #[inline]
-    fn check_execution_internal(&self, execution: &Execution<N>, is_partially_verified: bool) -> Result<()> {
+    fn check_execution_internal(
+        &self,
+        execution: &Execution<N>,
+        is_partially_verified: bool,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution proof, if it has not been partially-verified before.
-        let verification = match is_partially_verified {
-            true => Ok(()),
-            false => self.process.read().verify_execution(execution),
+        let verification = match (is_partially_verified, batch_verifier) {
+            (true, _) => Ok(()),
+            (false, Some(batch_verifier)) => self.process.read().verify_execution_deferred(execution, batch_verifier),
+            (false, None) => self.process.read().verify_execution(execution),
         };
         lap!(timer, "Verify the execution");
 
@@ -272,8 +439,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
+    ///
+    /// If a batch verifier is given, the final pairing check of the proof is deferred to the batch verifier.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch_verifier: Option<&mut BatchVerifier<N>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -281,7 +455,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        let verification = match batch_verifier {
+            Some(batch_verifier) => {
+                self.process.read().verify_fee_deferred(fee, deployment_or_execution_id, batch_verifier)
+            }
+            None => self.process.read().verify_fee(fee, deployment_or_execution_id),
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -391,13 +570,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    vm.check_execution_internal(&execution, false).unwrap();
+                    vm.check_execution_internal(&execution, false, None).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    vm.check_execution_internal(&recovered_execution, false).unwrap();
+                    vm.check_execution_internal(&recovered_execution, false, None).unwrap();
                 }
                 _ => panic!("Expected an execution transaction"),
             }
@@ -423,12 +602,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee, execution_id, None).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee, execution_id, None).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -459,6 +638,116 @@ mod tests {
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let private_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let public_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Ensure the transactions verify with a single batched pairing check.
+        vm.check_transactions(
+            &[(&deployment_transaction, None), (&private_fee_transaction, None), (&public_fee_transaction, None)],
+            rng,
+        )
+        .unwrap();
+
+        // Swap the execution proof of the public fee transaction with the execution proof of the private fee transaction.
+        // Note: The transaction ID does not commit to the proof, so the transaction remains well-formed.
+        let (Transaction::Execute(id, execution, fee), Some(other_execution)) =
+            (&public_fee_transaction, private_fee_transaction.execution())
+        else {
+            panic!("Expected an execution transaction");
+        };
+        let tampered_execution = Execution::from(
+            execution.transitions().cloned(),
+            execution.global_state_root(),
+            other_execution.proof().cloned(),
+        )
+        .unwrap();
+        let tampered_transaction = Transaction::Execute(*id, tampered_execution, fee.clone());
+
+        // Ensure the tampered transaction fails to verify, and is pinpointed as the invalid transaction.
+        // Note: A new VM is used, as the previous VM has the transaction ID in its partially-verified transactions cache.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let error = vm
+            .check_transactions(&[(&private_fee_transaction, None), (&tampered_transaction, None)], rng)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains(&tampered_transaction.id().to_string()), "{error}");
+        assert!(!error.contains(&private_fee_transaction.id().to_string()), "{error}");
+    }
+
+    #[test]
+    fn test_check_transactions_individually_after_spurious_batch_failure() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the transactions.
+        let private_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let public_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transactions = [(&private_fee_transaction, None), (&public_fee_transaction, None)];
+
+        // Ensure the transactions are rejected, even though each transaction verifies individually,
+        // if the batched pairing check of their proofs failed.
+        let error = vm
+            .check_transactions_individually(&transactions, anyhow!("Failed to verify the batch of 2 proofs"), rng)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("each transaction verified individually"), "{error}");
+    }
+
+    #[cfg(feature = "dev-mode")]
+    #[test]
+    fn test_check_transaction_dev_mode() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Ensure dev mode cannot be enabled on the production network.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.block_store().insert(&Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()).unwrap();
+        assert!(vm.enable_dev_mode().is_err());
+        assert!(!vm.process().read().is_dev_mode());
+
+        // Initialize the VM, and ensure dev mode cannot be enabled before the genesis block is added.
+        let vm = crate::vm::test_helpers::sample_vm();
+        assert!(vm.enable_dev_mode().is_err());
+        vm.add_next_block(&genesis).unwrap();
+
+        // Execute a public transfer, with a public fee.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Replace the proofs of the execution and the fee with mock proofs.
+        let Transaction::Execute(_, execution, Some(fee)) = &transaction else {
+            panic!("Expected an execution with a fee")
+        };
+        let execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        let fee = Fee::from(fee.transition().clone(), fee.global_state_root(), None).unwrap();
+        let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
+
+        // Ensure the VM rejects the mock proofs, unless dev mode is enabled.
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        vm.enable_dev_mode().unwrap();
+        assert!(vm.process().read().is_dev_mode());
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
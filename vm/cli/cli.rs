@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Build, Clean, Execute, Graph, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -44,6 +44,8 @@ pub enum Command {
     Clean(Clean),
     #[clap(name = "execute")]
     Execute(Execute),
+    #[clap(name = "graph")]
+    Graph(Graph),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
@@ -59,6 +61,7 @@ impl Command {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
             Self::Execute(command) => command.parse(),
+            Self::Graph(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
@@ -44,9 +44,11 @@ impl Execute {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
+        // Initialize the query to the endpoint.
+        let query = Query::<_, BlockMemory<_>>::from(self.endpoint);
         // Execute the request.
         let (response, execution, metrics) =
-            package.execute::<Aleo, _>(self.endpoint, &private_key, self.function, &self.inputs, rng)?;
+            package.execute::<Aleo, _>(query, &private_key, self.function, &self.inputs, rng)?;
 
         // TODO (howardwu): Include the option to execute a fee.
         let fee = None;
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Renders the import graph of an Aleo program, or the call graph of a function execution
+#[derive(Debug, Parser)]
+pub struct Graph {
+    /// The function name. If specified, the function is executed and its call graph is rendered.
+    function: Option<Identifier<CurrentNetwork>>,
+    /// The function inputs.
+    inputs: Vec<Value<CurrentNetwork>>,
+    /// Uses the specified endpoint.
+    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
+    endpoint: String,
+    /// Renders the graph as JSON, instead of Graphviz DOT.
+    #[clap(long)]
+    json: bool,
+    /// Writes the graph to the specified path, instead of printing it.
+    #[clap(long)]
+    output: Option<PathBuf>,
+}
+
+impl Graph {
+    /// Renders the graph of the Aleo program in the current directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+        // Load the process.
+        let process = package.get_process()?;
+
+        // Construct the graph.
+        let graph = match self.function {
+            Some(function) => {
+                // Load the private key.
+                let private_key = crate::cli::helpers::dotenv_private_key()?;
+                // Initialize an RNG.
+                let rng = &mut rand::thread_rng();
+                // Initialize the query to the endpoint.
+                let query = Query::<_, BlockMemory<_>>::from(self.endpoint);
+                // Execute the request.
+                let (_, execution, metrics) =
+                    package.execute::<Aleo, _>(query, &private_key, function, &self.inputs, rng)?;
+                process.execution_graph(&execution, Some(&metrics))?
+            }
+            None => process.program_graph(package.program_id())?,
+        };
+
+        // Render the graph.
+        let rendered = match self.json {
+            true => serde_json::to_string_pretty(&graph.to_json())?,
+            false => graph.to_dot(),
+        };
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        match self.output {
+            Some(output) => {
+                std::fs::write(&output, rendered)?;
+                Ok(format!("✅ Wrote the graph to \"{}\" {}", output.display(), path_string.dimmed()))
+            }
+            None => {
+                println!("{rendered}");
+                Ok(format!(
+                    "✅ Rendered the graph of '{}' {}",
+                    package.program_id().to_string().bold(),
+                    path_string.dimmed()
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_graph() {
+        let arg_vec = vec!["snarkvm", "graph", "--json", "--output", "graph.json"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Graph(graph) = cli.command {
+            assert!(graph.function.is_none());
+            assert!(graph.inputs.is_empty());
+            assert!(graph.json);
+            assert_eq!(graph.output, Some(PathBuf::from("graph.json")));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkvm_graph_execution() {
+        let arg_vec = vec!["snarkvm", "graph", "hello", "1u32", "2u32"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Graph(graph) = cli.command {
+            assert_eq!(graph.function, Some(Identifier::try_from(arg_vec[2]).unwrap()));
+            assert_eq!(graph.inputs, vec![Value::try_from(arg_vec[3]).unwrap(), Value::try_from(arg_vec[4]).unwrap()]);
+            assert!(!graph.json);
+            assert!(graph.output.is_none());
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
@@ -21,6 +21,9 @@ pub use clean::*;
 pub mod execute;
 pub use execute::*;
 
+pub mod graph;
+pub use graph::*;
+
 pub mod new;
 pub use new::*;
 
@@ -32,15 +35,16 @@ pub use update::*;
 
 use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
-    ledger::block::Transaction,
+    ledger::{block::Transaction, query::Query, store::helpers::memory::BlockMemory},
     package::Package,
+    synthesizer::process::ConstraintProfile,
 };
 
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
 
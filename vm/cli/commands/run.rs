@@ -21,6 +21,12 @@ pub struct Run {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
+    /// Writes the constraint profile to the specified path, in the folded-stack flame graph format.
+    #[clap(long)]
+    flamegraph: Option<PathBuf>,
+    /// Writes the constraint profile to the specified path, as JSON.
+    #[clap(long)]
+    profile: Option<PathBuf>,
 }
 
 impl Run {
@@ -38,8 +44,12 @@ impl Run {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
-        // Execute the request.
-        let (response, metrics) = package.run::<Aleo, _>(&private_key, self.function, &self.inputs, rng)?;
+        // Execute the request, and record its constraint profile, if requested.
+        let mut run = || package.run::<Aleo, _>(&private_key, self.function, &self.inputs, rng);
+        let ((response, metrics), profile) = match self.flamegraph.is_some() || self.profile.is_some() {
+            true => ConstraintProfile::<CurrentNetwork>::record(run).map(|(output, profile)| (output, Some(profile)))?,
+            false => (run()?, None),
+        };
 
         // Count the number of times a function is called.
         let mut program_frequency = HashMap::<String, usize>::new();
@@ -76,6 +86,18 @@ impl Run {
             println!(" •  {function_constraints} {counter_string}",)
         }
 
+        // Write the constraint profile, if requested.
+        if let Some(profile) = profile {
+            if let Some(flamegraph) = &self.flamegraph {
+                std::fs::write(flamegraph, profile.to_folded_stacks())?;
+                println!("\n🔥 Wrote the flame graph profile to \"{}\"", flamegraph.display());
+            }
+            if let Some(json) = &self.profile {
+                std::fs::write(json, serde_json::to_string_pretty(&profile.to_json())?)?;
+                println!("\n📊 Wrote the constraint profile to \"{}\"", json.display());
+            }
+        }
+
         // Log the outputs.
         match response.outputs().len() {
             0 => (),
@@ -120,4 +142,20 @@ mod tests {
             panic!("Unexpected result of clap parsing!");
         }
     }
+
+    #[test]
+    fn clap_snarkvm_run_with_profile() {
+        let arg_vec =
+            vec!["snarkvm", "run", "hello", "1u32", "--flamegraph", "hello.folded", "--profile", "hello.json"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Run(run) = cli.command {
+            assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
+            assert_eq!(run.inputs, vec![Value::try_from(arg_vec[3]).unwrap()]);
+            assert_eq!(run.flamegraph, Some(PathBuf::from("hello.folded")));
+            assert_eq!(run.profile, Some(PathBuf::from("hello.json")));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
 }
@@ -167,6 +167,8 @@ impl<N: Network> Package<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ledger::{query::Query, store::helpers::memory::BlockMemory};
+    use snarkvm_utilities::TestRng;
 
     type CurrentNetwork = snarkvm_console::network::MainnetV0;
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -208,4 +210,36 @@ mod tests {
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    #[test]
+    fn test_deploy_and_execute_with_query_server() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+        // Samples a local chain, and starts a query server for it.
+        let (block_store, server) = crate::package::test_helpers::sample_local_chain();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+
+        // Deploy the package, and load the deployment into a process.
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.load_deployment(&deployment).unwrap();
+
+        // Execute the deployed program on the local chain.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        let query = Query::<_, BlockMemory<_>>::from(server.url());
+        let (_response, execution, _metrics) =
+            package.execute::<CurrentAleo, _>(query, &private_key, function_name, &inputs, rng).unwrap();
+
+        // Ensure the execution is anchored to the state root of the local chain.
+        assert_eq!(execution.global_state_root(), block_store.current_state_root());
+        // Ensure the execution is verified with the keys of the deployment.
+        process.verify_execution(&execution).unwrap();
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
 }
@@ -15,11 +15,11 @@
 use super::*;
 
 impl<N: Network> Package<N> {
-    /// Executes a program function with the given inputs.
+    /// Executes a program function with the given inputs, and proves its inclusion against the given query.
     #[allow(clippy::type_complexity)]
     pub fn execute<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
-        endpoint: String,
+        query: impl QueryTrait<N>,
         private_key: &PrivateKey<N>,
         function_name: Identifier<N>,
         inputs: &[Value<N>],
@@ -36,7 +36,7 @@ impl<N: Network> Package<N> {
 
         // Build the package, if the package requires building.
         // TODO (howardwu): We currently choose only to support local synthesis of keys due to performance.
-        // self.build::<A>(Some(endpoint.clone()))?;
+        // self.build::<A>(Some(endpoint))?;
         self.build::<A>(None)?;
 
         // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
@@ -102,7 +102,7 @@ impl<N: Network> Package<N> {
         let call_metrics = trace.call_metrics().to_vec();
 
         // Prepare the trace.
-        trace.prepare(Query::<_, BlockMemory<_>>::from(endpoint))?;
+        trace.prepare(query)?;
         // Prove the execution.
         let execution = trace.prove_execution::<A, R>(&locator.to_string(), rng)?;
         // Return the response, execution, and call metrics.
@@ -113,13 +113,12 @@ impl<N: Network> Package<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ledger::{query::Query, store::helpers::memory::BlockMemory};
     use snarkvm_utilities::TestRng;
 
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
 
-    // TODO: Re-enable this test after `mainnet`.
     #[test]
-    #[ignore]
     fn test_execute() {
         // Samples a new package at a temporary directory.
         let (directory, package) = crate::package::test_helpers::sample_token_package();
@@ -136,22 +135,23 @@ mod tests {
         // Sample the function inputs.
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
-        // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        // Load the query fixtures.
+        let query = crate::package::test_helpers::sample_genesis_query();
         // Run the program function.
-        let (_response, _execution, _metrics) =
-            package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
+        let (_response, execution, _metrics) =
+            package.execute::<CurrentAleo, _>(query.clone(), &private_key, function_name, &inputs, rng).unwrap();
+        // Ensure the execution is anchored to the recorded state root.
+        assert_eq!(execution.global_state_root(), query.current_state_root().unwrap());
 
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
 
-    // TODO: Re-enable this test using a mock API endpoint for the `Query` struct.
     #[test]
-    #[ignore]
     fn test_execute_with_import() {
         // Samples a new package at a temporary directory.
-        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        // Note: The nested package is used, as the record input of the wallet package has no state path.
+        let (directory, package) = crate::package::test_helpers::sample_nested_package();
 
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
@@ -165,11 +165,39 @@ mod tests {
         // Sample the function inputs.
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
-        // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        // Load the query fixtures.
+        let query = crate::package::test_helpers::sample_genesis_query();
         // Run the program function.
-        let (_response, _execution, _metrics) =
-            package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
+        let (_response, execution, _metrics) =
+            package.execute::<CurrentAleo, _>(query.clone(), &private_key, function_name, &inputs, rng).unwrap();
+        // Ensure the execution is anchored to the recorded state root.
+        assert_eq!(execution.global_state_root(), query.current_state_root().unwrap());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_execute_with_query_server() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+        // Samples a local chain, and starts a query server for it.
+        let (block_store, server) = crate::package::test_helpers::sample_local_chain();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Run the program function.
+        let query = Query::<_, BlockMemory<_>>::from(server.url());
+        let (response, execution, _metrics) =
+            package.execute::<CurrentAleo, _>(query, &private_key, function_name, &inputs, rng).unwrap();
+
+        // Ensure the execution is anchored to the state root of the local chain.
+        assert_eq!(execution.global_state_root(), block_store.current_state_root());
+        // Ensure the function output the token record.
+        assert_eq!(response.outputs().len(), 1);
 
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
@@ -194,11 +222,11 @@ mod tests {
         // Sample the function inputs.
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
-        // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        // Load the query fixtures.
+        let query = crate::package::test_helpers::sample_genesis_query();
         // Run the program function.
         let (_response, _execution, _metrics) =
-            package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
+            package.execute::<CurrentAleo, _>(query, &private_key, function_name, &inputs, rng).unwrap();
 
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
@@ -29,7 +29,7 @@ use crate::{
         program::{Identifier, Locator, ProgramID, Response, Value},
     },
     file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
-    ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
+    ledger::{block::Execution, query::QueryTrait},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
         process::{Assignments, CallMetrics, CallStack, Process, StackExecute},
@@ -180,7 +180,20 @@ impl<N: Network> Package<N> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use snarkvm_console::{account::Address, network::MainnetV0, prelude::TestRng};
+    use crate::ledger::{
+        block::Block,
+        query::{FixtureQuery, QueryServer},
+        store::{
+            helpers::memory::{BlockMemory, FinalizeMemory},
+            BlockStore,
+            FinalizeStore,
+        },
+    };
+    use snarkvm_console::{
+        account::Address,
+        network::MainnetV0,
+        prelude::{FromBytes, TestRng},
+    };
 
     use std::{fs::File, io::Write};
 
@@ -396,6 +409,29 @@ function main:
         (directory, package)
     }
 
+    /// Samples a local chain with the genesis block, and starts a query server for it.
+    pub(crate) fn sample_local_chain() -> (BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>, QueryServer) {
+        // Initialize the stores with the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Start the query server.
+        let server = QueryServer::start("127.0.0.1:0", block_store.clone(), finalize_store).unwrap();
+        (block_store, server)
+    }
+
+    /// Returns the path of the query fixtures, recorded from a local chain with the genesis block.
+    pub(crate) fn genesis_query_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("vm/package/fixtures/genesis_query.json")
+    }
+
+    /// Loads the query fixtures, recorded from a local chain with the genesis block.
+    pub(crate) fn sample_genesis_query() -> FixtureQuery<CurrentNetwork> {
+        FixtureQuery::load(genesis_query_path()).unwrap()
+    }
+
     /// Samples a candidate input to execute the sample package.
     pub(crate) fn sample_package_run(
         program_id: &ProgramID<CurrentNetwork>,
@@ -465,7 +501,10 @@ function main:
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::prelude::MainnetV0;
+    use crate::{
+        ledger::{query::QueryRecorder, store::helpers::memory::BlockMemory},
+        prelude::MainnetV0,
+    };
     use snarkvm_utilities::TestRng;
 
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -555,15 +594,15 @@ function bar:
         let function_name = Identifier::from_str("bar").unwrap();
         let inputs = vec![Value::from_str("true").unwrap()];
 
-        // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        // Load the query fixtures.
+        let query = crate::package::test_helpers::sample_genesis_query();
 
         // Run the program function.
         let run_result = package.run::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).ok();
 
         // Execute the program function.
         let execute_result =
-            package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).ok();
+            package.execute::<CurrentAleo, _>(query, &private_key, function_name, &inputs, rng).ok();
 
         match (run_result, execute_result) {
             // If both results are `None`, then they both failed.
@@ -579,4 +618,32 @@ function bar:
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    /// Use `cargo test record_genesis_query -- --ignored` to re-record the query fixtures of the package tests.
+    #[test]
+    #[ignore]
+    fn test_record_genesis_query() {
+        // Samples a local chain, and records the queries to its query server.
+        let (_block_store, server) = crate::package::test_helpers::sample_local_chain();
+        let recorder = QueryRecorder::<CurrentNetwork, BlockMemory<_>>::new(server.url());
+
+        // Execute the sample packages.
+        for (directory, package) in
+            [crate::package::test_helpers::sample_token_package(), crate::package::test_helpers::sample_nested_package()]
+        {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+            // Sample the function inputs.
+            let (private_key, function_name, inputs) =
+                crate::package::test_helpers::sample_package_run(package.program_id());
+            // Execute the program function.
+            package.execute::<CurrentAleo, _>(recorder.clone(), &private_key, function_name, &inputs, rng).unwrap();
+
+            // Proactively remove the temporary directory (to conserve space).
+            std::fs::remove_dir_all(directory).unwrap();
+        }
+
+        // Save the recorded queries.
+        recorder.save(crate::package::test_helpers::genesis_query_path()).unwrap();
+    }
 }